@@ -0,0 +1,47 @@
+/*!
+Benchmarks for [`Material::compute_flux_density`], comparing the direct
+solution for a [`RelativePermeability::Constant`] with the Newton-Raphson
+iteration for a [`RelativePermeability::FerromagneticPermeability`].
+
+Run with `cargo bench --bench compute_flux_density`.
+ */
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use stem_material::prelude::*;
+
+fn compute_flux_density(c: &mut Criterion) {
+    let h = MagneticFieldStrength::new::<ampere_per_meter>(1000.0);
+
+    let constant = MaterialBuilder::new().relative_permeability(1000.0).build();
+    c.bench_function("compute_flux_density (Constant)", |bencher| {
+        bencher.iter(|| {
+            black_box(&constant)
+                .compute_flux_density(black_box(h), &[], 1e-9, 50)
+                .expect("solved directly")
+        })
+    });
+
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[(0.0, 0.0), (100.0, 1.0), (1000.0, 1.5), (10000.0, 1.8)],
+        1.0,
+    )
+    .expect("valid input data");
+    let ferromagnetic = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(
+            permeability,
+        ))
+        .build();
+    c.bench_function(
+        "compute_flux_density (FerromagneticPermeability)",
+        |bencher| {
+            bencher.iter(|| {
+                black_box(&ferromagnetic)
+                    .compute_flux_density(black_box(h), &[], 1e-9, 50)
+                    .expect("converges")
+            })
+        },
+    );
+}
+
+criterion_group!(benches, compute_flux_density);
+criterion_main!(benches);
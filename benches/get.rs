@@ -0,0 +1,65 @@
+/*!
+Benchmarks for the `get` methods of [`IronLosses`] and [`RelativePermeability`].
+
+Run with `cargo bench --bench get`.
+ */
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use stem_material::prelude::*;
+
+fn iron_losses(c: &mut Criterion) {
+    let b = MagneticFluxDensity::new::<tesla>(1.5);
+    let f = Frequency::new::<hertz>(50.0);
+    let conditions = [b.into(), f.into()];
+
+    let constant = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.0));
+    c.bench_function("IronLosses::Constant::get", |bencher| {
+        bencher.iter(|| black_box(&constant).get(black_box(&conditions)))
+    });
+
+    let jordan_model = IronLosses::JordanModel(JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ));
+    c.bench_function("IronLosses::JordanModel::get", |bencher| {
+        bencher.iter(|| black_box(&jordan_model).get(black_box(&conditions)))
+    });
+    c.bench_function("IronLosses::JordanModel::get_at", |bencher| {
+        bencher.iter(|| black_box(&jordan_model).get_at(black_box(b), black_box(f)))
+    });
+}
+
+fn relative_permeability(c: &mut Criterion) {
+    let b = MagneticFluxDensity::new::<tesla>(1.2);
+    let conditions = [b.into()];
+
+    let constant = RelativePermeability::Constant(1000.0);
+    c.bench_function("RelativePermeability::Constant::get", |bencher| {
+        bencher.iter(|| black_box(&constant).get(black_box(&conditions)))
+    });
+
+    let ferromagnetic = RelativePermeability::FerromagneticPermeability(
+        FerromagneticPermeability::from_bh_pairs_si(
+            &[
+                (0.0, 0.0),
+                (100.0, 0.5),
+                (200.0, 1.0),
+                (500.0, 1.3),
+                (2000.0, 1.6),
+            ],
+            1.0,
+        )
+        .expect("valid input data"),
+    );
+    c.bench_function(
+        "RelativePermeability::FerromagneticPermeability::get",
+        |bencher| bencher.iter(|| black_box(&ferromagnetic).get(black_box(&conditions))),
+    );
+    c.bench_function(
+        "RelativePermeability::FerromagneticPermeability::get_at_b",
+        |bencher| bencher.iter(|| black_box(&ferromagnetic).get_at_b(black_box(b))),
+    );
+}
+
+criterion_group!(benches, iron_losses, relative_permeability);
+criterion_main!(benches);
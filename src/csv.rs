@@ -0,0 +1,701 @@
+/*!
+Import and export of tabular material data as CSV.
+
+This module is only available with the `csv` feature. It contains a small,
+dependency-free CSV layer which is sufficient for the simple numeric tables
+typically provided by lamination manufacturers or measurement equipment:
+
+- Fields are separated by commas, surrounding whitespace is ignored.
+- The first non-empty line is the header. Each header entry consists of a
+  quantity name and its unit, separated by an underscore (e.g.
+  `flux_density_T`). The header entries are case-insensitive and the columns can
+  be given in any order.
+- Empty lines and lines starting with `#` are skipped.
+- All other lines must contain one number per column.
+
+The following methods use this layer:
+- [`IronLossData::from_csv`] / [`IronLossData::to_csv`]
+- [`IronLossData::from_epcos_format`]
+- [`IronLossCharacteristic::to_csv`]
+- [`MagnetizationCurve::from_csv`]
+- [`PolarizationCurve::from_csv`]
+ */
+
+use std::io::{BufRead, BufReader, Read, Write};
+
+use var_quantity::uom::si::{
+    f64::*,
+    frequency::{hertz, kilohertz, megahertz},
+    magnetic_field_strength::ampere_per_meter,
+    magnetic_flux_density::{millitesla, tesla},
+    mass_density::kilogram_per_cubic_meter,
+    specific_power::watt_per_kilogram,
+};
+
+use crate::iron_losses::{IronLossCharacteristic, IronLossData};
+use crate::relative_permeability::{InvalidInputData, MagnetizationCurve, PolarizationCurve};
+
+/**
+Errors which can occur when reading tabular data from CSV.
+ */
+#[derive(Debug)]
+pub enum CsvParseError {
+    /// Reading from the underlying reader failed.
+    Io(std::io::Error),
+    /// The input did not contain a header line.
+    MissingHeader,
+    /// A header entry does not correspond to any of the expected columns.
+    BadHeader {
+        /// The offending header entry.
+        column: String,
+    },
+    /// One of the expected columns is not present in the header.
+    MissingColumn {
+        /// Expected header entry (name and unit) of the missing column.
+        column: String,
+    },
+    /// A column has the expected name, but a different unit.
+    UnitMismatch {
+        /// The offending header entry.
+        column: String,
+        /// Expected unit of the column.
+        expected: &'static str,
+    },
+    /// A line does not contain as many fields as the header.
+    WrongNumberOfFields {
+        /// Line number (starting at 1).
+        line: usize,
+        /// Number of fields in the header.
+        expected: usize,
+        /// Number of fields in the line.
+        found: usize,
+    },
+    /// A field could not be parsed as a number.
+    InvalidNumber {
+        /// Line number (starting at 1).
+        line: usize,
+        /// Content of the field.
+        value: String,
+    },
+    /// A line of an EPCOS table does not belong to any frequency, see
+    /// [`IronLossData::from_epcos_format`].
+    MissingFrequency {
+        /// Line number (starting at 1).
+        line: usize,
+    },
+    /// A frequency line of an EPCOS table could not be parsed, see
+    /// [`IronLossData::from_epcos_format`].
+    InvalidFrequency {
+        /// Line number (starting at 1).
+        line: usize,
+        /// Content of the line.
+        value: String,
+    },
+    /// The parsed data was rejected by the constructor of the target type.
+    InvalidInputData(InvalidInputData),
+}
+
+impl From<InvalidInputData> for CsvParseError {
+    fn from(value: InvalidInputData) -> Self {
+        return Self::InvalidInputData(value);
+    }
+}
+
+impl From<std::io::Error> for CsvParseError {
+    fn from(value: std::io::Error) -> Self {
+        return Self::Io(value);
+    }
+}
+
+impl std::fmt::Display for CsvParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvParseError::Io(error) => return error.fmt(f),
+            CsvParseError::MissingHeader => write!(f, "input does not contain a header line."),
+            CsvParseError::BadHeader { column } => {
+                write!(f, "header contains the unexpected column {column}.")
+            }
+            CsvParseError::MissingColumn { column } => {
+                write!(f, "header does not contain the column {column}.")
+            }
+            CsvParseError::UnitMismatch { column, expected } => write!(
+                f,
+                "column {column} has an unexpected unit, expected the unit {expected}."
+            ),
+            CsvParseError::WrongNumberOfFields {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} contains {found} fields, but the header has {expected}."
+            ),
+            CsvParseError::InvalidNumber { line, value } => {
+                write!(f, "could not parse {value} in line {line} as a number.")
+            }
+            CsvParseError::MissingFrequency { line } => write!(
+                f,
+                "line {line} is not preceded by a frequency line (e.g. \"f = 100 kHz\")."
+            ),
+            CsvParseError::InvalidFrequency { line, value } => {
+                write!(f, "could not parse {value} in line {line} as a frequency.")
+            }
+            CsvParseError::InvalidInputData(error) => return error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CsvParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvParseError::Io(error) => Some(error),
+            CsvParseError::InvalidInputData(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Name and unit of an expected CSV column.
+pub(crate) struct Column {
+    pub(crate) name: &'static str,
+    pub(crate) unit: &'static str,
+}
+
+/**
+Reads a CSV table with the given `columns` from `reader`. Each returned row
+contains the values in the order of `columns`, regardless of their order in
+the header.
+ */
+pub(crate) fn read_table<R: Read>(
+    reader: R,
+    columns: &[Column],
+) -> Result<Vec<Vec<f64>>, CsvParseError> {
+    let mut lines = BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| line.map(|content| (idx + 1, content)));
+
+    // Find the header and map each header entry to its column index
+    let header = loop {
+        match lines.next() {
+            Some(line) => {
+                let (_, content) = line?;
+                if !is_skipped(&content) {
+                    break content;
+                }
+            }
+            None => return Err(CsvParseError::MissingHeader),
+        }
+    };
+    let (positions, num_fields) = parse_header(&header, columns)?;
+
+    // Parse the rows
+    let mut rows = Vec::new();
+    for line in lines {
+        let (line_number, content) = line?;
+        if is_skipped(&content) {
+            continue;
+        }
+        rows.push(parse_row(line_number, &content, &positions, num_fields)?);
+    }
+    return Ok(rows);
+}
+
+/**
+Maps each of the `columns` to its position within the given `header` line.
+Returns these positions (in the order of `columns`) and the total number of
+fields in the header.
+ */
+fn parse_header(header: &str, columns: &[Column]) -> Result<(Vec<usize>, usize), CsvParseError> {
+    let mut positions: Vec<Option<usize>> = vec![None; columns.len()];
+    let num_fields = header.split(',').count();
+    for (field_idx, field) in header.split(',').enumerate() {
+        let field = field.trim();
+        let lowercase = field.to_lowercase();
+        let mut found = false;
+        for (column_idx, column) in columns.iter().enumerate() {
+            let Some(unit) = lowercase.strip_prefix(&format!("{}_", column.name)) else {
+                continue;
+            };
+            if unit != column.unit.to_lowercase() {
+                return Err(CsvParseError::UnitMismatch {
+                    column: field.to_string(),
+                    expected: column.unit,
+                });
+            }
+            positions[column_idx] = Some(field_idx);
+            found = true;
+            break;
+        }
+        if !found {
+            return Err(CsvParseError::BadHeader {
+                column: field.to_string(),
+            });
+        }
+    }
+    let positions = positions
+        .into_iter()
+        .zip(columns.iter())
+        .map(|(position, column)| {
+            position.ok_or_else(|| CsvParseError::MissingColumn {
+                column: format!("{}_{}", column.name, column.unit),
+            })
+        })
+        .collect::<Result<Vec<usize>, CsvParseError>>()?;
+    return Ok((positions, num_fields));
+}
+
+/**
+Parses a single data line. The returned values are ordered according to
+`positions` (see [`parse_header`]).
+ */
+fn parse_row(
+    line_number: usize,
+    content: &str,
+    positions: &[usize],
+    num_fields: usize,
+) -> Result<Vec<f64>, CsvParseError> {
+    let fields: Vec<&str> = content.split(',').map(str::trim).collect();
+    if fields.len() != num_fields {
+        return Err(CsvParseError::WrongNumberOfFields {
+            line: line_number,
+            expected: num_fields,
+            found: fields.len(),
+        });
+    }
+    return positions
+        .iter()
+        .map(|position| {
+            fields[*position]
+                .parse::<f64>()
+                .map_err(|_| CsvParseError::InvalidNumber {
+                    line: line_number,
+                    value: fields[*position].to_string(),
+                })
+        })
+        .collect();
+}
+
+/// Returns whether `line` is empty or a comment.
+fn is_skipped(line: &str) -> bool {
+    let trimmed = line.trim();
+    return trimmed.is_empty() || trimmed.starts_with('#');
+}
+
+const IRON_LOSS_COLUMNS: [Column; 3] = [
+    Column {
+        name: "frequency",
+        unit: "Hz",
+    },
+    Column {
+        name: "flux_density",
+        unit: "T",
+    },
+    Column {
+        name: "specific_loss",
+        unit: "W_per_kg",
+    },
+];
+
+impl IronLossData {
+    /**
+    Reads an [`IronLossData`] dataset from CSV with the columns
+    `frequency_Hz`, `flux_density_T` and `specific_loss_W_per_kg` (see the
+    [module-level documentation](crate::csv) for the format). Rows with the
+    same frequency are grouped into one [`IronLossCharacteristic`] via
+    [`IronLossData::from_triples`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let csv = "frequency_Hz, flux_density_T, specific_loss_W_per_kg
+    50, 0.5, 0.4
+    50, 1.0, 1.3
+    100, 0.5, 1.0
+    100, 1.0, 3.1";
+
+    let data = IronLossData::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(data.0.len(), 2);
+    assert_eq!(data.0[1].frequency.get::<hertz>(), 100.0);
+    assert_eq!(data.0[1].characteristic[1].specific_loss.get::<watt_per_kilogram>(), 3.1);
+
+    let mut exported = Vec::new();
+    data.to_csv(&mut exported).unwrap();
+    assert_eq!(IronLossData::from_csv(exported.as_slice()).unwrap(), data);
+    ```
+     */
+    pub fn from_csv(reader: impl Read) -> Result<IronLossData, CsvParseError> {
+        let rows = read_table(reader, &IRON_LOSS_COLUMNS)?;
+
+        return Ok(IronLossData::from_triples(rows.into_iter().map(|row| {
+            (
+                Frequency::new::<hertz>(row[0]),
+                MagneticFluxDensity::new::<tesla>(row[1]),
+                SpecificPower::new::<watt_per_kilogram>(row[2]),
+            )
+        })));
+    }
+
+    /**
+    Writes `self` as CSV with the columns `frequency_Hz`, `flux_density_T`
+    and `specific_loss_W_per_kg` into `writer`. The output can be read again
+    with [`IronLossData::from_csv`].
+
+    The rows are grouped by frequency in ascending order, i.e. first all rows
+    of the lowest frequency, then all rows of the next higher frequency and so
+    on. Within a frequency, the order of the datapoints is preserved. `self`
+    itself is not reordered.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData(vec![
+        IronLossCharacteristic::from_vecs(f(100.0), &[b(0.5)], &[p(1.0)]),
+        IronLossCharacteristic::from_vecs(f(50.0), &[b(0.5), b(1.0)], &[p(0.4), p(1.3)]),
+    ]);
+
+    let mut exported = Vec::new();
+    data.to_csv(&mut exported).unwrap();
+    assert_eq!(
+        String::from_utf8(exported).unwrap(),
+        "frequency_Hz,flux_density_T,specific_loss_W_per_kg\n50,0.5,0.4\n50,1,1.3\n100,0.5,1\n"
+    );
+    ```
+     */
+    pub fn to_csv(&self, mut writer: impl Write) -> Result<(), std::io::Error> {
+        writeln!(writer, "frequency_Hz,flux_density_T,specific_loss_W_per_kg")?;
+
+        // Stable sort, so characteristics with equal frequencies keep their order
+        let mut characteristics: Vec<&IronLossCharacteristic> = self.0.iter().collect();
+        characteristics.sort_by(|a, b| {
+            a.frequency
+                .get::<hertz>()
+                .total_cmp(&b.frequency.get::<hertz>())
+        });
+
+        for characteristic in characteristics {
+            let frequency = characteristic.frequency.get::<hertz>();
+            for pair in characteristic.characteristic.iter() {
+                writeln!(
+                    writer,
+                    "{},{},{}",
+                    frequency,
+                    pair.flux_density.get::<tesla>(),
+                    pair.specific_loss.get::<watt_per_kilogram>()
+                )?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+const EPCOS_COLUMNS: [Column; 2] = [
+    Column {
+        name: "b",
+        unit: "mT",
+    },
+    Column {
+        name: "pv",
+        unit: "kW_per_m3",
+    },
+];
+
+impl IronLossData {
+    /**
+    Reads an [`IronLossData`] dataset from the tabular format used in the
+    ferrite loss datasheets of EPCOS (TDK).
+
+    Such a file consists of one table per frequency. Each table starts with a
+    frequency line of the form `f = <value> <unit>`, where the unit is either
+    `Hz`, `kHz` or `MHz`. It is followed by a header with the columns `B_mT`
+    (flux density amplitude in mT) and `Pv_kW_per_m3` (power loss density in
+    kW/m³) and the datapoints. Apart from the frequency lines, the tables
+    follow the format described in the [module-level documentation](crate::csv).
+
+    Since [`IronLossData`] contains specific losses, the power loss density is
+    divided by `mass_density`. Each table results in one
+    [`IronLossCharacteristic`]; the tables are kept in the order of the input.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let tables = "# N87, 100 °C
+    f = 25 kHz
+    B_mT, Pv_kW_per_m3
+    100, 12
+    200, 60
+
+    f = 100 kHz
+    B_mT, Pv_kW_per_m3
+    100, 60
+    200, 300";
+
+    let data = IronLossData::from_epcos_format(
+        tables.as_bytes(),
+        MassDensity::new::<kilogram_per_cubic_meter>(4850.0),
+    )
+    .unwrap();
+    assert_eq!(data.0.len(), 2);
+    assert_eq!(data.0[1].frequency.get::<hertz>(), 100e3);
+    approx::assert_abs_diff_eq!(
+        data.0[1].characteristic[0].flux_density.get::<tesla>(),
+        0.1,
+        epsilon = 1e-12
+    );
+    approx::assert_abs_diff_eq!(
+        data.0[1].characteristic[0].specific_loss.get::<watt_per_kilogram>(),
+        60e3 / 4850.0,
+        epsilon = 1e-9
+    );
+
+    // Each table needs to start with a frequency line
+    let result = IronLossData::from_epcos_format(
+        "B_mT, Pv_kW_per_m3\n100, 12".as_bytes(),
+        MassDensity::new::<kilogram_per_cubic_meter>(4850.0),
+    );
+    assert!(matches!(result, Err(CsvParseError::MissingFrequency { line: 1 })));
+    ```
+     */
+    pub fn from_epcos_format(
+        reader: impl Read,
+        mass_density: MassDensity,
+    ) -> Result<IronLossData, CsvParseError> {
+        let mass_density = mass_density.get::<kilogram_per_cubic_meter>();
+        let mut characteristics: Vec<IronLossCharacteristic> = Vec::new();
+        let mut table: Option<EpcosTable> = None;
+
+        for (idx, line) in BufReader::new(reader).lines().enumerate() {
+            let line_number = idx + 1;
+            let content = line?;
+            if is_skipped(&content) {
+                continue;
+            }
+            if let Some(frequency) = parse_frequency_line(line_number, &content)? {
+                if let Some(finished) = table.take() {
+                    characteristics.push(finished.into_characteristic()?);
+                }
+                table = Some(EpcosTable {
+                    frequency,
+                    header: None,
+                    flux_density: Vec::new(),
+                    specific_loss: Vec::new(),
+                });
+                continue;
+            }
+            let Some(current) = table.as_mut() else {
+                return Err(CsvParseError::MissingFrequency { line: line_number });
+            };
+            match &current.header {
+                None => current.header = Some(parse_header(&content, &EPCOS_COLUMNS)?),
+                Some((positions, num_fields)) => {
+                    let row = parse_row(line_number, &content, positions, *num_fields)?;
+                    current
+                        .flux_density
+                        .push(MagneticFluxDensity::new::<millitesla>(row[0]));
+                    current
+                        .specific_loss
+                        .push(SpecificPower::new::<watt_per_kilogram>(
+                            row[1] * 1000.0 / mass_density,
+                        ));
+                }
+            }
+        }
+        if let Some(finished) = table {
+            characteristics.push(finished.into_characteristic()?);
+        }
+        return Ok(IronLossData(characteristics));
+    }
+}
+
+/// A single table of [`IronLossData::from_epcos_format`] which is being read.
+struct EpcosTable {
+    frequency: Frequency,
+    /// Column positions and number of fields (see [`parse_header`]).
+    header: Option<(Vec<usize>, usize)>,
+    flux_density: Vec<MagneticFluxDensity>,
+    specific_loss: Vec<SpecificPower>,
+}
+
+impl EpcosTable {
+    fn into_characteristic(self) -> Result<IronLossCharacteristic, CsvParseError> {
+        if self.header.is_none() {
+            return Err(CsvParseError::MissingHeader);
+        }
+        return Ok(IronLossCharacteristic::from_vecs(
+            self.frequency,
+            &self.flux_density,
+            &self.specific_loss,
+        ));
+    }
+}
+
+/**
+Returns the frequency if `content` is a frequency line (`f = <value> <unit>`)
+of an EPCOS table and `None` if it is any other line.
+ */
+fn parse_frequency_line(
+    line_number: usize,
+    content: &str,
+) -> Result<Option<Frequency>, CsvParseError> {
+    let trimmed = content.trim();
+    let Some(rest) = trimmed
+        .strip_prefix(['f', 'F'])
+        .and_then(|rest| rest.trim_start().strip_prefix('='))
+    else {
+        return Ok(None);
+    };
+    let invalid = || CsvParseError::InvalidFrequency {
+        line: line_number,
+        value: trimmed.to_string(),
+    };
+    let mut parts = rest.split_whitespace();
+    let (Some(value), Some(unit), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    let value: f64 = value.parse().map_err(|_| invalid())?;
+    let frequency = match unit.to_lowercase().as_str() {
+        "hz" => Frequency::new::<hertz>(value),
+        "khz" => Frequency::new::<kilohertz>(value),
+        "mhz" => Frequency::new::<megahertz>(value),
+        _ => return Err(invalid()),
+    };
+    return Ok(Some(frequency));
+}
+
+impl IronLossCharacteristic {
+    /**
+    Writes the datapoints of `self` as CSV with the columns `flux_density_T`
+    and `specific_loss_W_per_kg` into `writer`. Since the frequency is the
+    same for all datapoints, it is not written.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let characteristic = IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(1.0)],
+        &[SpecificPower::new::<watt_per_kilogram>(0.4), SpecificPower::new::<watt_per_kilogram>(1.3)],
+    );
+
+    let mut exported = Vec::new();
+    characteristic.to_csv(&mut exported).unwrap();
+    assert_eq!(
+        String::from_utf8(exported).unwrap(),
+        "flux_density_T,specific_loss_W_per_kg\n0.5,0.4\n1,1.3\n"
+    );
+    ```
+     */
+    pub fn to_csv(&self, mut writer: impl Write) -> Result<(), std::io::Error> {
+        writeln!(writer, "flux_density_T,specific_loss_W_per_kg")?;
+        for pair in self.characteristic.iter() {
+            writeln!(
+                writer,
+                "{},{}",
+                pair.flux_density.get::<tesla>(),
+                pair.specific_loss.get::<watt_per_kilogram>()
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+const MAGNETIZATION_COLUMNS: [Column; 2] = [
+    Column {
+        name: "h",
+        unit: "A_per_m",
+    },
+    Column {
+        name: "b",
+        unit: "T",
+    },
+];
+
+const POLARIZATION_COLUMNS: [Column; 2] = [
+    Column {
+        name: "h",
+        unit: "A_per_m",
+    },
+    Column {
+        name: "j",
+        unit: "T",
+    },
+];
+
+impl MagnetizationCurve {
+    /**
+    Reads a [`MagnetizationCurve`] from CSV with the columns `H_A_per_m` and
+    `B_T` (see the [module-level documentation](crate::csv) for the format).
+    The parsed data is validated in the same way as in
+    [`MagnetizationCurve::new`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let csv = "H_A_per_m, B_T
+    0, 0
+    100, 1.0
+    1000, 1.5";
+
+    let curve = MagnetizationCurve::from_csv(csv.as_bytes(), 0.95).unwrap();
+    assert_eq!(curve.flux_density()[2].get::<tesla>(), 1.5);
+
+    // Invalid iron fill factor
+    assert!(MagnetizationCurve::from_csv(csv.as_bytes(), 1.5).is_err());
+    ```
+     */
+    pub fn from_csv(reader: impl Read, iron_fill_factor: f64) -> Result<Self, CsvParseError> {
+        let rows = read_table(reader, &MAGNETIZATION_COLUMNS)?;
+        let field_strength = rows
+            .iter()
+            .map(|row| MagneticFieldStrength::new::<ampere_per_meter>(row[0]))
+            .collect();
+        let flux_density = rows
+            .iter()
+            .map(|row| MagneticFluxDensity::new::<tesla>(row[1]))
+            .collect();
+        return Ok(MagnetizationCurve::new(
+            field_strength,
+            flux_density,
+            iron_fill_factor,
+        )?);
+    }
+}
+
+impl PolarizationCurve {
+    /**
+    Reads a [`PolarizationCurve`] from CSV with the columns `H_A_per_m` and
+    `J_T` (see the [module-level documentation](crate::csv) for the format).
+    The parsed data is validated in the same way as in
+    [`PolarizationCurve::new`].
+     */
+    pub fn from_csv(reader: impl Read, iron_fill_factor: f64) -> Result<Self, CsvParseError> {
+        let rows = read_table(reader, &POLARIZATION_COLUMNS)?;
+        let field_strength = rows
+            .iter()
+            .map(|row| MagneticFieldStrength::new::<ampere_per_meter>(row[0]))
+            .collect();
+        let polarization = rows
+            .iter()
+            .map(|row| MagneticFluxDensity::new::<tesla>(row[1]))
+            .collect();
+        return Ok(PolarizationCurve::new(
+            field_strength,
+            polarization,
+            iron_fill_factor,
+        )?);
+    }
+}
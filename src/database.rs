@@ -0,0 +1,131 @@
+/*!
+Typed access to a material database.
+
+This module is only available with the `database` feature. It contains the
+[`MaterialDatabase`] struct, which wraps a [`DatabaseManager`] from the
+[serde_mosaic] crate and offers a small, domain-specific API for reading
+[`Material`]s from a directory of YAML files (such as the stem test database).
+
+# Examples
+
+```no_run
+use stem_material::prelude::*;
+
+let mut database = MaterialDatabase::open("stem_test_database/src").unwrap();
+for name in database.list_materials() {
+    println!("{name}");
+}
+let copper = database.read("Copper").unwrap();
+assert_eq!(copper.name(), "Copper");
+```
+ */
+
+use std::path::{Path, PathBuf};
+
+use serde_mosaic::{DatabaseManager, SerdeYaml};
+
+use crate::material::Material;
+
+/**
+A database of [`Material`]s stored as YAML files within a directory.
+
+This is a thin wrapper around a [`DatabaseManager`] which fixes the entry type
+to [`Material`] and the file format to YAML.
+ */
+pub struct MaterialDatabase {
+    manager: DatabaseManager,
+    path: PathBuf,
+}
+
+impl MaterialDatabase {
+    /**
+    Opens the database located at `path`. This fails if the underlying
+    [`DatabaseManager`] cannot be created, e.g. because `path` does not exist.
+     */
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DatabaseError> {
+        let path = path.as_ref().to_path_buf();
+        let manager = DatabaseManager::open(&path, SerdeYaml)
+            .map_err(|error| DatabaseError::Open(error.into()))?;
+        return Ok(Self { manager, path });
+    }
+
+    /// Returns the path of the database directory.
+    pub fn path(&self) -> &Path {
+        return self.path.as_path();
+    }
+
+    /**
+    Reads the [`Material`] with the given `name` from the database.
+     */
+    pub fn read(&mut self, name: &str) -> Result<Material, DatabaseError> {
+        return self
+            .manager
+            .read::<Material, _>(name)
+            .map_err(|error| DatabaseError::Read {
+                name: name.to_string(),
+                source: error.into(),
+            });
+    }
+
+    /**
+    Returns the names of all entries of the database directory, i.e. the file
+    names without their extension, in alphabetical order. Hidden entries
+    (whose name starts with a dot) are skipped. If the directory cannot be
+    read, an empty vector is returned.
+     */
+    pub fn list_materials(&self) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(&self.path) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = path.file_stem()?.to_str()?.to_string();
+                if name.starts_with('.') {
+                    return None;
+                }
+                Some(name)
+            })
+            .collect();
+        names.sort();
+        names.dedup();
+        return names;
+    }
+}
+
+/**
+Errors which can occur when accessing a [`MaterialDatabase`].
+ */
+#[derive(Debug)]
+pub enum DatabaseError {
+    /// The database could not be opened.
+    Open(Box<dyn std::error::Error + Send + Sync>),
+    /// The material `name` could not be read from the database.
+    Read {
+        /// Name of the material.
+        name: String,
+        /// Error returned by the underlying [`DatabaseManager`].
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl std::fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatabaseError::Open(error) => write!(f, "could not open database: {error}"),
+            DatabaseError::Read { name, source } => {
+                write!(f, "could not read material {name}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DatabaseError::Open(error) => Some(error.as_ref()),
+            DatabaseError::Read { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
@@ -0,0 +1,450 @@
+/*!
+An implementation of a generalized Jordan model for iron losses.
+
+The classical [`JordanModel`](crate::iron_losses::jordan_model::JordanModel)
+assumes that the hysteresis losses rise linearly and the eddy current losses
+quadratically with the frequency. Some materials such as grain-oriented steel
+or amorphous metals are better described by fractional frequency exponents:
+
+`p = kh * f^α * B² + kec * f^β * B²`,
+
+where `f` is the frequency and `B` is the amplitude of the flux density. For
+`α = 1` and `β = 2`, this equation is identical to that of the
+[`JordanModel`](crate::iron_losses::jordan_model::JordanModel).
+
+This module offers the [`GeneralizedJordanModel`] struct, a simple container for
+the four parameters which provides the formula given above via its
+[`GeneralizedJordanModel::losses`] method. The struct implements
+[`IsQuantityFunction`] and can therefore be used as the
+[iron loss model](crate::material::Material::iron_losses) of a
+[`Material`](crate::material::Material). Analogous to the
+[`JordanModel`](crate::iron_losses::jordan_model::JordanModel), the parameters
+can be obtained from an [`IronLossData`] instance via the [`TryFrom`]
+implementation.
+ */
+
+use argmin::core::{CostFunction, State};
+use var_quantity::DynQuantity;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use var_quantity::deserialize_quantity;
+
+use var_quantity::IsQuantityFunction;
+use var_quantity::uom::si::{
+    f64::*, frequency::hertz, magnetic_flux_density::tesla, ratio::ratio,
+    specific_power::watt_per_kilogram,
+};
+
+use crate::iron_losses::flux_density_and_frequency;
+use crate::iron_losses::jordan_model::{
+    FailedCoefficientCalculation, FlatLossData, IronLossData, NelderMeadResult, SolverConfig,
+    run_nelder_mead,
+};
+
+/**
+Implementation of the generalized Jordan iron loss model.
+
+As discussed in the
+[module-level documentation](crate::iron_losses::generalized_jordan_model),
+this struct contains the parameters of the generalized Jordan iron loss model:
+
+`p = kh * f^α * B² + kec * f^β * B²`.
+
+Like the [`JordanModel`](crate::iron_losses::jordan_model::JordanModel), the
+frequency `f` is normalized to 50 Hz (see
+[`GeneralizedJordanModel::reference_frequency`]) and the amplitude `B` is
+normalized to 1.5 T (see [`GeneralizedJordanModel::reference_flux_density`]).
+This way, the coefficients have the unit W/kg regardless of the exponents.
+
+# Constructing a generalized Jordan loss model
+
+If the parameters are known, a [`GeneralizedJordanModel`] can be constructed
+via the default field assignment constructor or via
+[`GeneralizedJordanModel::new`]. Alternatively, the parameters can be derived by
+fitting loss curves into the loss equation. This is done by first creating an
+[`IronLossData`] struct and then fallibly converting it into a
+[`GeneralizedJordanModel`] using [`TryFrom`]. Since both frequency exponents are
+free parameters, the data needs to contain characteristics for at least three
+different frequencies.
+
+# Usage in `Material`
+
+This struct is meant to be used for the
+[`Material::iron_losses`](crate::material::Material::iron_losses), hence it
+implements [`IsQuantityFunction`]. Inside the [`IsQuantityFunction::call`]
+function, the input conditions are searched for an entry whose unit corresponds
+to that of the magnetic flux density and another one which matches that of the
+frequency. If either one cannot be found, a value of zero is assumed:
+
+```
+use stem_material::prelude::*;
+
+let model = GeneralizedJordanModel {
+    hysteresis_coefficient: SpecificPower::new::<watt_per_kilogram>(1.0),
+    eddy_current_coefficient: SpecificPower::new::<watt_per_kilogram>(0.5),
+    hysteresis_frequency_exponent: 1.1,
+    eddy_current_frequency_exponent: 1.8,
+};
+
+let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(20.0).into()];
+assert_eq!(model.call(conditions).value, 0.0);
+
+// The input matches the reference values, hence the sum of the coefficients is returned.
+let conditions = &[MagneticFluxDensity::new::<tesla>(1.5).into(), Frequency::new::<hertz>(50.0).into()];
+assert_eq!(model.call(conditions).value, 1.5);
+```
+
+# Serialization and deserialization
+
+Analogous to the [`JordanModel`](crate::iron_losses::jordan_model::JordanModel),
+a [`GeneralizedJordanModel`] is serialized as a struct with four fields, but it
+can be deserialized both from this representation and from that of
+[`IronLossData`]. In case of the latter, the parameters are fitted during
+deserialization.
+
+```ignore
+GeneralizedJordanModel:
+  hysteresis_coefficient: 1 W/kg
+  eddy_current_coefficient: 0.5 W/kg
+  hysteresis_frequency_exponent: 1.1
+  eddy_current_frequency_exponent: 1.8
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "serde_impl::GeneralizedJordanModelDeEnum")
+)]
+pub struct GeneralizedJordanModel {
+    /// Hysteresis loss coefficient `kh`.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub hysteresis_coefficient: SpecificPower,
+    /// Eddy current loss coefficient `kec`.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub eddy_current_coefficient: SpecificPower,
+    /// Frequency exponent `α` of the hysteresis losses.
+    pub hysteresis_frequency_exponent: f64,
+    /// Frequency exponent `β` of the eddy current losses.
+    pub eddy_current_frequency_exponent: f64,
+}
+
+impl GeneralizedJordanModel {
+    /**
+    Creates a new [`GeneralizedJordanModel`] from its parameters.
+     */
+    pub fn new(
+        hysteresis_coefficient: SpecificPower,
+        eddy_current_coefficient: SpecificPower,
+        hysteresis_frequency_exponent: f64,
+        eddy_current_frequency_exponent: f64,
+    ) -> Self {
+        return Self {
+            hysteresis_coefficient,
+            eddy_current_coefficient,
+            hysteresis_frequency_exponent,
+            eddy_current_frequency_exponent,
+        };
+    }
+
+    /**
+    Returns the "reference frequency" of 50 Hz used in the model.
+
+    A frequency input to [`GeneralizedJordanModel::losses`] or
+    [`GeneralizedJordanModel::call`] is divided by this value before being
+    inserted into the model equation.
+     */
+    pub fn reference_frequency() -> Frequency {
+        return Frequency::new::<hertz>(50.0);
+    }
+
+    /**
+    Returns the "reference flux density" of 1.5 T used in the model.
+
+    A flux density input to [`GeneralizedJordanModel::losses`] or
+    [`GeneralizedJordanModel::call`] is divided by this value before being
+    inserted into the model equation.
+     */
+    pub fn reference_flux_density() -> MagneticFluxDensity {
+        return MagneticFluxDensity::new::<tesla>(1.5);
+    }
+
+    /**
+    Returns the specific losses for a sinusoidal changing magnetic flux density
+    with the amplitude `magnetic_flux_density` and the specified `frequency`.
+
+    This function returns the result `p` of the equation:
+
+    `p = kh * f^α * B² + kec * f^β * B²`,
+
+    with the arguments normalized using
+    [`GeneralizedJordanModel::reference_frequency`] and
+    [`GeneralizedJordanModel::reference_flux_density`]. Since the exponents are
+    usually not integers, the absolute value of the normalized frequency is
+    used.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = GeneralizedJordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+        1.0,
+        2.0,
+    );
+
+    // With α = 1 and β = 2, the model is identical to the Jordan model
+    let jordan = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+    let b = MagneticFluxDensity::new::<tesla>(1.2);
+    let f = Frequency::new::<hertz>(200.0);
+    approx::assert_abs_diff_eq!(
+        model.losses(b, f).get::<watt_per_kilogram>(),
+        jordan.losses(b, f).get::<watt_per_kilogram>(),
+        epsilon = 1e-9
+    );
+    ```
+    */
+    pub fn losses(
+        &self,
+        magnetic_flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> SpecificPower {
+        let (hysteresis, eddy_current) = self.losses_by_component(magnetic_flux_density, frequency);
+        return hysteresis + eddy_current;
+    }
+
+    /**
+    Returns the hysteresis and the eddy current part of the specific losses.
+    The first element of the returned tuple is the hysteresis loss term
+    `kh * f^α * B²`, the second one is the eddy current loss term
+    `kec * f^β * B²`.
+     */
+    pub fn losses_by_component(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> (SpecificPower, SpecificPower) {
+        return loss_components(
+            flux_density,
+            frequency,
+            self.hysteresis_coefficient,
+            self.eddy_current_coefficient,
+            self.hysteresis_frequency_exponent,
+            self.eddy_current_frequency_exponent,
+        );
+    }
+
+    /**
+    Returns the coefficient of determination R² of `self` with respect to all
+    datapoints in `data`.
+
+    A value close to 1 indicates that the model reproduces the measured losses
+    very well. If all measured losses in `data` are identical, the result is
+    not finite.
+     */
+    pub fn r_squared(&self, data: &IronLossData) -> f64 {
+        return data.coefficient_of_determination(|flux_density, frequency| {
+            self.losses(flux_density, frequency)
+        });
+    }
+}
+
+impl std::fmt::Display for GeneralizedJordanModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Generalized Jordan loss model: kh = {} W/kg, kec = {} W/kg, α = {}, β = {}",
+            self.hysteresis_coefficient.get::<watt_per_kilogram>(),
+            self.eddy_current_coefficient.get::<watt_per_kilogram>(),
+            self.hysteresis_frequency_exponent,
+            self.eddy_current_frequency_exponent
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for GeneralizedJordanModel {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        let (flux_density, frequency) = flux_density_and_frequency(conditions);
+        return self.losses(flux_density, frequency).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/**
+Actual loss calculation function. Factored out from the
+[`GeneralizedJordanModel`] method because it is also used in
+[`TryFrom<IronLossData>`].
+ */
+fn loss_components(
+    flux_density: MagneticFluxDensity,
+    frequency: Frequency,
+    hysteresis_coefficient: SpecificPower,
+    eddy_current_coefficient: SpecificPower,
+    hysteresis_frequency_exponent: f64,
+    eddy_current_frequency_exponent: f64,
+) -> (SpecificPower, SpecificPower) {
+    let f_norm = (frequency / GeneralizedJordanModel::reference_frequency())
+        .get::<ratio>()
+        .abs();
+    let b_norm_squared = (flux_density / GeneralizedJordanModel::reference_flux_density())
+        .get::<ratio>()
+        .powi(2);
+
+    let hysteresis =
+        hysteresis_coefficient * f_norm.powf(hysteresis_frequency_exponent) * b_norm_squared;
+    let eddy_current =
+        eddy_current_coefficient * f_norm.powf(eddy_current_frequency_exponent) * b_norm_squared;
+    return (hysteresis, eddy_current);
+}
+
+/**
+Cost function for fitting the parameters of a [`GeneralizedJordanModel`]. This
+struct is the [`GeneralizedJordanModel`] counterpart to
+[`FitLossCurve`](crate::iron_losses::jordan_model::FitLossCurve).
+ */
+pub struct FitGeneralizedLossCurve(FlatLossData);
+
+/// Optimization result of fitting a [`FitGeneralizedLossCurve`] with the
+/// [`FitSolver`](crate::iron_losses::jordan_model::FitSolver).
+pub type FitGeneralizedLossCurveResult = NelderMeadResult<FitGeneralizedLossCurve>;
+
+impl CostFunction for FitGeneralizedLossCurve {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
+        // Convert to SI units
+        let hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(p[0]);
+        let eddy_current_coefficient = SpecificPower::new::<watt_per_kilogram>(p[1]);
+
+        Ok(self.0.squared_error(|flux_density, frequency| {
+            let (hysteresis, eddy_current) = loss_components(
+                flux_density,
+                frequency,
+                hysteresis_coefficient,
+                eddy_current_coefficient,
+                p[2],
+                p[3],
+            );
+            hysteresis + eddy_current
+        }))
+    }
+}
+
+impl IronLossData {
+    /**
+    Performs least-square fitting of all the datapoints in `self` into the
+    generalized Jordan equation using the [`argmin`] crate. This is the
+    [`GeneralizedJordanModel`] counterpart to
+    [`IronLossData::solve_for_coefficients`]. The elements of the best
+    parameter vector are the hysteresis coefficient `kh` in W/kg, the eddy
+    current coefficient `kec` in W/kg, the hysteresis frequency exponent `α`
+    and the eddy current frequency exponent `β` (in this order).
+     */
+    pub fn solve_for_generalized_jordan_parameters(
+        &self,
+    ) -> Result<FitGeneralizedLossCurveResult, FailedCoefficientCalculation> {
+        // Separating the two frequency exponents requires at least three
+        // characteristics.
+        self.check_minimum_characteristic_count(3, 2)?;
+
+        let fit = FitGeneralizedLossCurve(FlatLossData::new(self));
+
+        // Coefficients in W/kg, exponents are unitless. The start values are
+        // centered around the classical Jordan model (α = 1, β = 2).
+        let start_values = vec![
+            vec![3.0f64, 3.0f64, 1.0f64, 2.0f64],
+            vec![2.0f64, 1.5f64, 1.2f64, 1.8f64],
+            vec![1.0f64, 0.5f64, 0.9f64, 2.1f64],
+            vec![1.5f64, 1.0f64, 1.1f64, 1.6f64],
+            vec![2.5f64, 2.0f64, 0.8f64, 2.2f64],
+        ];
+
+        let config = SolverConfig {
+            max_iterations: 5000,
+            min_iterations: 0,
+            sd_tolerance: 1e-12,
+        };
+        return run_nelder_mead(fit, start_values, config);
+    }
+}
+
+impl TryFrom<IronLossData> for GeneralizedJordanModel {
+    type Error = FailedCoefficientCalculation;
+    fn try_from(value: IronLossData) -> Result<Self, Self::Error> {
+        return (&value).try_into();
+    }
+}
+
+impl TryFrom<&IronLossData> for GeneralizedJordanModel {
+    type Error = FailedCoefficientCalculation;
+
+    fn try_from(value: &IronLossData) -> Result<Self, Self::Error> {
+        let res = value.solve_for_generalized_jordan_parameters()?;
+        let solution = res
+            .state
+            .get_best_param()
+            .ok_or_else(FailedCoefficientCalculation::missing_solution)?;
+
+        return Ok(GeneralizedJordanModel {
+            hysteresis_coefficient: SpecificPower::new::<watt_per_kilogram>(solution[0]),
+            eddy_current_coefficient: SpecificPower::new::<watt_per_kilogram>(solution[1]),
+            hysteresis_frequency_exponent: solution[2],
+            eddy_current_frequency_exponent: solution[3],
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError;
+
+    #[derive(Deserialize)]
+    pub(super) struct GeneralizedJordanModelAlias {
+        #[serde(deserialize_with = "deserialize_quantity")]
+        hysteresis_coefficient: SpecificPower,
+        #[serde(deserialize_with = "deserialize_quantity")]
+        eddy_current_coefficient: SpecificPower,
+        hysteresis_frequency_exponent: f64,
+        eddy_current_frequency_exponent: f64,
+    }
+
+    #[derive(DeserializeUntaggedVerboseError)]
+    pub(super) enum GeneralizedJordanModelDeEnum {
+        GeneralizedJordanModelAlias(GeneralizedJordanModelAlias),
+        IronLossData(IronLossData),
+    }
+
+    impl TryFrom<GeneralizedJordanModelDeEnum> for GeneralizedJordanModel {
+        type Error = FailedCoefficientCalculation;
+
+        fn try_from(value: GeneralizedJordanModelDeEnum) -> Result<Self, Self::Error> {
+            match value {
+                GeneralizedJordanModelDeEnum::GeneralizedJordanModelAlias(alias) => {
+                    Ok(GeneralizedJordanModel {
+                        hysteresis_coefficient: alias.hysteresis_coefficient,
+                        eddy_current_coefficient: alias.eddy_current_coefficient,
+                        hysteresis_frequency_exponent: alias.hysteresis_frequency_exponent,
+                        eddy_current_frequency_exponent: alias.eddy_current_frequency_exponent,
+                    })
+                }
+                GeneralizedJordanModelDeEnum::IronLossData(iron_loss_data) => {
+                    iron_loss_data.try_into()
+                }
+            }
+        }
+    }
+}
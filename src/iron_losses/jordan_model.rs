@@ -59,7 +59,7 @@ materials. Journal of Applied Physics, vol. 53, no. 11, pp. 8276-8280, Nov.1982
 "#]
 
 use argmin::{
-    core::{CostFunction, State},
+    core::{CostFunction, IterState, KV, Problem, Solver, State, TerminationStatus},
     solver::neldermead::NelderMead,
 };
 use var_quantity::DynQuantity;
@@ -73,9 +73,12 @@ use var_quantity::deserialize_quantity;
 use var_quantity::IsQuantityFunction;
 use var_quantity::uom::si::{
     f64::*, frequency::hertz, magnetic_flux_density::tesla, ratio::ratio,
-    specific_power::watt_per_kilogram,
+    specific_power::watt_per_kilogram, thermodynamic_temperature::kelvin,
 };
 
+use crate::iron_losses::flux_density_and_frequency;
+use crate::relative_permeability::InvalidInputData;
+
 /**
 Implementation of the Jordan iron loss model.
 
@@ -196,6 +199,88 @@ impl JordanModel {
         };
     }
 
+    /**
+    Estimates a [`JordanModel`] from a single [`IronLossCharacteristic`].
+
+    With only one frequency, the two loss terms are indistinguishable from each
+    other: Both scale with `B²`, hence only the combined factor
+    `c = kh * f + kec * f²` can be determined. It is obtained via a
+    least-squares fit of `p = c * B²` to the datapoints (with `f` and `B`
+    normalized as described in [`JordanModel::losses`]). Afterwards, `c` is
+    split into the coefficients as follows:
+
+    - If `assume_hysteresis_dominant` is `true`, all losses are attributed to
+      hysteresis, i.e. `kh = c / f` and `kec = 0`. This is a common heuristic if
+      only data at a low frequency such as 50 Hz is available.
+    - Otherwise, the minimum-norm solution of `kh * f + kec * f² = c` is
+      returned, i.e. `kh = c * f / (f² + f⁴)` and `kec = c * f² / (f² + f⁴)`.
+
+    In both cases, the returned model reproduces the given characteristic as
+    good as possible, but extrapolations to other frequencies are only rough
+    estimates. If multiple characteristics are available, fitting them via
+    [`TryFrom<IronLossData>`] is preferable. The frequency of `characteristic`
+    must be positive and at least one datapoint must have a nonzero flux
+    density, otherwise the coefficients are not finite.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let flux_densities: Vec<MagneticFluxDensity> = [0.5, 1.0, 1.5]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let specific_losses: Vec<SpecificPower> = [0.3, 1.2, 2.7]
+        .into_iter()
+        .map(SpecificPower::new::<watt_per_kilogram>)
+        .collect();
+    let characteristic = IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &flux_densities,
+        &specific_losses,
+    );
+
+    let model = JordanModel::from_single_characteristic(&characteristic, true);
+    approx::assert_abs_diff_eq!(model.hysteresis_coefficient.get::<watt_per_kilogram>(), 2.7, epsilon = 1e-12);
+    assert_eq!(model.eddy_current_coefficient.get::<watt_per_kilogram>(), 0.0);
+
+    // At the reference frequency, the losses are split evenly
+    let model = JordanModel::from_single_characteristic(&characteristic, false);
+    approx::assert_abs_diff_eq!(model.hysteresis_coefficient.get::<watt_per_kilogram>(), 1.35, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(model.eddy_current_coefficient.get::<watt_per_kilogram>(), 1.35, epsilon = 1e-12);
+    ```
+     */
+    pub fn from_single_characteristic(
+        characteristic: &IronLossCharacteristic,
+        assume_hysteresis_dominant: bool,
+    ) -> JordanModel {
+        let f = (characteristic.frequency / Self::reference_frequency()).get::<ratio>();
+
+        // Least-squares fit of p = c * B²
+        let mut numerator = 0.0;
+        let mut denominator = 0.0;
+        for pair in characteristic.characteristic.iter() {
+            let b_squared = (pair.flux_density / Self::reference_flux_density())
+                .get::<ratio>()
+                .powi(2);
+            numerator += pair.specific_loss.get::<watt_per_kilogram>() * b_squared;
+            denominator += b_squared * b_squared;
+        }
+        let c = numerator / denominator;
+
+        let (kh, kec) = if assume_hysteresis_dominant {
+            (c / f, 0.0)
+        } else {
+            let norm = f * f + f.powi(4);
+            (c * f / norm, c * f * f / norm)
+        };
+        return JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(kh),
+            SpecificPower::new::<watt_per_kilogram>(kec),
+        );
+    }
+
     /**
     Returns the "reference frequency" of 50 Hz used in the model.
 
@@ -268,7 +353,7 @@ impl JordanModel {
     assert_eq!(model.losses(MagneticFluxDensity::new::<tesla>(1.5), Frequency::new::<hertz>(50.0)).value, 1.5);
 
     // Double the frequency - Losses rise drastically (nonlinear dependency)
-    assert_eq!(model.losses(MagneticFluxDensity::new::<tesla>(1.5), Frequency::new::<hertz>(100.0)).value, 5.0);
+    assert_eq!(model.losses(MagneticFluxDensity::new::<tesla>(1.5), Frequency::new::<hertz>(100.0)).value, 4.0);
     ```
     */
     pub fn losses(
@@ -279,24 +364,132 @@ impl JordanModel {
         return losses(
             magnetic_flux_density,
             frequency,
+            self.hysteresis_coefficient,
             self.eddy_current_coefficient,
+        );
+    }
+
+    /**
+    Returns the hysteresis and the eddy current part of the specific losses for
+    a sinusoidal changing magnetic flux density with the amplitude
+    `magnetic_flux_density` and the specified `frequency`.
+
+    The first element of the returned tuple is the hysteresis loss term
+    `kh * f * B²`, the second one is the eddy current loss term
+    `kec * (f * B)²`. The inputs are normalized in the same way as in
+    [`JordanModel::losses`], hence the sum of both elements is equal to the
+    value returned by [`JordanModel::losses`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = JordanModel {
+        hysteresis_coefficient: SpecificPower::new::<watt_per_kilogram>(1.0),
+        eddy_current_coefficient: SpecificPower::new::<watt_per_kilogram>(0.5),
+    };
+
+    let flux_density = MagneticFluxDensity::new::<tesla>(1.5);
+    let frequency = Frequency::new::<hertz>(100.0);
+    let (hysteresis, eddy_current) = model.losses_by_component(flux_density, frequency);
+    assert_eq!(hysteresis.value, 2.0);
+    assert_eq!(eddy_current.value, 2.0);
+    assert_eq!(hysteresis + eddy_current, model.losses(flux_density, frequency));
+    ```
+    */
+    pub fn losses_by_component(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> (SpecificPower, SpecificPower) {
+        return loss_components(
+            flux_density,
+            frequency,
             self.hysteresis_coefficient,
+            self.eddy_current_coefficient,
         );
     }
+
+    /**
+    Returns the share of the hysteresis losses in the total losses
+    `p_hys / (p_hys + p_eddy)` for the given `flux_density` and `frequency`
+    (see [`JordanModel::losses_by_component`]).
+
+    The returned value is clamped to the interval `[0, 1]`. If both loss
+    components are zero (e.g. for a zero flux density or frequency), 0.5 is
+    returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+
+    let flux_density = MagneticFluxDensity::new::<tesla>(1.5);
+    let frequency = Frequency::new::<hertz>(100.0);
+    assert_eq!(model.hysteresis_loss_fraction(flux_density, frequency), 0.5);
+    assert_eq!(model.eddy_current_loss_fraction(flux_density, frequency), 0.5);
+
+    // At 50 Hz, the hysteresis losses dominate
+    let frequency = Frequency::new::<hertz>(50.0);
+    approx::assert_abs_diff_eq!(model.hysteresis_loss_fraction(flux_density, frequency), 2.0 / 3.0, epsilon = 1e-12);
+    ```
+     */
+    pub fn hysteresis_loss_fraction(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> f64 {
+        let (hysteresis, eddy_current) = self.losses_by_component(flux_density, frequency);
+        let hysteresis = hysteresis.get::<watt_per_kilogram>();
+        let total = hysteresis + eddy_current.get::<watt_per_kilogram>();
+        if total == 0.0 {
+            return 0.5;
+        }
+        return (hysteresis / total).clamp(0.0, 1.0);
+    }
+
+    /**
+    Returns the share of the eddy current losses in the total losses, which is
+    `1 - hysteresis_loss_fraction` (see
+    [`JordanModel::hysteresis_loss_fraction`]).
+     */
+    pub fn eddy_current_loss_fraction(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> f64 {
+        return 1.0 - self.hysteresis_loss_fraction(flux_density, frequency);
+    }
+
+    /**
+    Returns the coefficient of determination R² of `self` with respect to all
+    datapoints in `data`.
+
+    For each [`FluxDensityLossPair`] of each [`IronLossCharacteristic`] in
+    `data`, the losses predicted by [`JordanModel::losses`] are compared to the
+    measured ones. A value close to 1 indicates that the model reproduces the
+    measured losses very well, while smaller values indicate a worse fit. This
+    is useful to judge the quality of a [`JordanModel`] which has been fitted
+    via [`TryFrom<IronLossData>`]. If all measured losses in `data` are
+    identical, the result is not finite.
+     */
+    pub fn r_squared(&self, data: &IronLossData) -> f64 {
+        return data.coefficient_of_determination(|flux_density, frequency| {
+            self.losses(flux_density, frequency)
+        });
+    }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl IsQuantityFunction for JordanModel {
     fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
-        let mut flux_density = MagneticFluxDensity::new::<tesla>(0.0);
-        let mut frequency = Frequency::new::<hertz>(0.0);
-        for factor in conditions {
-            if let Ok(fd) = MagneticFluxDensity::try_from(*factor) {
-                flux_density = fd;
-            } else if let Ok(f) = Frequency::try_from(*factor) {
-                frequency = f;
-            }
-        }
+        let (flux_density, frequency) = flux_density_and_frequency(conditions);
         return self.losses(flux_density, frequency).into();
     }
 
@@ -315,15 +508,46 @@ fn losses(
     hysteresis_coefficient: SpecificPower,
     eddy_current_coefficient: SpecificPower,
 ) -> SpecificPower {
+    let (hysteresis, eddy_current) = loss_components(
+        flux_density,
+        frequency,
+        hysteresis_coefficient,
+        eddy_current_coefficient,
+    );
+    return hysteresis + eddy_current;
+}
+
+/**
+Returns the hysteresis and the eddy current loss terms of the Jordan model
+separately. See [`JordanModel::losses_by_component`].
+ */
+fn loss_components(
+    flux_density: MagneticFluxDensity,
+    frequency: Frequency,
+    hysteresis_coefficient: SpecificPower,
+    eddy_current_coefficient: SpecificPower,
+) -> (SpecificPower, SpecificPower) {
     let f_norm = JordanModel::reference_frequency();
     let b_norm = JordanModel::reference_flux_density();
 
-    return hysteresis_coefficient
+    let hysteresis = hysteresis_coefficient
         * (frequency / f_norm)
-        * (flux_density / b_norm).get::<ratio>().powi(2)
-        + eddy_current_coefficient
-            * (frequency / f_norm).get::<ratio>().powi(2)
-            * (flux_density / b_norm).get::<ratio>().powi(2);
+        * (flux_density / b_norm).get::<ratio>().powi(2);
+    let eddy_current = eddy_current_coefficient
+        * (frequency / f_norm).get::<ratio>().powi(2)
+        * (flux_density / b_norm).get::<ratio>().powi(2);
+    return (hysteresis, eddy_current);
+}
+
+impl std::fmt::Display for JordanModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Jordan loss model: kh = {} W/kg, kec = {} W/kg",
+            self.hysteresis_coefficient.get::<watt_per_kilogram>(),
+            self.eddy_current_coefficient.get::<watt_per_kilogram>()
+        )
+    }
 }
 
 impl Default for JordanModel {
@@ -338,215 +562,1383 @@ impl Default for JordanModel {
 // =============================================================================
 
 /**
-This struct is a "flattened" version of [`IronLossData`]. It is not meant to be
-used on its own and is just exposed so the optimization result of
-[`IronLossData::solve_for_coefficients`] can be examined. See its docstring for
-more.
- */
-pub struct FitLossCurve {
-    frequencies: Vec<Frequency>,
-    flux_densities: Vec<MagneticFluxDensity>,
-    specific_losses: Vec<SpecificPower>,
-}
-
-impl CostFunction for FitLossCurve {
-    type Param = Vec<f64>;
-    type Output = f64;
+A [`JordanModel`] whose coefficients depend linearly on the temperature.
 
-    fn cost(&self, p: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
-        let mut err = 0.0; // W/kg
-
-        // Convert to SI units
-        let hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(p[0]);
-        let eddy_current_coefficient = SpecificPower::new::<watt_per_kilogram>(p[1]);
+The iron losses of laminations decrease with rising temperature (roughly
+-0.3 %/K for silicon steel), mainly because the electrical resistivity of the
+material increases. This struct models this effect by scaling the coefficients
+of its [`base_model`](TemperatureScaledJordanModel::base_model) linearly with
+the difference between the actual temperature `T` and the
+[`reference_temperature`](TemperatureScaledJordanModel::reference_temperature)
+`T0`:
 
-        for (fi, (bi, pi)) in self
-            .frequencies
-            .iter()
-            .zip(self.flux_densities.iter().zip(self.specific_losses.iter()))
-        {
-            err = err
-                + (*pi - losses(*bi, *fi, hysteresis_coefficient, eddy_current_coefficient))
-                    .get::<watt_per_kilogram>()
-                    .powi(2);
-        }
-        Ok(err)
-    }
-}
+`kh(T) = kh * (1 + αh * (T - T0))`
 
-/**
-A container for multiple [`IronLossCharacteristic`]s.
+`kec(T) = kec * (1 + αec * (T - T0))`
 
-This struct represents a full dataset of multiple loss characteristics at
-different frequencies obtained from either a manufacturer data sheet or from own
-measurements. Its main purpose is to be used for the calculation of the
-[`JordanModel`] coefficients via the
-[`solve_for_coefficients`](IronLossData::solve_for_coefficients) method. This
-method returns the raw result of the underlying fitting as an
-[`argmin::core::OptimizationResult`], which contains the coefficients. For
-convenience, a [`TryFrom<IronLossData>`] implementation exists for
-[`JordanModel`], which calls
-[`solve_for_coefficients`](IronLossData::solve_for_coefficients) and then
-unpacks the coefficients.
- */
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct IronLossData(pub Vec<IronLossCharacteristic>);
+where `αh` and `αec` are the temperature coefficients of the hysteresis and the
+eddy current losses in 1/K. A scaled coefficient is clamped to zero if the
+scaling factor would become negative (which can only happen far outside the
+validity range of the linear approximation).
 
-impl IronLossData {
-    /**
-    Performs least-square fitting of all the datapoints in `self` into the loss
-    equation using the [`argmin`]. If the fitting succeeds, the raw
-    [`argmin::core::OptimizationResult`] is returned, which can then be
-    examined. In particular, the coefficients can be retrieved with the
-    [`State::get_best_param`](`argmin::core::State::get_best_param`). As a
-    convencience wrapper, a [`TryFrom<IronLossData>`] implementation exists for
-    [`JordanModel`], which calls
-    [`solve_for_coefficients`](IronLossData::solve_for_coefficients) and then
-    unpacks the coefficients.
+# Usage in `Material`
 
-    # Examples
+This struct implements [`IsQuantityFunction`]. Inside the
+[`IsQuantityFunction::call`] function, the flux density and frequency are
+identified in the same way as in the [`JordanModel`]. Additionally, the
+conditions are searched for a temperature. If none is found, the
+[`reference_temperature`](TemperatureScaledJordanModel::reference_temperature)
+is used, meaning that the losses of the
+[`base_model`](TemperatureScaledJordanModel::base_model) are returned.
 
-    ```
-    use stem_material::prelude::*;
+```
+use stem_material::prelude::*;
 
-    // Expose the get_best_param method
-    use argmin::core::State;
+let model = TemperatureScaledJordanModel {
+    base_model: JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ),
+    reference_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    hysteresis_temp_coefficient: -0.001,
+    eddy_current_temp_coefficient: -0.003,
+};
 
-    // First characteristic
-    let frequency = Frequency::new::<hertz>(50.0);
-    let mut datapoints = Vec::new();
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.5),
-        SpecificPower::new::<watt_per_kilogram>(2.0)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.6),
-        SpecificPower::new::<watt_per_kilogram>(2.5)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.7),
-        SpecificPower::new::<watt_per_kilogram>(3.2)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.8),
-        SpecificPower::new::<watt_per_kilogram>(4.0)
-    ));
-    let lc_50 = IronLossCharacteristic::new(frequency, datapoints);
+let b = MagneticFluxDensity::new::<tesla>(1.5);
+let f = Frequency::new::<hertz>(50.0);
 
-    // Second characteristic
-    let frequency = Frequency::new::<hertz>(100.0);
-    let mut datapoints = Vec::new();
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.5),
-        SpecificPower::new::<watt_per_kilogram>(5.0)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.6),
-        SpecificPower::new::<watt_per_kilogram>(6.0)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.7),
-        SpecificPower::new::<watt_per_kilogram>(8.0)
-    ));
-    datapoints.push(FluxDensityLossPair::new(
-        MagneticFluxDensity::new::<tesla>(0.8),
-        SpecificPower::new::<watt_per_kilogram>(12.0)
-    ));
-    let lc_100 = IronLossCharacteristic::new(frequency, datapoints);
+// No temperature given -> reference temperature
+assert_eq!(model.call(&[b.into(), f.into()]).value, 1.5);
 
-    let iron_loss_data = IronLossData(vec![lc_50, lc_100]);
-    let res = iron_loss_data.solve_for_coefficients().expect("fitting succeded");
-    let c = res.state.get_best_param().expect("must contain coefficients");
+// 100 K above the reference temperature
+let t = ThermodynamicTemperature::new::<degree_celsius>(120.0);
+approx::assert_abs_diff_eq!(model.call(&[b.into(), f.into(), t.into()]).value, 0.9 + 0.35, epsilon = 1e-9);
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemperatureScaledJordanModel {
+    /// Jordan model which is valid at the reference temperature.
+    pub base_model: JordanModel,
+    /// Temperature at which [`TemperatureScaledJordanModel::base_model`] is
+    /// valid.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub reference_temperature: ThermodynamicTemperature,
+    /// Relative change of the hysteresis loss coefficient per kelvin.
+    pub hysteresis_temp_coefficient: f64,
+    /// Relative change of the eddy current loss coefficient per kelvin.
+    pub eddy_current_temp_coefficient: f64,
+}
 
-    // First element is the hysteresis coefficient
-    approx::assert_abs_diff_eq!(c[0], 9.528, epsilon=1e-3);
+impl TemperatureScaledJordanModel {
+    /**
+    Returns a [`JordanModel`] whose coefficients are scaled to the given
+    `temperature`.
+     */
+    pub fn model_at(&self, temperature: ThermodynamicTemperature) -> JordanModel {
+        let delta = temperature.get::<kelvin>() - self.reference_temperature.get::<kelvin>();
+        let hysteresis_factor = (1.0 + self.hysteresis_temp_coefficient * delta).max(0.0);
+        let eddy_current_factor = (1.0 + self.eddy_current_temp_coefficient * delta).max(0.0);
+        return JordanModel {
+            hysteresis_coefficient: self.base_model.hysteresis_coefficient * hysteresis_factor,
+            eddy_current_coefficient: self.base_model.eddy_current_coefficient
+                * eddy_current_factor,
+        };
+    }
 
-    // Second element is the eddy current coefficient
-    approx::assert_abs_diff_eq!(c[1], 5.265, epsilon=1e-3);
-    ```
+    /**
+    Returns the specific losses for a sinusoidal changing magnetic flux density
+    with the amplitude `flux_density` and the specified `frequency` at the
+    given `temperature`. See [`JordanModel::losses`].
      */
-    pub fn solve_for_coefficients(
+    pub fn losses(
         &self,
-    ) -> Result<
-        argmin::core::OptimizationResult<
-            FitLossCurve,
-            NelderMead<Vec<f64>, f64>,
-            argmin::core::IterState<Vec<f64>, (), (), (), (), f64>,
-        >,
-        FailedCoefficientCalculation,
-    > {
-        // Concatenate all vectors
-        let mut num_elems: usize = 0;
-        for characteristic in self.0.iter() {
-            num_elems += characteristic.characteristic.len();
-        }
-        let mut frequencies_flat: Vec<Frequency> = Vec::with_capacity(num_elems);
-        let mut flux_density_flat: Vec<MagneticFluxDensity> = Vec::with_capacity(num_elems);
-        let mut specific_losses_flat: Vec<SpecificPower> = Vec::with_capacity(num_elems);
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+        temperature: ThermodynamicTemperature,
+    ) -> SpecificPower {
+        return self.model_at(temperature).losses(flux_density, frequency);
+    }
 
-        for characteristic in self.0.iter() {
-            let frequency = characteristic.frequency;
+    /**
+    Returns the hysteresis and the eddy current part of the specific losses at
+    the given `temperature`. See [`JordanModel::losses_by_component`].
+     */
+    pub fn losses_by_component(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+        temperature: ThermodynamicTemperature,
+    ) -> (SpecificPower, SpecificPower) {
+        return self
+            .model_at(temperature)
+            .losses_by_component(flux_density, frequency);
+    }
 
-            for flux_density_and_specific_loss in characteristic.characteristic.iter().cloned() {
-                frequencies_flat.push(frequency);
-                flux_density_flat.push(flux_density_and_specific_loss.flux_density);
-                specific_losses_flat.push(flux_density_and_specific_loss.specific_loss);
+    /**
+    Searches `conditions` for a temperature and returns the
+    [`reference_temperature`](TemperatureScaledJordanModel::reference_temperature)
+    if none can be found.
+     */
+    pub(crate) fn temperature(&self, conditions: &[DynQuantity<f64>]) -> ThermodynamicTemperature {
+        for factor in conditions {
+            if let Ok(temperature) = ThermodynamicTemperature::try_from(*factor) {
+                return temperature;
             }
         }
-
-        let fit = FitLossCurve {
-            frequencies: frequencies_flat,
-            flux_densities: flux_density_flat,
-            specific_losses: specific_losses_flat,
-        };
-
-        // All values in W/kg
-        let start_values = vec![
-            vec![3.0f64, 3.0f64],
-            vec![2.0f64, 1.5f64],
-            vec![1.0f64, 0.5f64],
-        ];
-
-        let solver = NelderMead::new(start_values)
-            .with_sd_tolerance(0.0001)
-            .map_err(|error| FailedCoefficientCalculation(Some(error)))?;
-
-        // Run solver
-        return argmin::core::Executor::new(fit, solver)
-            .configure(|state| state.max_iters(200))
-            .run()
-            .map_err(|error| FailedCoefficientCalculation(Some(error)));
+        return self.reference_temperature;
     }
 }
 
-impl TryFrom<IronLossData> for JordanModel {
-    type Error = FailedCoefficientCalculation;
-    fn try_from(value: IronLossData) -> Result<Self, Self::Error> {
-        return (&value).try_into();
+impl std::fmt::Display for TemperatureScaledJordanModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at {} K, temperature coefficients: {} 1/K (hysteresis), {} 1/K (eddy current))",
+            self.base_model,
+            self.reference_temperature.get::<kelvin>(),
+            self.hysteresis_temp_coefficient,
+            self.eddy_current_temp_coefficient
+        )
     }
 }
 
-impl TryFrom<&IronLossData> for JordanModel {
-    type Error = FailedCoefficientCalculation;
-
-    fn try_from(value: &IronLossData) -> Result<Self, Self::Error> {
-        let res = value.solve_for_coefficients()?;
-        let solution = res
-            .state
-            .get_best_param()
-            .ok_or(FailedCoefficientCalculation(None))?;
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for TemperatureScaledJordanModel {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        let (flux_density, frequency) = flux_density_and_frequency(conditions);
+        let temperature = self.temperature(conditions);
+        return self.losses(flux_density, frequency, temperature).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+// =============================================================================
+
+/**
+This struct is a "flattened" version of [`IronLossData`] which implements the
+[`CostFunction`] used in [`IronLossData::solve_for_coefficients`].
+
+Besides being part of the optimization result of
+[`IronLossData::solve_for_coefficients`], it can also be used to inspect the
+optimization landscape or to implement a custom fitting algorithm via
+[`FitLossCurve::predict`] and [`FitLossCurve::data_points`].
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let data = IronLossData::from_triples([
+    (Frequency::new::<hertz>(50.0), MagneticFluxDensity::new::<tesla>(1.5), SpecificPower::new::<watt_per_kilogram>(1.5)),
+    (Frequency::new::<hertz>(100.0), MagneticFluxDensity::new::<tesla>(1.5), SpecificPower::new::<watt_per_kilogram>(4.0)),
+]);
+let fit = FitLossCurve::new(&data);
+assert_eq!(fit.data_points().count(), 2);
+
+// These coefficients reproduce the data exactly
+let predictions = fit.predict(
+    SpecificPower::new::<watt_per_kilogram>(1.0),
+    SpecificPower::new::<watt_per_kilogram>(0.5),
+);
+for (prediction, (_, _, measured)) in predictions.iter().zip(fit.data_points()) {
+    approx::assert_abs_diff_eq!(prediction.get::<watt_per_kilogram>(), measured.get::<watt_per_kilogram>(), epsilon = 1e-12);
+}
+```
+ */
+#[derive(Clone)]
+pub struct FitLossCurve {
+    frequencies: Vec<Frequency>,
+    flux_densities: Vec<MagneticFluxDensity>,
+    specific_losses: Vec<SpecificPower>,
+    weights: Vec<f64>,
+}
+
+/**
+Settings for the [`NelderMead`] solver used in
+[`IronLossData::solve_for_coefficients_with_config`].
+
+The solver terminates once the standard deviation of the cost function values
+at the simplex vertices falls below `sd_tolerance` or once `max_iterations` is
+reached. If the tolerance is already reached before `min_iterations`, the
+solver continues until `min_iterations` regardless (this guards against a
+premature exit if the initial simplex happens to be close to a local minimum).
+A `min_iterations` larger than `max_iterations` is treated as `max_iterations`.
+
+The [`Default`] implementation returns the settings used by
+[`IronLossData::solve_for_coefficients`]: 200 maximum iterations, no minimum
+number of iterations and a tolerance of 0.0001.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverConfig {
+    /// Maximum number of iterations.
+    pub max_iterations: u64,
+    /// Minimum number of iterations.
+    pub min_iterations: u64,
+    /// Standard deviation tolerance of the cost function values at the simplex
+    /// vertices. Must not be negative.
+    pub sd_tolerance: f64,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        return Self {
+            max_iterations: 200,
+            min_iterations: 0,
+            sd_tolerance: 0.0001,
+        };
+    }
+}
+
+/// Internal state of the [`FitSolver`].
+type FitState = IterState<Vec<f64>, (), (), (), (), f64>;
+
+/**
+The [`NelderMead`] solver used to fit the parameters of all loss models of this
+crate. It behaves exactly like the wrapped [`NelderMead`] solver, except that
+the convergence criterion (standard deviation tolerance) is only evaluated once
+the minimum number of iterations given by [`SolverConfig::min_iterations`] has
+been performed.
+ */
+pub struct FitSolver {
+    solver: NelderMead<Vec<f64>, f64>,
+    min_iterations: u64,
+}
+
+impl<O> Solver<O, FitState> for FitSolver
+where
+    O: CostFunction<Param = Vec<f64>, Output = f64>,
+{
+    fn name(&self) -> &str {
+        return Solver::<O, FitState>::name(&self.solver);
+    }
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: FitState,
+    ) -> Result<(FitState, Option<KV>), argmin::core::Error> {
+        return self.solver.init(problem, state);
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: FitState,
+    ) -> Result<(FitState, Option<KV>), argmin::core::Error> {
+        return self.solver.next_iter(problem, state);
+    }
+
+    fn terminate(&mut self, state: &FitState) -> TerminationStatus {
+        if state.get_iter() < self.min_iterations {
+            return TerminationStatus::NotTerminated;
+        }
+        return Solver::<O, FitState>::terminate(&mut self.solver, state);
+    }
+}
+
+/// Optimization result of fitting the cost function `C` with the [`FitSolver`].
+pub type NelderMeadResult<C> = argmin::core::OptimizationResult<C, FitSolver, FitState>;
+
+/// Optimization result of fitting a [`FitLossCurve`] with the [`FitSolver`].
+pub type FitLossCurveResult = NelderMeadResult<FitLossCurve>;
+
+/**
+Minimizes `cost` with a [`FitSolver`] which starts from the simplex given by
+`start_values` and terminates according to `config` (see [`SolverConfig`]).
+
+This is the fitting scaffolding shared by all loss models of this crate.
+ */
+pub(crate) fn run_nelder_mead<C>(
+    cost: C,
+    start_values: Vec<Vec<f64>>,
+    config: SolverConfig,
+) -> Result<NelderMeadResult<C>, FailedCoefficientCalculation>
+where
+    C: CostFunction<Param = Vec<f64>, Output = f64>,
+{
+    let solver = FitSolver {
+        solver: NelderMead::new(start_values)
+            .with_sd_tolerance(config.sd_tolerance)
+            .map_err(FailedCoefficientCalculation::from_argmin_error)?,
+        min_iterations: config.min_iterations,
+    };
+
+    return argmin::core::Executor::new(cost, solver)
+        .configure(|state| state.max_iters(config.max_iterations))
+        .run()
+        .map_err(FailedCoefficientCalculation::from_argmin_error);
+}
+
+/**
+The datapoints of an [`IronLossData`] instance, concatenated into three vectors
+of equal length (see [`IronLossData::flatten`]). The cost functions of the
+[`SteinmetzModel`](crate::iron_losses::steinmetz_model::SteinmetzModel) and the
+[`GeneralizedJordanModel`](crate::iron_losses::generalized_jordan_model::GeneralizedJordanModel)
+are built on top of it.
+ */
+#[derive(Clone)]
+pub(crate) struct FlatLossData {
+    frequencies: Vec<Frequency>,
+    flux_densities: Vec<MagneticFluxDensity>,
+    specific_losses: Vec<SpecificPower>,
+}
+
+impl FlatLossData {
+    pub(crate) fn new(data: &IronLossData) -> Self {
+        let (frequencies, flux_densities, specific_losses) = data.flatten();
+        return Self {
+            frequencies,
+            flux_densities,
+            specific_losses,
+        };
+    }
+
+    /**
+    Returns the sum of the squared differences (in (W/kg)²) between the
+    measured losses and the losses returned by `model` for the flux density and
+    frequency of each datapoint.
+     */
+    pub(crate) fn squared_error<F>(&self, model: F) -> f64
+    where
+        F: Fn(MagneticFluxDensity, Frequency) -> SpecificPower,
+    {
+        let mut err = 0.0;
+        for (fi, (bi, pi)) in self
+            .frequencies
+            .iter()
+            .zip(self.flux_densities.iter().zip(self.specific_losses.iter()))
+        {
+            err += (*pi - model(*bi, *fi)).get::<watt_per_kilogram>().powi(2);
+        }
+        return err;
+    }
+}
+
+impl FitLossCurve {
+    /**
+    Creates a new [`FitLossCurve`] from all datapoints in `data`. The
+    datapoints are stored characteristic by characteristic.
+     */
+    pub fn new(data: &IronLossData) -> Self {
+        let (frequencies, flux_densities, specific_losses) = data.flatten();
+        let weights = vec![1.0; frequencies.len()];
+        return Self {
+            frequencies,
+            flux_densities,
+            specific_losses,
+            weights,
+        };
+    }
+
+    /**
+    Assigns a weight to all datapoints whose frequency is contained in
+    `weights`. The squared error of a datapoint is multiplied with its weight
+    during the summation in the [`CostFunction`] implementation. Datapoints
+    whose frequency is not contained in `weights` keep their current weight
+    (1 by default).
+     */
+    pub fn with_frequency_weights(mut self, weights: &[(Frequency, f64)]) -> Self {
+        for (frequency, weight) in self.frequencies.iter().zip(self.weights.iter_mut()) {
+            if let Some((_, w)) = weights.iter().find(|(f, _)| f == frequency) {
+                *weight = *w;
+            }
+        }
+        return self;
+    }
+
+    /// Fits the coefficients of the Jordan model into the datapoints of `self`.
+    fn solve(self) -> Result<FitLossCurveResult, FailedCoefficientCalculation> {
+        return self.solve_with_config(SolverConfig::default());
+    }
+
+    /**
+    Fits the coefficients of the Jordan model into the datapoints of `self`
+    using the settings given in `config`.
+     */
+    fn solve_with_config(
+        self,
+        config: SolverConfig,
+    ) -> Result<FitLossCurveResult, FailedCoefficientCalculation> {
+        // All values in W/kg
+        let start_values = vec![
+            vec![3.0f64, 3.0f64],
+            vec![2.0f64, 1.5f64],
+            vec![1.0f64, 0.5f64],
+        ];
+        return run_nelder_mead(self, start_values, config);
+    }
+
+    /**
+    Returns the losses predicted by the [`JordanModel`] with the given
+    coefficients for all datapoints of `self`. The order of the predictions
+    matches that of [`FitLossCurve::data_points`].
+     */
+    pub fn predict(
+        &self,
+        hysteresis_coefficient: SpecificPower,
+        eddy_current_coefficient: SpecificPower,
+    ) -> Vec<SpecificPower> {
+        return self
+            .frequencies
+            .iter()
+            .zip(self.flux_densities.iter())
+            .map(|(f, b)| losses(*b, *f, hysteresis_coefficient, eddy_current_coefficient))
+            .collect();
+    }
+
+    /**
+    Returns an iterator over the stored `(frequency, flux density, specific
+    loss)` datapoints.
+     */
+    pub fn data_points(
+        &self,
+    ) -> impl Iterator<Item = (Frequency, MagneticFluxDensity, SpecificPower)> + '_ {
+        return self
+            .frequencies
+            .iter()
+            .zip(self.flux_densities.iter().zip(self.specific_losses.iter()))
+            .map(|(f, (b, p))| (*f, *b, *p));
+    }
+}
+
+impl CostFunction for FitLossCurve {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
+        let mut err = 0.0; // W/kg
+
+        // Convert to SI units
+        let hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(p[0]);
+        let eddy_current_coefficient = SpecificPower::new::<watt_per_kilogram>(p[1]);
+
+        for ((fi, wi), (bi, pi)) in self
+            .frequencies
+            .iter()
+            .zip(self.weights.iter())
+            .zip(self.flux_densities.iter().zip(self.specific_losses.iter()))
+        {
+            err = err
+                + wi * (*pi - losses(*bi, *fi, hysteresis_coefficient, eddy_current_coefficient))
+                    .get::<watt_per_kilogram>()
+                    .powi(2);
+        }
+        Ok(err)
+    }
+}
+
+/**
+A container for multiple [`IronLossCharacteristic`]s.
+
+This struct represents a full dataset of multiple loss characteristics at
+different frequencies obtained from either a manufacturer data sheet or from own
+measurements. Its main purpose is to be used for the calculation of the
+[`JordanModel`] coefficients via the
+[`solve_for_coefficients`](IronLossData::solve_for_coefficients) method. This
+method returns the raw result of the underlying fitting as an
+[`argmin::core::OptimizationResult`], which contains the coefficients. For
+convenience, a [`TryFrom<IronLossData>`] implementation exists for
+[`JordanModel`], which calls
+[`solve_for_coefficients`](IronLossData::solve_for_coefficients) and then
+unpacks the coefficients.
+
+When the `serde` feature is enabled, an [`IronLossData`] is (de)serialized as a
+list of its [`IronLossCharacteristic`]s, i.e. in the same raw data format which
+can also be deserialized into a [`JordanModel`] directly. Since only the raw
+data is stored, a serialization round trip is lossless.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IronLossData(pub Vec<IronLossCharacteristic>);
+
+impl IronLossData {
+    /**
+    Creates a new [`IronLossData`] from `(frequency, flux density, specific
+    loss)` triples.
+
+    Triples with exactly the same frequency are grouped into one
+    [`IronLossCharacteristic`]. The characteristics are ordered by the first
+    occurrence of their frequency and the datapoints within a characteristic
+    are sorted by their flux density (see
+    [`IronLossCharacteristic::new`]). See
+    [`from_triples_with_tolerance`](IronLossData::from_triples_with_tolerance)
+    for grouping frequencies which are only approximately equal.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData::from_triples([
+        (f(50.0), b(1.0), p(1.3)),
+        (f(100.0), b(0.5), p(1.0)),
+        (f(50.0), b(0.5), p(0.4)),
+    ]);
+    assert_eq!(data.0.len(), 2);
+    assert_eq!(data.0[0].frequency, f(50.0));
+    assert_eq!(data.0[0].characteristic.len(), 2);
+    assert!(data.0[0].is_sorted_by_flux_density());
+    assert_eq!(data.0[1].characteristic.len(), 1);
+    ```
+     */
+    pub fn from_triples(
+        triples: impl IntoIterator<Item = (Frequency, MagneticFluxDensity, SpecificPower)>,
+    ) -> IronLossData {
+        return Self::from_triples_with_tolerance(triples, Frequency::new::<hertz>(0.0));
+    }
+
+    /**
+    Like [`from_triples`](IronLossData::from_triples), but a triple is added to
+    an existing characteristic if the absolute difference between their
+    frequencies is at most `freq_tolerance`. The characteristic keeps the
+    frequency of the first triple which created it.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData::from_triples_with_tolerance(
+        [(f(50.0), b(0.5), p(0.4)), (f(50.01), b(1.0), p(1.3))],
+        f(0.1),
+    );
+    assert_eq!(data.0.len(), 1);
+    assert_eq!(data.0[0].frequency, f(50.0));
+    ```
+     */
+    pub fn from_triples_with_tolerance(
+        triples: impl IntoIterator<Item = (Frequency, MagneticFluxDensity, SpecificPower)>,
+        freq_tolerance: Frequency,
+    ) -> IronLossData {
+        let mut groups: Vec<(Frequency, Vec<FluxDensityLossPair>)> = Vec::new();
+        for (frequency, flux_density, specific_loss) in triples {
+            let pair = FluxDensityLossPair::new(flux_density, specific_loss);
+            match groups
+                .iter_mut()
+                .find(|(group_frequency, _)| (*group_frequency - frequency).abs() <= freq_tolerance)
+            {
+                Some((_, pairs)) => pairs.push(pair),
+                None => groups.push((frequency, vec![pair])),
+            }
+        }
+        return IronLossData(
+            groups
+                .into_iter()
+                .map(|(frequency, pairs)| IronLossCharacteristic::new(frequency, pairs))
+                .collect(),
+        );
+    }
+
+    /**
+    Performs least-square fitting of all the datapoints in `self` into the loss
+    equation using the [`argmin`]. If the fitting succeeds, the raw
+    [`argmin::core::OptimizationResult`] is returned, which can then be
+    examined. In particular, the coefficients can be retrieved with the
+    [`State::get_best_param`](`argmin::core::State::get_best_param`). As a
+    convencience wrapper, a [`TryFrom<IronLossData>`] implementation exists for
+    [`JordanModel`], which calls
+    [`solve_for_coefficients`](IronLossData::solve_for_coefficients) and then
+    unpacks the coefficients.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    // Expose the get_best_param method
+    use argmin::core::State;
+
+    // First characteristic
+    let frequency = Frequency::new::<hertz>(50.0);
+    let mut datapoints = Vec::new();
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.5),
+        SpecificPower::new::<watt_per_kilogram>(2.0)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.6),
+        SpecificPower::new::<watt_per_kilogram>(2.5)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.7),
+        SpecificPower::new::<watt_per_kilogram>(3.2)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.8),
+        SpecificPower::new::<watt_per_kilogram>(4.0)
+    ));
+    let lc_50 = IronLossCharacteristic::new(frequency, datapoints);
+
+    // Second characteristic
+    let frequency = Frequency::new::<hertz>(100.0);
+    let mut datapoints = Vec::new();
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.5),
+        SpecificPower::new::<watt_per_kilogram>(5.0)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.6),
+        SpecificPower::new::<watt_per_kilogram>(6.0)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.7),
+        SpecificPower::new::<watt_per_kilogram>(8.0)
+    ));
+    datapoints.push(FluxDensityLossPair::new(
+        MagneticFluxDensity::new::<tesla>(0.8),
+        SpecificPower::new::<watt_per_kilogram>(12.0)
+    ));
+    let lc_100 = IronLossCharacteristic::new(frequency, datapoints);
+
+    let iron_loss_data = IronLossData(vec![lc_50, lc_100]);
+    let res = iron_loss_data.solve_for_coefficients().expect("fitting succeded");
+    let c = res.state.get_best_param().expect("must contain coefficients");
+
+    // First element is the hysteresis coefficient
+    approx::assert_abs_diff_eq!(c[0], 9.528, epsilon=1e-3);
+
+    // Second element is the eddy current coefficient
+    approx::assert_abs_diff_eq!(c[1], 5.265, epsilon=1e-3);
+    ```
+     */
+    pub fn solve_for_coefficients(
+        &self,
+    ) -> Result<FitLossCurveResult, FailedCoefficientCalculation> {
+        return self.solve_for_coefficients_with_config(SolverConfig::default());
+    }
+
+    /**
+    Like [`IronLossData::solve_for_coefficients`], but uses the settings given
+    in `config` for the [`NelderMead`] solver.
+
+    # Examples
+
+    ```
+    use argmin::core::State;
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData::from_triples([
+        (f(50.0), b(0.5), p(0.2)),
+        (f(50.0), b(1.5), p(1.6)),
+        (f(200.0), b(0.5), p(1.3)),
+        (f(200.0), b(1.5), p(12.0)),
+    ]);
+    let config = SolverConfig {
+        max_iterations: 500,
+        min_iterations: 50,
+        ..Default::default()
+    };
+    let res = data.solve_for_coefficients_with_config(config).unwrap();
+    assert!(res.state.get_iter() >= 50);
+    ```
+     */
+    pub fn solve_for_coefficients_with_config(
+        &self,
+        config: SolverConfig,
+    ) -> Result<FitLossCurveResult, FailedCoefficientCalculation> {
+        // Two unknown coefficients
+        self.check_minimum_characteristic_count(2, 2)?;
+        return FitLossCurve::new(self).solve_with_config(config);
+    }
+
+    /**
+    Fits a [`JordanModel`] into the datapoints of `self`, weighting the squared
+    error of each datapoint with the weight given for its frequency in
+    `weights`.
+
+    This is useful if the losses at certain frequencies (e.g. the operating
+    frequency of a motor) are more important than others. Frequencies which
+    are not contained in `weights` have a weight of 1. With all weights being
+    1, the result is identical to that of the [`TryFrom<IronLossData>`]
+    implementation for [`JordanModel`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData::from_triples([
+        (f(50.0), b(0.5), p(0.2)),
+        (f(50.0), b(1.5), p(1.6)),
+        (f(200.0), b(0.5), p(1.3)),
+        (f(200.0), b(1.5), p(12.0)),
+    ]);
+    let unweighted = JordanModel::try_from(&data).unwrap();
+    let weighted = data.weighted_fit(&[(f(200.0), 100.0)]).unwrap();
+
+    // The weighted model reproduces the 200 Hz datapoints better
+    let error = |model: &JordanModel| (model.losses(b(1.5), f(200.0)) - p(12.0)).abs();
+    assert!(error(&weighted) <= error(&unweighted));
+    ```
+     */
+    pub fn weighted_fit(
+        &self,
+        weights: &[(Frequency, f64)],
+    ) -> Result<JordanModel, FailedCoefficientCalculation> {
+        // Two unknown coefficients
+        self.check_minimum_characteristic_count(2, 2)?;
+        let res = FitLossCurve::new(self)
+            .with_frequency_weights(weights)
+            .solve()?;
+        return jordan_model_from_result(res);
+    }
+
+    /**
+    Returns an iterator over the residuals of the given `model` with respect to
+    all datapoints in `self`.
+
+    Each yielded item is a tuple `(frequency, flux_density, residual)`, where
+    the residual is the difference between the losses predicted by
+    [`JordanModel::losses`] and the measured losses (`predicted - measured`).
+    The datapoints are traversed characteristic by characteristic. This is
+    useful to identify outliers or frequency ranges where the model fails.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+    let data = IronLossData(vec![IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(1.5)],
+        &[SpecificPower::new::<watt_per_kilogram>(2.0)],
+    )]);
+
+    let (frequency, flux_density, residual) = data.residuals(&model).next().unwrap();
+    assert_eq!(frequency.get::<hertz>(), 50.0);
+    assert_eq!(flux_density.get::<tesla>(), 1.5);
+    assert_eq!(residual.get::<watt_per_kilogram>(), -0.5);
+    ```
+     */
+    pub fn residuals<'a>(
+        &'a self,
+        model: &'a JordanModel,
+    ) -> impl Iterator<Item = (Frequency, MagneticFluxDensity, SpecificPower)> + 'a {
+        return self
+            .residuals_with(move |flux_density, frequency| model.losses(flux_density, frequency));
+    }
+
+    /**
+    Like [`IronLossData::residuals`], but for an arbitrary loss model given as
+    a closure. The closure receives the flux density and the frequency of a
+    datapoint and returns the predicted specific losses. This allows examining
+    the residuals of other models such as the
+    [`SteinmetzModel`](crate::iron_losses::steinmetz_model::SteinmetzModel) or
+    of user-defined models.
+     */
+    pub fn residuals_with<'a, F>(
+        &'a self,
+        model: F,
+    ) -> impl Iterator<Item = (Frequency, MagneticFluxDensity, SpecificPower)> + 'a
+    where
+        F: Fn(MagneticFluxDensity, Frequency) -> SpecificPower + Clone + 'a,
+    {
+        return self.0.iter().flat_map(move |characteristic| {
+            let frequency = characteristic.frequency;
+            let model = model.clone();
+            characteristic.characteristic.iter().map(move |pair| {
+                (
+                    frequency,
+                    pair.flux_density,
+                    model(pair.flux_density, frequency) - pair.specific_loss,
+                )
+            })
+        });
+    }
+
+    /**
+    Returns `(frequency, flux_density, measured, predicted)` tuples for all
+    datapoints in `self`, where `predicted` are the losses calculated by
+    [`JordanModel::losses`].
+
+    The datapoints are traversed characteristic by characteristic, which is
+    the same order as in [`IronLossData::solve_for_coefficients`]. The output
+    can be used directly for plotting or for computing residual statistics.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+    let data = IronLossData(vec![IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(1.5)],
+        &[SpecificPower::new::<watt_per_kilogram>(2.0)],
+    )]);
+
+    let (_, _, measured, predicted) = data.predict_all(&model)[0];
+    assert_eq!(measured.get::<watt_per_kilogram>(), 2.0);
+    assert_eq!(predicted.get::<watt_per_kilogram>(), 1.5);
+    ```
+     */
+    pub fn predict_all(
+        &self,
+        model: &JordanModel,
+    ) -> Vec<(Frequency, MagneticFluxDensity, SpecificPower, SpecificPower)> {
+        return self
+            .0
+            .iter()
+            .flat_map(|characteristic| {
+                let frequency = characteristic.frequency;
+                characteristic.characteristic.iter().map(move |pair| {
+                    (
+                        frequency,
+                        pair.flux_density,
+                        pair.specific_loss,
+                        model.losses(pair.flux_density, frequency),
+                    )
+                })
+            })
+            .collect();
+    }
+
+    /**
+    Returns a copy of `self` where all losses are converted to the
+    `reference_frequency` using the given `model`.
+
+    Each specific loss value is divided by the losses predicted by
+    [`JordanModel::losses`] at the frequency of its characteristic and
+    multiplied by the prediction at `reference_frequency` (both for the flux
+    density of the datapoint). The frequency of all returned characteristics is
+    `reference_frequency`. This allows comparing loss data from different
+    sources, which has been measured at different frequencies. Datapoints for
+    which the model predicts zero losses (e.g. at a flux density of zero) are
+    replaced by the prediction at `reference_frequency`.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+    let data = IronLossData(vec![IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(100.0),
+        &[MagneticFluxDensity::new::<tesla>(1.5)],
+        &[SpecificPower::new::<watt_per_kilogram>(4.0)],
+    )]);
+
+    let normalized = data.normalize_to_frequency(&model, Frequency::new::<hertz>(50.0));
+    assert_eq!(normalized.0[0].frequency, Frequency::new::<hertz>(50.0));
+    approx::assert_abs_diff_eq!(
+        normalized.0[0].characteristic[0]
+            .specific_loss
+            .get::<watt_per_kilogram>(),
+        1.5,
+        epsilon = 1e-12
+    );
+    ```
+     */
+    pub fn normalize_to_frequency(
+        &self,
+        model: &JordanModel,
+        reference_frequency: Frequency,
+    ) -> IronLossData {
+        let characteristics = self
+            .0
+            .iter()
+            .map(|characteristic| {
+                let pairs = characteristic
+                    .characteristic
+                    .iter()
+                    .map(|pair| {
+                        let reference = model.losses(pair.flux_density, reference_frequency);
+                        let predicted = model.losses(pair.flux_density, characteristic.frequency);
+                        let specific_loss = if predicted.get::<watt_per_kilogram>() == 0.0 {
+                            reference
+                        } else {
+                            pair.specific_loss * (reference / predicted).get::<ratio>()
+                        };
+                        FluxDensityLossPair::new(pair.flux_density, specific_loss)
+                    })
+                    .collect();
+                IronLossCharacteristic::new(reference_frequency, pairs)
+            })
+            .collect();
+        return IronLossData(characteristics);
+    }
+
+    /**
+    Performs a leave-one-out cross-validation of the [`JordanModel`] fitted to
+    `self`.
+
+    For each characteristic, a [`JordanModel`] is fitted to all other
+    characteristics and then used to predict the losses of the left-out
+    characteristic. The result contains one `(frequency, rms_error)` pair per
+    characteristic (in the order of `self`), where `rms_error` is the root mean
+    square error of the prediction in W/kg. Large errors for individual
+    characteristics indicate that the model does not generalize well to these
+    frequencies.
+
+    Since the fitting is performed once per characteristic and each fit
+    involves all remaining characteristics, the runtime is `O(N²)` with `N`
+    being the number of characteristics. Therefore, this method is
+    comparatively slow.
+
+    At least three characteristics are needed, since each fit requires two
+    characteristics (see [`IronLossData::solve_for_coefficients`]).
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let f = |v| Frequency::new::<hertz>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let data = IronLossData::from_triples([
+        (f(50.0), b(0.5), p(0.2)),
+        (f(50.0), b(1.5), p(1.6)),
+        (f(100.0), b(0.5), p(0.5)),
+        (f(100.0), b(1.5), p(4.2)),
+        (f(200.0), b(0.5), p(1.3)),
+        (f(200.0), b(1.5), p(12.0)),
+    ]);
+    let errors = data.cross_validate().unwrap();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].0, f(50.0));
+    ```
+     */
+    pub fn cross_validate(&self) -> Result<Vec<(Frequency, f64)>, FailedCoefficientCalculation> {
+        // Two characteristics are needed for the fit, plus the left-out one
+        self.check_minimum_characteristic_count(3, 2)?;
+
+        let mut errors = Vec::with_capacity(self.0.len());
+        for (index, characteristic) in self.0.iter().enumerate() {
+            let mut remaining = self.0.clone();
+            remaining.remove(index);
+            let model = JordanModel::try_from(IronLossData(remaining))?;
+
+            let frequency = characteristic.frequency;
+            let sum_of_squares: f64 = characteristic
+                .characteristic
+                .iter()
+                .map(|pair| {
+                    (model.losses(pair.flux_density, frequency) - pair.specific_loss)
+                        .get::<watt_per_kilogram>()
+                        .powi(2)
+                })
+                .sum();
+            let rms_error = (sum_of_squares / characteristic.characteristic.len() as f64).sqrt();
+            errors.push((frequency, rms_error));
+        }
+        return Ok(errors);
+    }
+
+    /**
+    Sorts the characteristics of `self` by their frequency in ascending order.
+    The sort is stable, i.e. characteristics with equal frequencies keep their
+    relative order.
+     */
+    pub fn sort_by_frequency(&mut self) {
+        self.0.sort_by(|a, b| {
+            a.frequency
+                .get::<hertz>()
+                .total_cmp(&b.frequency.get::<hertz>())
+        });
+    }
+
+    /**
+    Removes all characteristics whose frequency has already occurred earlier
+    in `self`, i.e. only the first characteristic for each frequency is kept.
+    Contrary to [`Vec::dedup`], the duplicates do not need to be consecutive.
+
+    Duplicated frequencies lead to the corresponding datapoints being weighted
+    multiple times when fitting a loss model, which is usually not intended.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut data = IronLossData(vec![
+        IronLossCharacteristic::new(Frequency::new::<hertz>(100.0), Vec::new()),
+        IronLossCharacteristic::new(Frequency::new::<hertz>(50.0), Vec::new()),
+        IronLossCharacteristic::new(Frequency::new::<hertz>(100.0), Vec::new()),
+    ]);
+    data.dedup_by_frequency();
+    assert_eq!(data.0.len(), 2);
+
+    data.sort_by_frequency();
+    assert_eq!(data.0[0].frequency.get::<hertz>(), 50.0);
+    assert!(data.contains_frequency(Frequency::new::<hertz>(100.0)));
+    assert!(!data.contains_frequency(Frequency::new::<hertz>(200.0)));
+    ```
+     */
+    pub fn dedup_by_frequency(&mut self) {
+        let mut seen: Vec<Frequency> = Vec::with_capacity(self.0.len());
+        self.0.retain(|characteristic| {
+            if seen.contains(&characteristic.frequency) {
+                return false;
+            }
+            seen.push(characteristic.frequency);
+            return true;
+        });
+    }
+
+    /// Returns whether `self` contains a characteristic for the frequency `f`.
+    pub fn contains_frequency(&self, f: Frequency) -> bool {
+        return self
+            .0
+            .iter()
+            .any(|characteristic| characteristic.frequency == f);
+    }
+
+    /**
+    Returns the frequencies of all characteristics in `self`, sorted in
+    ascending order and without duplicates.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let data = IronLossData(vec![
+        IronLossCharacteristic::new(Frequency::new::<hertz>(100.0), Vec::new()),
+        IronLossCharacteristic::new(Frequency::new::<hertz>(50.0), Vec::new()),
+        IronLossCharacteristic::new(Frequency::new::<hertz>(100.0), Vec::new()),
+    ]);
+    assert_eq!(
+        data.frequency_list(),
+        vec![Frequency::new::<hertz>(50.0), Frequency::new::<hertz>(100.0)]
+    );
+    assert_eq!(data.frequency_count(), 2);
+    ```
+     */
+    pub fn frequency_list(&self) -> Vec<Frequency> {
+        let mut frequencies: Vec<Frequency> = self
+            .0
+            .iter()
+            .map(|characteristic| characteristic.frequency)
+            .collect();
+        frequencies.sort_by(|a, b| a.get::<hertz>().total_cmp(&b.get::<hertz>()));
+        frequencies.dedup();
+        return frequencies;
+    }
+
+    /**
+    Returns the number of distinct frequencies in `self`. This is the same as
+    `self.frequency_list().len()`, but does not allocate.
+     */
+    pub fn frequency_count(&self) -> usize {
+        return self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(idx, characteristic)| {
+                !self.0[..*idx]
+                    .iter()
+                    .any(|other| other.frequency == characteristic.frequency)
+            })
+            .count();
+    }
+
+    /**
+    Returns the characteristic whose frequency is closest to `frequency` (by
+    absolute difference) or `None` if `self` does not contain any
+    characteristics. If two characteristics are equally close, the first one
+    is returned. See
+    [`characteristic_exact`](IronLossData::characteristic_exact) for a lookup
+    which only accepts exactly matching frequencies.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let data = IronLossData(vec![
+        IronLossCharacteristic::new(Frequency::new::<hertz>(50.0), Vec::new()),
+        IronLossCharacteristic::new(Frequency::new::<hertz>(400.0), Vec::new()),
+    ]);
+
+    let nearest = data
+        .characteristic_at_frequency(Frequency::new::<hertz>(150.0))
+        .unwrap();
+    assert_eq!(nearest.frequency.get::<hertz>(), 50.0);
+
+    assert!(data.characteristic_exact(Frequency::new::<hertz>(150.0)).is_none());
+    assert!(data.characteristic_exact(Frequency::new::<hertz>(400.0)).is_some());
+    ```
+     */
+    pub fn characteristic_at_frequency(
+        &self,
+        frequency: Frequency,
+    ) -> Option<&IronLossCharacteristic> {
+        let target = frequency.get::<hertz>();
+        return self.0.iter().min_by(|a, b| {
+            let dist_a = (a.frequency.get::<hertz>() - target).abs();
+            let dist_b = (b.frequency.get::<hertz>() - target).abs();
+            dist_a.total_cmp(&dist_b)
+        });
+    }
+
+    /**
+    Returns the first characteristic whose frequency is exactly equal to
+    `frequency` or `None` if no such characteristic exists.
+     */
+    pub fn characteristic_exact(&self, frequency: Frequency) -> Option<&IronLossCharacteristic> {
+        return self
+            .0
+            .iter()
+            .find(|characteristic| characteristic.frequency == frequency);
+    }
+
+    /**
+    Returns the smallest and the largest frequency of all characteristics in
+    `self` or `None` if `self` does not contain any characteristics.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let data = IronLossData(vec![
+        IronLossCharacteristic::from_vecs(
+            Frequency::new::<hertz>(400.0),
+            &[MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(1.0)],
+            &[SpecificPower::new::<watt_per_kilogram>(5.0), SpecificPower::new::<watt_per_kilogram>(15.0)],
+        ),
+        IronLossCharacteristic::from_vecs(
+            Frequency::new::<hertz>(50.0),
+            &[MagneticFluxDensity::new::<tesla>(1.5)],
+            &[SpecificPower::new::<watt_per_kilogram>(2.0)],
+        ),
+    ]);
+
+    let (f_min, f_max) = data.frequency_range().unwrap();
+    assert_eq!(f_min.get::<hertz>(), 50.0);
+    assert_eq!(f_max.get::<hertz>(), 400.0);
+
+    let (b_min, b_max) = data.flux_density_range().unwrap();
+    assert_eq!(b_min.get::<tesla>(), 0.5);
+    assert_eq!(b_max.get::<tesla>(), 1.5);
+
+    assert_eq!(data.total_datapoint_count(), 3);
+    assert!(IronLossData(Vec::new()).frequency_range().is_none());
+    ```
+     */
+    pub fn frequency_range(&self) -> Option<(Frequency, Frequency)> {
+        return min_max(self.0.iter().map(|characteristic| characteristic.frequency));
+    }
+
+    /**
+    Returns the smallest and the largest flux density of all datapoints in
+    `self` or `None` if `self` does not contain any datapoints.
+     */
+    pub fn flux_density_range(&self) -> Option<(MagneticFluxDensity, MagneticFluxDensity)> {
+        return min_max(
+            self.0
+                .iter()
+                .flat_map(|characteristic| characteristic.characteristic.iter())
+                .map(|pair| pair.flux_density),
+        );
+    }
+
+    /// Returns the number of datapoints over all characteristics in `self`.
+    pub fn total_datapoint_count(&self) -> usize {
+        return self
+            .0
+            .iter()
+            .map(|characteristic| characteristic.characteristic.len())
+            .sum();
+    }
+
+    /**
+    Checks whether `self` contains at least `minimum_characteristics`
+    characteristics and whether each characteristic contains at least
+    `minimum_datapoints` datapoints. Otherwise, a loss model fitted to `self`
+    is not well-determined, which is why this check is performed before the
+    fitting in e.g. [`IronLossData::solve_for_coefficients`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
 
-        let hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(solution[0]);
-        let eddy_current_coefficient = SpecificPower::new::<watt_per_kilogram>(solution[1]);
+    let data = IronLossData(vec![IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(1.0)],
+        &[SpecificPower::new::<watt_per_kilogram>(0.5), SpecificPower::new::<watt_per_kilogram>(1.5)],
+    )]);
+
+    assert!(data.check_minimum_characteristic_count(1, 2).is_ok());
+    assert!(matches!(
+        JordanModel::try_from(&data),
+        Err(FailedCoefficientCalculation::InsufficientData {
+            characteristics_provided: 1,
+            minimum_required: 2
+        })
+    ));
+    ```
+     */
+    pub fn check_minimum_characteristic_count(
+        &self,
+        minimum_characteristics: usize,
+        minimum_datapoints: usize,
+    ) -> Result<(), FailedCoefficientCalculation> {
+        if self.0.len() < minimum_characteristics {
+            return Err(FailedCoefficientCalculation::InsufficientData {
+                characteristics_provided: self.0.len(),
+                minimum_required: minimum_characteristics,
+            });
+        }
+        for (index, characteristic) in self.0.iter().enumerate() {
+            if characteristic.characteristic.len() < minimum_datapoints {
+                return Err(FailedCoefficientCalculation::InsufficientDatapoints {
+                    characteristic_index: index,
+                    datapoints_provided: characteristic.characteristic.len(),
+                    minimum_required: minimum_datapoints,
+                });
+            }
+        }
+        return Ok(());
+    }
 
-        return Ok(JordanModel {
-            hysteresis_coefficient,
-            eddy_current_coefficient,
-        });
+    /**
+    Concatenates all datapoints of `self` into three vectors of equal length
+    (frequency, flux density and specific losses). The datapoints are ordered
+    by characteristic first and then by their position within the
+    characteristic.
+     */
+    pub(crate) fn flatten(&self) -> (Vec<Frequency>, Vec<MagneticFluxDensity>, Vec<SpecificPower>) {
+        let mut num_elems: usize = 0;
+        for characteristic in self.0.iter() {
+            num_elems += characteristic.characteristic.len();
+        }
+        let mut frequencies_flat: Vec<Frequency> = Vec::with_capacity(num_elems);
+        let mut flux_density_flat: Vec<MagneticFluxDensity> = Vec::with_capacity(num_elems);
+        let mut specific_losses_flat: Vec<SpecificPower> = Vec::with_capacity(num_elems);
+
+        for characteristic in self.0.iter() {
+            let frequency = characteristic.frequency;
+
+            for flux_density_and_specific_loss in characteristic.characteristic.iter().cloned() {
+                frequencies_flat.push(frequency);
+                flux_density_flat.push(flux_density_and_specific_loss.flux_density);
+                specific_losses_flat.push(flux_density_and_specific_loss.specific_loss);
+            }
+        }
+
+        return (frequencies_flat, flux_density_flat, specific_losses_flat);
+    }
+
+    /**
+    Calculates the coefficient of determination R² of the loss `model` with
+    respect to all datapoints in `self`. The closure receives the flux density
+    and frequency of a datapoint and returns the predicted specific losses.
+
+    If all measured losses are identical, the total sum of squares is zero and
+    the result is not finite.
+     */
+    pub(crate) fn coefficient_of_determination<F>(&self, model: F) -> f64
+    where
+        F: Fn(MagneticFluxDensity, Frequency) -> SpecificPower,
+    {
+        let (frequencies, flux_densities, specific_losses) = self.flatten();
+        if specific_losses.is_empty() {
+            return f64::NAN;
+        }
+
+        let mean = specific_losses
+            .iter()
+            .map(|p| p.get::<watt_per_kilogram>())
+            .sum::<f64>()
+            / specific_losses.len() as f64;
+
+        let mut ss_res = 0.0;
+        let mut ss_tot = 0.0;
+        for (fi, (bi, pi)) in frequencies
+            .iter()
+            .zip(flux_densities.iter().zip(specific_losses.iter()))
+        {
+            let measured = pi.get::<watt_per_kilogram>();
+            let predicted = model(*bi, *fi).get::<watt_per_kilogram>();
+            ss_res += (measured - predicted).powi(2);
+            ss_tot += (measured - mean).powi(2);
+        }
+        return 1.0 - ss_res / ss_tot;
+    }
+}
+
+/**
+Returns the smallest and the largest value of `values` or `None` if `values` is
+empty.
+ */
+fn min_max<T: PartialOrd + Copy>(values: impl Iterator<Item = T>) -> Option<(T, T)> {
+    return values.fold(None, |acc, value| match acc {
+        None => Some((value, value)),
+        Some((min, max)) => Some((
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        )),
+    });
+}
+
+impl TryFrom<IronLossData> for JordanModel {
+    type Error = FailedCoefficientCalculation;
+    fn try_from(value: IronLossData) -> Result<Self, Self::Error> {
+        return (&value).try_into();
+    }
+}
+
+impl TryFrom<&IronLossData> for JordanModel {
+    type Error = FailedCoefficientCalculation;
+
+    fn try_from(value: &IronLossData) -> Result<Self, Self::Error> {
+        let res = value.solve_for_coefficients()?;
+        return jordan_model_from_result(res);
     }
 }
 
+/// Unpacks the coefficients of a [`JordanModel`] from an optimization result.
+fn jordan_model_from_result(
+    res: FitLossCurveResult,
+) -> Result<JordanModel, FailedCoefficientCalculation> {
+    let solution = res
+        .state
+        .get_best_param()
+        .ok_or_else(FailedCoefficientCalculation::missing_solution)?;
+
+    let hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(solution[0]);
+    let eddy_current_coefficient = SpecificPower::new::<watt_per_kilogram>(solution[1]);
+
+    return Ok(JordanModel {
+        hysteresis_coefficient,
+        eddy_current_coefficient,
+    });
+}
+
 /**
 A iron loss characteristic for a specific frequency.
 
@@ -602,19 +1994,77 @@ pub struct IronLossCharacteristic {
     #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
     pub frequency: Frequency,
     /// Collection of amplitude - losses datapoints. The order of these
-    /// datapoints does not matter.
+    /// datapoints does not matter. [`IronLossCharacteristic::new`] sorts them
+    /// by their flux density, but a deserialized characteristic keeps the
+    /// order of the serialized representation (see
+    /// [`IronLossCharacteristic::sort_by_flux_density`]).
     pub characteristic: Vec<FluxDensityLossPair>,
 }
 
 impl IronLossCharacteristic {
     /**
-    Creates a new [`IronLossCharacteristic`] from its fields.
+    Creates a new [`IronLossCharacteristic`] from its fields. The datapoints
+    are sorted by their flux density (see
+    [`IronLossCharacteristic::sort_by_flux_density`]), so that e.g. the
+    serialized output does not depend on the order in which the datapoints
+    were collected.
      */
     pub fn new(frequency: Frequency, characteristic: Vec<FluxDensityLossPair>) -> Self {
-        return Self {
+        let mut this = Self {
             frequency,
             characteristic,
         };
+        this.sort_by_flux_density();
+        return this;
+    }
+
+    /**
+    Sorts the datapoints of `self` by their flux density in ascending order.
+    The sort is stable, i.e. datapoints with equal flux densities keep their
+    relative order.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+    let mut characteristic = IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[b(0.5), b(1.0)],
+        &[p(0.4), p(1.3)],
+    );
+    characteristic.characteristic.push(FluxDensityLossPair::new(b(0.7), p(0.8)));
+    assert!(!characteristic.is_sorted_by_flux_density());
+
+    characteristic.sort_by_flux_density();
+    assert!(characteristic.is_sorted_by_flux_density());
+    assert_eq!(characteristic.characteristic[1].flux_density, b(0.7));
+    ```
+     */
+    pub fn sort_by_flux_density(&mut self) {
+        self.characteristic.sort_by(|a, b| {
+            a.flux_density
+                .get::<tesla>()
+                .total_cmp(&b.flux_density.get::<tesla>())
+        });
+    }
+
+    /**
+    Returns `true` if the datapoints of `self` are sorted by their flux density
+    in ascending order. This is always the case for characteristics created
+    via [`IronLossCharacteristic::new`] or
+    [`IronLossCharacteristic::from_vecs`], unless the datapoints have been
+    modified afterwards. Deserialized characteristics are not sorted
+    automatically.
+     */
+    pub fn is_sorted_by_flux_density(&self) -> bool {
+        return self
+            .characteristic
+            .windows(2)
+            .all(|pair| pair[0].flux_density <= pair[1].flux_density);
     }
 
     /**
@@ -623,7 +2073,9 @@ impl IronLossCharacteristic {
 
     Each entry of the `flux_densities` vector is paired with the same-index
     entry of `specific_losses` to form a [`FluxDensityLossPair`]. If one slice
-    is longer than the other, the surplus entries are discarded.
+    is longer than the other, the surplus entries are discarded. As in
+    [`IronLossCharacteristic::new`], the datapoints are sorted by their flux
+    density.
      */
     pub fn from_vecs(
         frequency: Frequency,
@@ -642,6 +2094,409 @@ impl IronLossCharacteristic {
 
         return Self::new(frequency, characteristic);
     }
+
+    /**
+    Creates a new [`IronLossCharacteristic`] by sampling a polynomial fit of
+    the specific losses over the flux density, as it is given in some older
+    datasheets.
+
+    The polynomial `p(B) = Σ a_i * B^i` is defined by its `coefficients`
+    `[a_0, a_1, a_2, ...]`, where `B` is in T and `p(B)` is in W/kg. It is
+    evaluated at `num_samples` uniformly spaced flux densities from the lower
+    to the upper bound of `flux_density_range` (both inclusive). If
+    `num_samples` is 1, only the lower bound is sampled, if it is 0, the
+    returned characteristic is empty.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    // p(B) = 0.5 * B + 1.0 * B²
+    let characteristic = IronLossCharacteristic::from_polynomial(
+        Frequency::new::<hertz>(50.0),
+        &[0.0, 0.5, 1.0],
+        (MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(1.5)),
+        3,
+    );
+    assert_eq!(characteristic.characteristic.len(), 3);
+    let pair = &characteristic.characteristic[2];
+    assert_eq!(pair.flux_density.get::<tesla>(), 1.5);
+    assert_eq!(pair.specific_loss.get::<watt_per_kilogram>(), 0.75 + 2.25);
+    ```
+     */
+    pub fn from_polynomial(
+        frequency: Frequency,
+        coefficients: &[f64],
+        flux_density_range: (MagneticFluxDensity, MagneticFluxDensity),
+        num_samples: usize,
+    ) -> Self {
+        let lower = flux_density_range.0.get::<tesla>();
+        let upper = flux_density_range.1.get::<tesla>();
+        let step = if num_samples > 1 {
+            (upper - lower) / (num_samples - 1) as f64
+        } else {
+            0.0
+        };
+
+        let characteristic = (0..num_samples)
+            .map(|index| {
+                let flux_density = lower + step * index as f64;
+
+                // Horner's method
+                let specific_loss = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, coefficient| acc * flux_density + coefficient);
+                FluxDensityLossPair::new(
+                    MagneticFluxDensity::new::<tesla>(flux_density),
+                    SpecificPower::new::<watt_per_kilogram>(specific_loss),
+                )
+            })
+            .collect();
+        return Self::new(frequency, characteristic);
+    }
+
+    /**
+    Returns the specific losses at the given `flux_density` by linearly
+    interpolating between the datapoints of `self`. Since the datapoints are
+    not required to be ordered, they are sorted by their flux density first.
+    If `flux_density` is outside the range covered by the datapoints, `None`
+    is returned. See
+    [`loss_at_flux_density_extrapolated`](IronLossCharacteristic::loss_at_flux_density_extrapolated)
+    for a variant which extrapolates instead.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let characteristic = IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(1.0), MagneticFluxDensity::new::<tesla>(0.5)],
+        &[SpecificPower::new::<watt_per_kilogram>(2.0), SpecificPower::new::<watt_per_kilogram>(1.0)],
+    );
+
+    let losses = characteristic
+        .loss_at_flux_density(MagneticFluxDensity::new::<tesla>(0.75))
+        .unwrap();
+    approx::assert_abs_diff_eq!(losses.get::<watt_per_kilogram>(), 1.5, epsilon = 1e-12);
+
+    assert!(characteristic.loss_at_flux_density(MagneticFluxDensity::new::<tesla>(1.5)).is_none());
+
+    // Log-log extrapolation: The losses are proportional to B² here
+    let losses = characteristic
+        .loss_at_flux_density_extrapolated(MagneticFluxDensity::new::<tesla>(2.0))
+        .unwrap();
+    approx::assert_abs_diff_eq!(losses.get::<watt_per_kilogram>(), 4.0, epsilon = 1e-12);
+    ```
+     */
+    pub fn loss_at_flux_density(&self, flux_density: MagneticFluxDensity) -> Option<SpecificPower> {
+        let sorted = self.sorted_datapoints();
+        let first = sorted.first()?;
+        let last = sorted.last()?;
+        if flux_density < first.flux_density || flux_density > last.flux_density {
+            return None;
+        }
+        if sorted.len() == 1 {
+            return Some(first.specific_loss);
+        }
+
+        for window in sorted.windows(2) {
+            let (left, right) = (&window[0], &window[1]);
+            if flux_density <= right.flux_density {
+                let width = right.flux_density - left.flux_density;
+                if width.get::<tesla>() == 0.0 {
+                    return Some(left.specific_loss);
+                }
+                let fraction: f64 = ((flux_density - left.flux_density) / width).get::<ratio>();
+                return Some(
+                    left.specific_loss + (right.specific_loss - left.specific_loss) * fraction,
+                );
+            }
+        }
+        return None;
+    }
+
+    /**
+    Like [`loss_at_flux_density`](IronLossCharacteristic::loss_at_flux_density),
+    but extrapolates outside the range covered by the datapoints instead of
+    returning `None`.
+
+    The extrapolation is linear in a log-log representation, i.e. the losses
+    are assumed to follow `p = p1 * (B / B1)^k` with the exponent `k` being
+    determined from the two outermost datapoints on the respective side. This
+    matches the behaviour of most loss curves much better than a linear
+    extrapolation. `None` is returned if `self` has less than two datapoints or
+    if one of the datapoints used for the extrapolation has a non-positive flux
+    density or loss value.
+     */
+    pub fn loss_at_flux_density_extrapolated(
+        &self,
+        flux_density: MagneticFluxDensity,
+    ) -> Option<SpecificPower> {
+        if let Some(losses) = self.loss_at_flux_density(flux_density) {
+            return Some(losses);
+        }
+
+        let sorted = self.sorted_datapoints();
+        if sorted.len() < 2 {
+            return None;
+        }
+        let (p1, p2) = if flux_density < sorted[0].flux_density {
+            (&sorted[0], &sorted[1])
+        } else {
+            (&sorted[sorted.len() - 1], &sorted[sorted.len() - 2])
+        };
+
+        let b1 = p1.flux_density.get::<tesla>();
+        let b2 = p2.flux_density.get::<tesla>();
+        let l1 = p1.specific_loss.get::<watt_per_kilogram>();
+        let l2 = p2.specific_loss.get::<watt_per_kilogram>();
+        if !(b1 > 0.0 && b2 > 0.0 && l1 > 0.0 && l2 > 0.0) || b1 == b2 {
+            return None;
+        }
+
+        let exponent = (l2 / l1).ln() / (b2 / b1).ln();
+        let losses = l1 * (flux_density.get::<tesla>() / b1).powf(exponent);
+        return Some(SpecificPower::new::<watt_per_kilogram>(losses));
+    }
+
+    /**
+    Returns a copy of `self` whose specific losses are multiplied by
+    `fill_factor`.
+
+    Loss data from manufacturers is usually measured on Epstein frames, while
+    the lamination stack of a core has an iron fill factor below 1. This is the
+    loss counterpart to the fill factor correction applied in
+    [`FerromagneticPermeability::from_magnetization`](crate::relative_permeability::FerromagneticPermeability::from_magnetization).
+    An [`InvalidInputData::IronFillFactor`] is returned if `fill_factor` is not
+    within (0, 1].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let characteristic = IronLossCharacteristic::new(
+        Frequency::new::<hertz>(50.0),
+        vec![FluxDensityLossPair::new(
+            MagneticFluxDensity::new::<tesla>(1.5),
+            SpecificPower::new::<watt_per_kilogram>(2.0),
+        )],
+    );
+    let scaled = characteristic.scale_by_iron_fill_factor(0.95).unwrap();
+    approx::assert_abs_diff_eq!(
+        scaled.characteristic[0].specific_loss.get::<watt_per_kilogram>(),
+        1.9,
+        epsilon = 1e-12
+    );
+    assert!(characteristic.scale_by_iron_fill_factor(0.0).is_err());
+    ```
+     */
+    pub fn scale_by_iron_fill_factor(
+        &self,
+        fill_factor: f64,
+    ) -> Result<IronLossCharacteristic, InvalidInputData> {
+        if fill_factor <= 0.0 || fill_factor > 1.0 {
+            return Err(InvalidInputData::IronFillFactor(fill_factor));
+        }
+        return Ok(self.clone() * fill_factor);
+    }
+
+    /**
+    Returns the largest specific loss of all datapoints of `self` or `None` if
+    `self` has no datapoints.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let characteristic = IronLossCharacteristic::from_vecs(
+        Frequency::new::<hertz>(50.0),
+        &[MagneticFluxDensity::new::<tesla>(1.0), MagneticFluxDensity::new::<tesla>(0.5)],
+        &[SpecificPower::new::<watt_per_kilogram>(2.0), SpecificPower::new::<watt_per_kilogram>(1.0)],
+    );
+    assert_eq!(
+        characteristic.max_specific_loss(),
+        Some(SpecificPower::new::<watt_per_kilogram>(2.0))
+    );
+    assert_eq!(
+        characteristic.min_flux_density(),
+        Some(MagneticFluxDensity::new::<tesla>(0.5))
+    );
+
+    let empty = IronLossCharacteristic::new(Frequency::new::<hertz>(50.0), Vec::new());
+    assert!(empty.max_specific_loss().is_none());
+    ```
+     */
+    pub fn max_specific_loss(&self) -> Option<SpecificPower> {
+        return self
+            .characteristic
+            .iter()
+            .map(|pair| pair.specific_loss)
+            .max_by(|a, b| {
+                a.get::<watt_per_kilogram>()
+                    .total_cmp(&b.get::<watt_per_kilogram>())
+            });
+    }
+
+    /**
+    Returns the smallest specific loss of all datapoints of `self` or `None` if
+    `self` has no datapoints.
+     */
+    pub fn min_specific_loss(&self) -> Option<SpecificPower> {
+        return self
+            .characteristic
+            .iter()
+            .map(|pair| pair.specific_loss)
+            .min_by(|a, b| {
+                a.get::<watt_per_kilogram>()
+                    .total_cmp(&b.get::<watt_per_kilogram>())
+            });
+    }
+
+    /**
+    Returns the largest flux density of all datapoints of `self` or `None` if
+    `self` has no datapoints.
+     */
+    pub fn max_flux_density(&self) -> Option<MagneticFluxDensity> {
+        return self
+            .characteristic
+            .iter()
+            .map(|pair| pair.flux_density)
+            .max_by(|a, b| a.get::<tesla>().total_cmp(&b.get::<tesla>()));
+    }
+
+    /**
+    Returns the smallest flux density of all datapoints of `self` or `None` if
+    `self` has no datapoints.
+     */
+    pub fn min_flux_density(&self) -> Option<MagneticFluxDensity> {
+        return self
+            .characteristic
+            .iter()
+            .map(|pair| pair.flux_density)
+            .min_by(|a, b| a.get::<tesla>().total_cmp(&b.get::<tesla>()));
+    }
+
+    /// Returns the datapoints of `self` sorted by their flux density.
+    fn sorted_datapoints(&self) -> Vec<FluxDensityLossPair> {
+        let mut sorted = self.characteristic.clone();
+        sorted.sort_by(|a, b| {
+            a.flux_density
+                .get::<tesla>()
+                .total_cmp(&b.flux_density.get::<tesla>())
+        });
+        return sorted;
+    }
+}
+
+/**
+Orders [`IronLossCharacteristic`]s by their frequency. Characteristics with the
+same frequency are ordered lexicographically by their datapoints (see the
+[`Ord`] implementation of [`FluxDensityLossPair`]). As for the datapoints,
+[`f64::total_cmp`] is used to compare the frequencies.
+ */
+impl Ord for IronLossCharacteristic {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        return self
+            .frequency
+            .get::<hertz>()
+            .total_cmp(&other.frequency.get::<hertz>())
+            .then_with(|| self.characteristic.cmp(&other.characteristic));
+    }
+}
+
+impl PartialOrd for IronLossCharacteristic {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/**
+Two [`IronLossCharacteristic`]s are equal if their frequencies and datapoints
+are equal according to the [`Ord`] implementation, i.e. on the basis of
+[`f64::total_cmp`].
+ */
+impl PartialEq for IronLossCharacteristic {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == std::cmp::Ordering::Equal;
+    }
+}
+
+impl Eq for IronLossCharacteristic {}
+
+/**
+Adds the losses of two [`IronLossCharacteristic`]s.
+
+Only datapoints whose flux density is contained in both characteristics are
+part of the result, all other datapoints are skipped. The returned
+characteristic has the frequency of the left operand, hence both operands
+should have been measured at the same frequency. Together with the
+[`Mul<f64>`](std::ops::Mul) implementation, this allows e.g. averaging data
+from two sources.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let b = |v| MagneticFluxDensity::new::<tesla>(v);
+let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+let source_a = IronLossCharacteristic::new(
+    Frequency::new::<hertz>(50.0),
+    vec![FluxDensityLossPair::new(b(0.5), p(1.0)), FluxDensityLossPair::new(b(1.0), p(3.0))],
+);
+let source_b = IronLossCharacteristic::new(
+    Frequency::new::<hertz>(50.0),
+    vec![FluxDensityLossPair::new(b(1.0), p(4.0)), FluxDensityLossPair::new(b(1.5), p(8.0))],
+);
+
+let average = (&source_a + &source_b) * 0.5;
+assert_eq!(average.characteristic, vec![FluxDensityLossPair::new(b(1.0), p(3.5))]);
+assert_eq!(average.frequency, source_a.frequency);
+```
+ */
+impl std::ops::Add<&IronLossCharacteristic> for &IronLossCharacteristic {
+    type Output = IronLossCharacteristic;
+
+    fn add(self, other: &IronLossCharacteristic) -> IronLossCharacteristic {
+        let characteristic = self
+            .characteristic
+            .iter()
+            .filter_map(|pair| {
+                other
+                    .characteristic
+                    .iter()
+                    .find(|other_pair| other_pair.flux_density == pair.flux_density)
+                    .map(|other_pair| {
+                        FluxDensityLossPair::new(
+                            pair.flux_density,
+                            pair.specific_loss + other_pair.specific_loss,
+                        )
+                    })
+            })
+            .collect();
+        return IronLossCharacteristic::new(self.frequency, characteristic);
+    }
+}
+
+/**
+Multiplies all specific loss values of an [`IronLossCharacteristic`] with a
+scalar. The frequency and the flux densities stay unchanged.
+ */
+impl std::ops::Mul<f64> for IronLossCharacteristic {
+    type Output = IronLossCharacteristic;
+
+    fn mul(mut self, factor: f64) -> IronLossCharacteristic {
+        for pair in self.characteristic.iter_mut() {
+            pair.specific_loss *= factor;
+        }
+        return self;
+    }
 }
 
 /**
@@ -677,6 +2532,70 @@ impl FluxDensityLossPair {
     }
 }
 
+/**
+Orders [`FluxDensityLossPair`]s by their flux density first and by their
+specific losses second. The comparison uses [`f64::total_cmp`] on the SI values,
+hence `NaN` values are ordered as well and `-0.0` is considered smaller than
+`0.0`.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let b = |v| MagneticFluxDensity::new::<tesla>(v);
+let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+
+let mut pairs = vec![
+    FluxDensityLossPair::new(b(1.0), p(3.0)),
+    FluxDensityLossPair::new(b(0.5), p(1.0)),
+    FluxDensityLossPair::new(b(1.0), p(2.0)),
+];
+pairs.sort();
+assert_eq!(
+    pairs,
+    vec![
+        FluxDensityLossPair::new(b(0.5), p(1.0)),
+        FluxDensityLossPair::new(b(1.0), p(2.0)),
+        FluxDensityLossPair::new(b(1.0), p(3.0)),
+    ]
+);
+```
+ */
+impl Ord for FluxDensityLossPair {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        return self
+            .flux_density
+            .get::<tesla>()
+            .total_cmp(&other.flux_density.get::<tesla>())
+            .then_with(|| {
+                self.specific_loss
+                    .get::<watt_per_kilogram>()
+                    .total_cmp(&other.specific_loss.get::<watt_per_kilogram>())
+            });
+    }
+}
+
+impl PartialOrd for FluxDensityLossPair {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/**
+Two [`FluxDensityLossPair`]s are equal if they are equal according to the
+[`Ord`] implementation, i.e. on the basis of [`f64::total_cmp`]. Contrary to
+the comparison of the raw `f64` values, `NaN` values are therefore equal to
+themselves and `-0.0` is not equal to `0.0`.
+ */
+impl PartialEq for FluxDensityLossPair {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == std::cmp::Ordering::Equal;
+    }
+}
+
+impl Eq for FluxDensityLossPair {}
+
 #[cfg(feature = "serde")]
 mod serde_impl {
     use super::*;
@@ -712,35 +2631,97 @@ mod serde_impl {
 }
 
 /**
-A struct representing a failed [`JordanModel`] coefficient calculation attempt.
-
-Calculating the coefficients of a [`JordanModel`] may fail due to a bad dataset.
-The calculation uses a least-square minimization algorithm provided by the
-[`argmin`] crate, which returns a [`argmin::core::Error`] when the calculation
-fails. Even if no such error is created, the returned coefficient might still
-be empty - this is represented by `FailedCoefficientCalculation(None)`.
+An error representing a failed [`JordanModel`] coefficient calculation attempt.
+
+Calculating the coefficients of a [`JordanModel`] (or the parameters of other
+fitted loss models) may fail due to a bad dataset. Before the calculation, the
+dataset is checked for a sufficient amount of data (see
+[`IronLossData::check_minimum_characteristic_count`]). The calculation itself
+uses a least-square minimization algorithm provided by the [`argmin`] crate,
+which returns a [`argmin::core::Error`] when the calculation fails. Even if no
+such error is created, the returned coefficient might still be empty.
  */
 #[derive(Debug)]
-pub struct FailedCoefficientCalculation(pub Option<argmin::core::Error>);
+pub enum FailedCoefficientCalculation {
+    /// The minimization algorithm returned an error.
+    Argmin(argmin::core::Error),
+    /// The minimization algorithm did not return any coefficients.
+    NoSolution,
+    /// The dataset contains less characteristics than needed for the fit.
+    InsufficientData {
+        /// Number of characteristics in the dataset.
+        characteristics_provided: usize,
+        /// Minimum number of characteristics needed for the fit.
+        minimum_required: usize,
+    },
+    /// A characteristic of the dataset contains less datapoints than needed.
+    InsufficientDatapoints {
+        /// Index of the offending characteristic within the dataset.
+        characteristic_index: usize,
+        /// Number of datapoints in the characteristic.
+        datapoints_provided: usize,
+        /// Minimum number of datapoints needed per characteristic.
+        minimum_required: usize,
+    },
+}
+
+impl FailedCoefficientCalculation {
+    /**
+    Creates a [`FailedCoefficientCalculation::Argmin`] from the error returned
+    by the minimization algorithm. The error is available via
+    [`source`](std::error::Error::source).
+     */
+    pub fn from_argmin_error(error: argmin::core::Error) -> Self {
+        return Self::Argmin(error);
+    }
+
+    /**
+    Creates a [`FailedCoefficientCalculation::NoSolution`] for the case that
+    the minimization algorithm finished without returning any coefficients.
+     */
+    pub fn missing_solution() -> Self {
+        return Self::NoSolution;
+    }
+}
 
 impl std::fmt::Display for FailedCoefficientCalculation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.0 {
-            Some(cause) => {
-                let original_message = cause.to_string();
-                write!(
-                    f,
-                    "The calculation of the hysteresis loss coefficients failed,
-                    likely due to bad input data. Original message: {original_message}."
-                )
-            }
-            None => write!(
+        match self {
+            FailedCoefficientCalculation::Argmin(_) => write!(
+                f,
+                "The optimizer failed to calculate the loss model coefficients, \
+                likely due to bad input data (see the source error for details)."
+            ),
+            FailedCoefficientCalculation::NoSolution => write!(
+                f,
+                "The optimizer returned no solution, possibly due to degenerate input data."
+            ),
+            FailedCoefficientCalculation::InsufficientData {
+                characteristics_provided,
+                minimum_required,
+            } => write!(
+                f,
+                "The dataset contains {characteristics_provided} loss characteristics, \
+                but at least {minimum_required} are needed."
+            ),
+            FailedCoefficientCalculation::InsufficientDatapoints {
+                characteristic_index,
+                datapoints_provided,
+                minimum_required,
+            } => write!(
                 f,
-                "The calculation of the hysteresis loss coefficients failed,
-                likely due to bad input data."
+                "Loss characteristic {characteristic_index} contains {datapoints_provided} \
+                datapoints, but at least {minimum_required} are needed."
             ),
         }
     }
 }
 
-impl std::error::Error for FailedCoefficientCalculation {}
+impl std::error::Error for FailedCoefficientCalculation {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FailedCoefficientCalculation::Argmin(cause) => Some(&**cause),
+            _ => None,
+        }
+    }
+}
@@ -7,12 +7,26 @@ See its docstring for more.
 
 Additionally, it offers the following predefined iron loss models:
 - [`JordanModel`] (from submodule [`jordan_model`] )
+- [`TemperatureScaledJordanModel`] (from submodule [`jordan_model`] )
+- [`SteinmetzModel`] (from submodule [`steinmetz_model`] )
+- [`GeneralizedJordanModel`] (from submodule [`generalized_jordan_model`] )
  */
 
 pub mod jordan_model;
 pub use jordan_model::*;
 
-use var_quantity::uom::si::f64::SpecificPower;
+pub mod steinmetz_model;
+pub use steinmetz_model::*;
+
+pub mod generalized_jordan_model;
+pub use generalized_jordan_model::*;
+
+use var_quantity::uom::si::f64::{
+    Frequency, MagneticFluxDensity, MassDensity, SpecificPower, VolumetricPowerDensity,
+};
+use var_quantity::uom::si::{
+    frequency::hertz, magnetic_flux_density::tesla, specific_power::watt_per_kilogram,
+};
 use var_quantity::{DynQuantity, IsQuantityFunction, QuantityFunction};
 
 /**
@@ -41,6 +55,24 @@ pub enum IronLosses {
      */
     JordanModel(JordanModel),
     /**
+    Optimization for the common case of using the [`SteinmetzModel`] defined
+    within this crate. This avoids going through dynamic dispatch when accessing
+    the model.
+     */
+    SteinmetzModel(SteinmetzModel),
+    /**
+    Optimization for the common case of using the
+    [`TemperatureScaledJordanModel`] defined within this crate. This avoids
+    going through dynamic dispatch when accessing the model.
+     */
+    TemperatureScaledJordanModel(TemperatureScaledJordanModel),
+    /**
+    Optimization for the common case of using the [`GeneralizedJordanModel`]
+    defined within this crate. This avoids going through dynamic dispatch when
+    accessing the model.
+     */
+    GeneralizedJordanModel(GeneralizedJordanModel),
+    /**
     Catch-all variant for any non-constant behaviour. Arbitrary behaviour
     can be realized with the contained [`IsQuantityFunction`] trait object, as
     long as the unit constraint outlined in the
@@ -54,14 +86,274 @@ impl IronLosses {
     Matches against `self` and calculates the iron losses (or just return the
     value in case of the [`IronLosses::Constant`]) variant).
     */
+    #[inline]
     pub fn get(&self, conditions: &[DynQuantity<f64>]) -> SpecificPower {
         match self {
-            Self::Constant(val) => val.clone(),
+            Self::Constant(val) => *val,
             Self::JordanModel(model) => model.call(conditions).try_into().expect("implementation of JordanModel makes sure the returned value is always a SpecificPower"),
+            Self::SteinmetzModel(model) => model.call(conditions).try_into().expect("implementation of SteinmetzModel makes sure the returned value is always a SpecificPower"),
+            Self::TemperatureScaledJordanModel(model) => model.call(conditions).try_into().expect("implementation of TemperatureScaledJordanModel makes sure the returned value is always a SpecificPower"),
+            Self::GeneralizedJordanModel(model) => model.call(conditions).try_into().expect("implementation of GeneralizedJordanModel makes sure the returned value is always a SpecificPower"),
             Self::Function(fun) => fun.call(conditions),
         }
     }
 
+    /**
+    Returns the iron losses per volume for the given `conditions`, i.e. the
+    specific losses returned by [`IronLosses::get`] multiplied with the
+    `mass_density` of the material.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+    use stem_material::uom::si::volumetric_power_density::watt_per_cubic_meter;
+
+    let losses = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.0));
+
+    // 2 W/kg * 7650 kg/m³ = 15300 W/m³
+    let loss_density = losses.get_density(&[], MassDensity::new::<kilogram_per_cubic_meter>(7650.0));
+    approx::assert_abs_diff_eq!(loss_density.get::<watt_per_cubic_meter>(), 15300.0, epsilon = 1e-9);
+    ```
+     */
+    pub fn get_density(
+        &self,
+        conditions: &[DynQuantity<f64>],
+        mass_density: MassDensity,
+    ) -> VolumetricPowerDensity {
+        return self.get(conditions) * mass_density;
+    }
+
+    /**
+    Calculates the iron losses for the given `flux_density` amplitude and
+    `frequency`.
+
+    This is a shorthand for
+    [`get_from_model_inputs`](IronLosses::get_from_model_inputs), which
+    forwards the typed quantities to the contained model without constructing a
+    [`DynQuantity`] slice.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let losses = IronLosses::JordanModel(JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ));
+    let b = MagneticFluxDensity::new::<tesla>(1.5);
+    let f = Frequency::new::<hertz>(50.0);
+    assert_eq!(losses.get_at(b, f), losses.get(&[b.into(), f.into()]));
+    ```
+     */
+    #[inline]
+    pub fn get_at(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return self.get_from_model_inputs(flux_density, frequency);
+    }
+
+    /**
+    Calculates the iron losses for a sinusoidal changing magnetic flux density
+    with the amplitude `flux_density` and the specified `frequency`.
+
+    Contrary to [`IronLosses::get`], the inputs are passed as typed quantities.
+    For all variants except [`IronLosses::Function`], the call is forwarded to
+    the [`IronLossModel::losses`] method of the contained model without
+    constructing a [`DynQuantity`] slice. The
+    [`IronLosses::TemperatureScaledJordanModel`] variant is evaluated at its
+    reference temperature.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let losses = IronLosses::JordanModel(JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ));
+    let b = MagneticFluxDensity::new::<tesla>(1.5);
+    let f = Frequency::new::<hertz>(50.0);
+    assert_eq!(losses.get_from_model_inputs(b, f), losses.get(&[b.into(), f.into()]));
+    ```
+     */
+    #[inline]
+    pub fn get_from_model_inputs(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> SpecificPower {
+        match self {
+            Self::Constant(val) => return *val,
+            Self::JordanModel(model) => {
+                return IronLossModel::losses(model, flux_density, frequency);
+            }
+            Self::SteinmetzModel(model) => {
+                return IronLossModel::losses(model, flux_density, frequency);
+            }
+            Self::TemperatureScaledJordanModel(model) => {
+                return IronLossModel::losses(model, flux_density, frequency);
+            }
+            Self::GeneralizedJordanModel(model) => {
+                return IronLossModel::losses(model, flux_density, frequency);
+            }
+            Self::Function(fun) => return fun.call(&[flux_density.into(), frequency.into()]),
+        }
+    }
+
+    /**
+    Returns the hysteresis and the eddy current part of the iron losses
+    separately.
+
+    For the [`IronLosses::JordanModel`] and
+    [`IronLosses::TemperatureScaledJordanModel`] and
+    [`IronLosses::GeneralizedJordanModel`] variants, the
+    `losses_by_component` method of the model is called with the flux density,
+    frequency (and temperature) found in `conditions` (see the
+    [`IsQuantityFunction`] implementations of the models). All other variants do not distinguish
+    between the two loss components, hence the full losses as returned by
+    [`IronLosses::get`] are given as the first element of the tuple and the
+    second element is zero. The sum of both elements is therefore always equal
+    to the value returned by [`IronLosses::get`].
+     */
+    pub fn get_by_component(
+        &self,
+        conditions: &[DynQuantity<f64>],
+    ) -> (SpecificPower, SpecificPower) {
+        match self {
+            Self::JordanModel(model) => {
+                let (flux_density, frequency) = flux_density_and_frequency(conditions);
+                return model.losses_by_component(flux_density, frequency);
+            }
+            Self::TemperatureScaledJordanModel(model) => {
+                let (flux_density, frequency) = flux_density_and_frequency(conditions);
+                let temperature = model.temperature(conditions);
+                return model.losses_by_component(flux_density, frequency, temperature);
+            }
+            Self::GeneralizedJordanModel(model) => {
+                let (flux_density, frequency) = flux_density_and_frequency(conditions);
+                return model.losses_by_component(flux_density, frequency);
+            }
+            _ => {
+                return (
+                    self.get(conditions),
+                    SpecificPower::new::<watt_per_kilogram>(0.0),
+                );
+            }
+        }
+    }
+
+    /**
+    Returns a new [`IronLosses`] whose losses are `factor` times the losses of
+    `self` for all conditions.
+
+    This is useful e.g. to account for additional losses caused by harmonics
+    via an empirical factor. For the predefined models, the scaling is applied
+    directly to the model coefficients, so the returned value has the same
+    variant as `self`. A [`IronLosses::Function`] is wrapped into a
+    [`ScaledIronLosses`] function.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let losses = IronLosses::JordanModel(JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ));
+    let scaled = losses.scale(1.5);
+
+    let conditions = &[
+        MagneticFluxDensity::new::<tesla>(1.5).into(),
+        Frequency::new::<hertz>(50.0).into(),
+    ];
+    approx::assert_abs_diff_eq!(
+        scaled.get(conditions).get::<watt_per_kilogram>(),
+        1.5 * losses.get(conditions).get::<watt_per_kilogram>(),
+        epsilon = 1e-12
+    );
+    ```
+     */
+    pub fn scale(&self, factor: f64) -> IronLosses {
+        match self {
+            Self::Constant(val) => return Self::Constant(*val * factor),
+            Self::JordanModel(model) => {
+                return Self::JordanModel(scale_jordan_model(model, factor));
+            }
+            Self::SteinmetzModel(model) => {
+                let mut model = model.clone();
+                model.coefficient *= factor;
+                return Self::SteinmetzModel(model);
+            }
+            Self::TemperatureScaledJordanModel(model) => {
+                let mut model = model.clone();
+                model.base_model = scale_jordan_model(&model.base_model, factor);
+                return Self::TemperatureScaledJordanModel(model);
+            }
+            Self::GeneralizedJordanModel(model) => {
+                let mut model = model.clone();
+                model.hysteresis_coefficient *= factor;
+                model.eddy_current_coefficient *= factor;
+                return Self::GeneralizedJordanModel(model);
+            }
+            Self::Function(fun) => {
+                let scaled = ScaledIronLosses {
+                    function: fun.clone(),
+                    factor,
+                };
+                return Self::Function(
+                    QuantityFunction::new(Box::new(scaled))
+                        .expect("ScaledIronLosses always returns a SpecificPower"),
+                );
+            }
+        }
+    }
+
+    /**
+    Returns a reference to the contained [`JordanModel`] if `self` is a
+    [`IronLosses::JordanModel`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut losses = IronLosses::JordanModel(JordanModel::default());
+    if let Some(model) = losses.try_as_jordan_model_mut() {
+        model.hysteresis_coefficient = SpecificPower::new::<watt_per_kilogram>(2.0);
+    }
+    assert_eq!(
+        losses
+            .try_as_jordan_model()
+            .unwrap()
+            .hysteresis_coefficient
+            .get::<watt_per_kilogram>(),
+        2.0
+    );
+    assert!(IronLosses::from(SpecificPower::new::<watt_per_kilogram>(1.0))
+        .try_as_jordan_model()
+        .is_none());
+    ```
+     */
+    pub fn try_as_jordan_model(&self) -> Option<&JordanModel> {
+        match self {
+            Self::JordanModel(model) => return Some(model),
+            _ => return None,
+        }
+    }
+
+    /**
+    Returns a mutable reference to the contained [`JordanModel`] if `self` is
+    a [`IronLosses::JordanModel`].
+     */
+    pub fn try_as_jordan_model_mut(&mut self) -> Option<&mut JordanModel> {
+        match self {
+            Self::JordanModel(model) => return Some(model),
+            _ => return None,
+        }
+    }
+
     /**
     Returns a reference to the underlying function if `self` is a
     [`IronLosses::Function`].
@@ -74,6 +366,267 @@ impl IronLosses {
     }
 }
 
+/**
+Common interface of iron loss models which depend on the amplitude of a
+sinusoidal changing magnetic flux density and its frequency.
+
+This trait is implemented by the predefined models [`JordanModel`],
+[`SteinmetzModel`], [`GeneralizedJordanModel`] and
+[`TemperatureScaledJordanModel`] as well as by [`IronLosses`] itself. It
+allows writing code which is generic over the used loss model without going
+through [`DynQuantity`] conditions.
+
+Models which depend on further quantities such as the temperature read them
+from the `conditions` passed to [`IronLossModel::losses_with_conditions`].
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let model = TemperatureScaledJordanModel {
+    base_model: JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    ),
+    reference_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    hysteresis_temp_coefficient: -0.001,
+    eddy_current_temp_coefficient: -0.002,
+};
+let b = MagneticFluxDensity::new::<tesla>(1.5);
+let f = Frequency::new::<hertz>(50.0);
+let t = ThermodynamicTemperature::new::<degree_celsius>(120.0);
+
+assert_eq!(
+    IronLossModel::losses_with_conditions(&model, b, f, &[t.into()]),
+    model.losses(b, f, t)
+);
+assert_eq!(
+    IronLossModel::losses(&model, b, f),
+    model.losses(b, f, model.reference_temperature)
+);
+```
+ */
+pub trait IronLossModel {
+    /**
+    Returns the specific losses for a sinusoidal changing magnetic flux density
+    with the amplitude `flux_density` and the specified `frequency`.
+     */
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower;
+
+    /**
+    Returns the specific losses like [`IronLossModel::losses`], but allows
+    the model to read further inputs (e.g. the temperature) from `conditions`.
+    The default implementation ignores `conditions`.
+     */
+    fn losses_with_conditions(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+        conditions: &[DynQuantity<f64>],
+    ) -> SpecificPower {
+        let _ = conditions;
+        return self.losses(flux_density, frequency);
+    }
+}
+
+impl IronLossModel for JordanModel {
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return JordanModel::losses(self, flux_density, frequency);
+    }
+}
+
+impl IronLossModel for SteinmetzModel {
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return SteinmetzModel::losses(self, flux_density, frequency);
+    }
+}
+
+impl IronLossModel for TemperatureScaledJordanModel {
+    /**
+    Evaluates the model at its
+    [`reference_temperature`](TemperatureScaledJordanModel::reference_temperature),
+    since no temperature is given.
+     */
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return self.losses_with_conditions(flux_density, frequency, &[]);
+    }
+
+    /**
+    Evaluates the model at the temperature found in `conditions` or at its
+    [`reference_temperature`](TemperatureScaledJordanModel::reference_temperature)
+    if `conditions` does not contain a temperature.
+     */
+    fn losses_with_conditions(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+        conditions: &[DynQuantity<f64>],
+    ) -> SpecificPower {
+        let temperature = self.temperature(conditions);
+        return TemperatureScaledJordanModel::losses(self, flux_density, frequency, temperature);
+    }
+}
+
+impl IronLossModel for GeneralizedJordanModel {
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return GeneralizedJordanModel::losses(self, flux_density, frequency);
+    }
+}
+
+impl IronLossModel for IronLosses {
+    fn losses(&self, flux_density: MagneticFluxDensity, frequency: Frequency) -> SpecificPower {
+        return self.get_from_model_inputs(flux_density, frequency);
+    }
+
+    fn losses_with_conditions(
+        &self,
+        flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+        conditions: &[DynQuantity<f64>],
+    ) -> SpecificPower {
+        match self {
+            Self::TemperatureScaledJordanModel(model) => {
+                return model.losses_with_conditions(flux_density, frequency, conditions);
+            }
+            Self::Function(fun) => {
+                let mut inputs = vec![flux_density.into(), frequency.into()];
+                inputs.extend_from_slice(conditions);
+                return fun.call(&inputs);
+            }
+            _ => return self.get_from_model_inputs(flux_density, frequency),
+        }
+    }
+}
+
+/**
+Adds two [`IronLosses`]. If both are [`IronLosses::Constant`], the result is
+a constant as well. Otherwise, both operands are wrapped into a
+[`SummedIronLosses`] function.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let stator = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.0));
+let rotor = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(0.5));
+let total = stator + rotor;
+assert_eq!(total, IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.5)));
+```
+ */
+impl std::ops::Add for IronLosses {
+    type Output = IronLosses;
+
+    fn add(self, other: IronLosses) -> IronLosses {
+        if let (Self::Constant(first), Self::Constant(second)) = (&self, &other) {
+            return Self::Constant(*first + *second);
+        }
+        let summed = SummedIronLosses {
+            first: Box::new(self),
+            second: Box::new(other),
+        };
+        return Self::Function(
+            QuantityFunction::new(Box::new(summed))
+                .expect("SummedIronLosses always returns a SpecificPower"),
+        );
+    }
+}
+
+/**
+An iron loss function whose output is the sum of two [`IronLosses`].
+
+This struct is created by the [`Add`](std::ops::Add) implementation of
+[`IronLosses`], but it can also be used on its own.
+ */
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummedIronLosses {
+    /// First summand.
+    pub first: Box<IronLosses>,
+    /// Second summand.
+    pub second: Box<IronLosses>,
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for SummedIronLosses {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        return (self.first.get(conditions) + self.second.get(conditions)).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/// Returns a copy of `model` with both coefficients multiplied by `factor`.
+fn scale_jordan_model(model: &JordanModel, factor: f64) -> JordanModel {
+    return JordanModel::new(
+        model.hysteresis_coefficient * factor,
+        model.eddy_current_coefficient * factor,
+    );
+}
+
+/**
+An iron loss function whose output is the output of another iron loss function
+multiplied by a constant factor.
+
+This struct is created by [`IronLosses::scale`] for the
+[`IronLosses::Function`] variant, but it can also be used on its own.
+ */
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaledIronLosses {
+    /// Underlying iron loss function.
+    pub function: QuantityFunction<SpecificPower>,
+    /// Factor which is applied to the output of [`ScaledIronLosses::function`].
+    pub factor: f64,
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for ScaledIronLosses {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        return (self.function.call(conditions) * self.factor).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/**
+Searches `conditions` for a magnetic flux density and a frequency. If one of
+them cannot be found, a value of zero is returned for it. This is used by the
+[`IsQuantityFunction`] implementations of the predefined iron loss models.
+ */
+pub(crate) fn flux_density_and_frequency(
+    conditions: &[DynQuantity<f64>],
+) -> (MagneticFluxDensity, Frequency) {
+    let mut flux_density = MagneticFluxDensity::new::<tesla>(0.0);
+    let mut frequency = Frequency::new::<hertz>(0.0);
+    for factor in conditions {
+        if let Ok(fd) = MagneticFluxDensity::try_from(*factor) {
+            flux_density = fd;
+        } else if let Ok(f) = Frequency::try_from(*factor) {
+            frequency = f;
+        }
+    }
+    return (flux_density, frequency);
+}
+
+impl std::fmt::Display for IronLosses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constant(val) => write!(f, "constant: {} W/kg", val.get::<watt_per_kilogram>()),
+            Self::JordanModel(model) => model.fmt(f),
+            Self::SteinmetzModel(model) => model.fmt(f),
+            Self::TemperatureScaledJordanModel(model) => model.fmt(f),
+            Self::GeneralizedJordanModel(model) => model.fmt(f),
+            Self::Function(_) => write!(f, "function"),
+        }
+    }
+}
+
 impl TryFrom<Box<dyn IsQuantityFunction>> for IronLosses {
     type Error = var_quantity::UnitsNotEqual;
 
@@ -98,9 +651,17 @@ impl serde::Serialize for IronLosses {
         use serde::Serialize;
         use var_quantity::serialize_quantity;
 
+        // The variants are renamed so the serialized tags match the model names
         #[derive(Serialize)]
         enum PredefinedModels<'a> {
-            JordanModel(&'a JordanModel),
+            #[serde(rename = "JordanModel")]
+            Jordan(&'a JordanModel),
+            #[serde(rename = "SteinmetzModel")]
+            Steinmetz(&'a SteinmetzModel),
+            #[serde(rename = "TemperatureScaledJordanModel")]
+            TemperatureScaledJordan(&'a TemperatureScaledJordanModel),
+            #[serde(rename = "GeneralizedJordanModel")]
+            GeneralizedJordan(&'a GeneralizedJordanModel),
         }
 
         #[derive(Serialize)]
@@ -115,7 +676,16 @@ impl serde::Serialize for IronLosses {
         let il = match self {
             IronLosses::Constant(v) => IronLossesSerde::Constant(*v),
             IronLosses::JordanModel(model) => {
-                IronLossesSerde::PredefinedModels(PredefinedModels::JordanModel(model))
+                IronLossesSerde::PredefinedModels(PredefinedModels::Jordan(model))
+            }
+            IronLosses::SteinmetzModel(model) => {
+                IronLossesSerde::PredefinedModels(PredefinedModels::Steinmetz(model))
+            }
+            IronLosses::TemperatureScaledJordanModel(model) => {
+                IronLossesSerde::PredefinedModels(PredefinedModels::TemperatureScaledJordan(model))
+            }
+            IronLosses::GeneralizedJordanModel(model) => {
+                IronLossesSerde::PredefinedModels(PredefinedModels::GeneralizedJordan(model))
             }
             IronLosses::Function(quantity_function) => IronLossesSerde::Function(quantity_function),
         };
@@ -134,9 +704,17 @@ impl<'de> serde::Deserialize<'de> for IronLosses {
         use serde::Deserialize;
         use var_quantity::DynQuantity;
 
+        // The variants are renamed so the serialized tags match the model names
         #[derive(Deserialize)]
         enum PredefinedModels {
-            JordanModel(JordanModel),
+            #[serde(rename = "JordanModel")]
+            Jordan(JordanModel),
+            #[serde(rename = "SteinmetzModel")]
+            Steinmetz(SteinmetzModel),
+            #[serde(rename = "TemperatureScaledJordanModel")]
+            TemperatureScaledJordan(TemperatureScaledJordanModel),
+            #[serde(rename = "GeneralizedJordanModel")]
+            GeneralizedJordan(GeneralizedJordanModel),
         }
 
         #[derive(deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError)]
@@ -158,8 +736,15 @@ impl<'de> serde::Deserialize<'de> for IronLosses {
                 IronLosses::Constant(static_quantity)
             }
             IronLossesSerde::PredefinedModels(pd) => match pd {
-                PredefinedModels::JordanModel(jordan_model) => {
-                    IronLosses::JordanModel(jordan_model)
+                PredefinedModels::Jordan(jordan_model) => IronLosses::JordanModel(jordan_model),
+                PredefinedModels::Steinmetz(steinmetz_model) => {
+                    IronLosses::SteinmetzModel(steinmetz_model)
+                }
+                PredefinedModels::TemperatureScaledJordan(model) => {
+                    IronLosses::TemperatureScaledJordanModel(model)
+                }
+                PredefinedModels::GeneralizedJordan(model) => {
+                    IronLosses::GeneralizedJordanModel(model)
                 }
             },
             IronLossesSerde::Function(quantity_function) => IronLosses::Function(quantity_function),
@@ -0,0 +1,402 @@
+/*!
+An implementation of the Steinmetz model for iron losses.
+
+The (classical) Steinmetz equation is an empirical formula for the specific
+losses of a ferromagnetic material subjected to a sinusoidal flux density
+change over time:
+
+`p = k * f^α * B^β`,
+
+where `f` is the frequency and `B` is the amplitude of the flux density. In
+contrast to the [`JordanModel`](crate::iron_losses::jordan_model::JordanModel),
+the losses are not separated into hysteresis and eddy current losses. Instead,
+the frequency exponent `α` and the flux density exponent `β` are free
+parameters which are derived together with the coefficient `k` by fitting
+measured loss curves. Many lamination datasheets already list pre-fitted
+exponents. See \[1\] for more.
+
+This module offers the [`SteinmetzModel`] struct, a simple container for the
+three parameters which provides the formula given above via its
+[`SteinmetzModel::losses`] method. The struct implements [`IsQuantityFunction`]
+and can therefore be used as the
+[iron loss model](crate::material::Material::iron_losses) of a
+[`Material`](crate::material::Material).
+
+Analogous to the [`JordanModel`](crate::iron_losses::jordan_model::JordanModel),
+the parameters can be obtained from an [`IronLossData`] instance via the
+[`TryFrom`] implementation.
+
+# Literature
+
+> \[1\] Krings, A. and Soulard, J.: Overview and comparison of iron loss models
+> for electrical machines. EVRE Monaco, March 2010. URL:
+> <https://www.researchgate.net/profile/Andreas-Krings/publication/228490936_Overview_and_Comparison_of_Iron_Loss_Models_for_Electrical_Machines/links/02e7e51935e2728dda000000/Overview-and-Comparison-of-Iron-Loss-Models-for-Electrical-Machines.pdf>
+ */
+
+use argmin::core::{CostFunction, State};
+use var_quantity::DynQuantity;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use var_quantity::deserialize_quantity;
+
+use var_quantity::IsQuantityFunction;
+use var_quantity::uom::si::{
+    f64::*, frequency::hertz, magnetic_flux_density::tesla, specific_power::watt_per_kilogram,
+};
+
+use crate::iron_losses::flux_density_and_frequency;
+use crate::iron_losses::jordan_model::{
+    FailedCoefficientCalculation, FlatLossData, IronLossData, NelderMeadResult, SolverConfig,
+    run_nelder_mead,
+};
+
+/**
+Implementation of the Steinmetz iron loss model.
+
+As discussed in the
+[module-level documentation](crate::iron_losses::steinmetz_model), this struct
+contains the parameters of the Steinmetz iron loss model:
+
+`p = k * f^α * B^β`.
+
+The parameters are used in the form they are usually listed in datasheets:
+The frequency `f` is inserted in Hz and the amplitude `B` in T, without any
+normalization. The coefficient `k` is therefore the specific loss at 1 Hz and
+1 T and is given in W/kg.
+
+# Constructing a Steinmetz loss model
+
+If the parameters are known, a [`SteinmetzModel`] can be constructed via the
+default field assignment constructor or via [`SteinmetzModel::new`].
+Alternatively, the parameters can be derived by fitting loss curves into the
+loss equation. This is done by first creating an [`IronLossData`] struct and
+then fallibly converting it into a [`SteinmetzModel`] using [`TryFrom`]. The
+quality of the resulting fit can be checked with [`SteinmetzModel::r_squared`].
+
+# Usage in `Material`
+
+This struct is meant to be used for the
+[`Material::iron_losses`](crate::material::Material::iron_losses), hence it
+implements [`IsQuantityFunction`]. Inside the [`IsQuantityFunction::call`]
+function, the input conditions are searched for an entry whose unit corresponds
+to that of the magnetic flux density and another one which matches that of the
+frequency. If either one cannot be found, a value of zero is assumed, which
+means that the returned losses are zero as well (provided both exponents are
+positive):
+
+```
+use stem_material::prelude::*;
+
+let model = SteinmetzModel {
+    coefficient: SpecificPower::new::<watt_per_kilogram>(0.01),
+    frequency_exponent: 1.3,
+    flux_density_exponent: 2.1,
+};
+
+let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(20.0).into()];
+assert_eq!(model.call(conditions).value, 0.0);
+
+// At 1 T and 1 Hz, the coefficient is returned.
+let conditions = &[MagneticFluxDensity::new::<tesla>(1.0).into(), Frequency::new::<hertz>(1.0).into()];
+assert_eq!(model.call(conditions).value, 0.01);
+
+let conditions = &[MagneticFluxDensity::new::<tesla>(1.5).into(), Frequency::new::<hertz>(50.0).into()];
+approx::assert_abs_diff_eq!(
+    model.call(conditions).value,
+    0.01 * 50.0f64.powf(1.3) * 1.5f64.powf(2.1),
+    epsilon = 1e-12
+);
+```
+
+# Serialization and deserialization
+
+Analogous to the [`JordanModel`](crate::iron_losses::jordan_model::JordanModel),
+a [`SteinmetzModel`] is serialized as a struct with three fields, but it can be
+deserialized both from this representation and from that of [`IronLossData`].
+In case of the latter, the parameters are fitted during deserialization.
+
+```ignore
+SteinmetzModel:
+  coefficient: 0.01 W/kg
+  frequency_exponent: 1.3
+  flux_density_exponent: 2.1
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "serde_impl::SteinmetzModelDeEnum")
+)]
+pub struct SteinmetzModel {
+    /// Loss coefficient `k`.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub coefficient: SpecificPower,
+    /// Frequency exponent `α`.
+    pub frequency_exponent: f64,
+    /// Flux density exponent `β`.
+    pub flux_density_exponent: f64,
+}
+
+impl SteinmetzModel {
+    /**
+    Creates a new [`SteinmetzModel`] from its parameters.
+     */
+    pub fn new(
+        coefficient: SpecificPower,
+        frequency_exponent: f64,
+        flux_density_exponent: f64,
+    ) -> Self {
+        return Self {
+            coefficient,
+            frequency_exponent,
+            flux_density_exponent,
+        };
+    }
+
+    /**
+    Returns the specific losses for a sinusoidal changing magnetic flux density
+    with the amplitude `magnetic_flux_density` and the specified `frequency`.
+
+    This function returns the result `p` of the equation:
+
+    `p = k * f^α * B^β`,
+
+    where `k` corresponds to [`SteinmetzModel::coefficient`], `α` to
+    [`SteinmetzModel::frequency_exponent`] and `β` to
+    [`SteinmetzModel::flux_density_exponent`]. The frequency is inserted in Hz
+    and the flux density in T. Since the exponents are usually not integers,
+    the absolute values of the arguments are used.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let model = SteinmetzModel::new(SpecificPower::new::<watt_per_kilogram>(0.01), 1.5, 2.0);
+    let b = MagneticFluxDensity::new::<tesla>(1.5);
+
+    // Double the frequency - the losses rise by a factor of 2^1.5
+    approx::assert_abs_diff_eq!(
+        model.losses(b, Frequency::new::<hertz>(100.0)).value,
+        model.losses(b, Frequency::new::<hertz>(50.0)).value * 2.0f64.powf(1.5),
+        epsilon = 1e-9
+    );
+    ```
+    */
+    pub fn losses(
+        &self,
+        magnetic_flux_density: MagneticFluxDensity,
+        frequency: Frequency,
+    ) -> SpecificPower {
+        return losses(
+            magnetic_flux_density,
+            frequency,
+            self.coefficient,
+            self.frequency_exponent,
+            self.flux_density_exponent,
+        );
+    }
+
+    /**
+    Returns the coefficient of determination R² of `self` with respect to all
+    datapoints in `data`.
+
+    A value close to 1 indicates that the model reproduces the measured losses
+    very well. If all measured losses in `data` are identical, the result is
+    not finite.
+     */
+    pub fn r_squared(&self, data: &IronLossData) -> f64 {
+        return data.coefficient_of_determination(|flux_density, frequency| {
+            self.losses(flux_density, frequency)
+        });
+    }
+}
+
+impl std::fmt::Display for SteinmetzModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Steinmetz loss model: k = {} W/kg, α = {}, β = {}",
+            self.coefficient.get::<watt_per_kilogram>(),
+            self.frequency_exponent,
+            self.flux_density_exponent
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for SteinmetzModel {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        let (flux_density, frequency) = flux_density_and_frequency(conditions);
+        return self.losses(flux_density, frequency).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/**
+Actual loss calculation function. Factored out from the [`SteinmetzModel`]
+method of the same name because it is also used in [`TryFrom<IronLossData>`].
+ */
+fn losses(
+    flux_density: MagneticFluxDensity,
+    frequency: Frequency,
+    coefficient: SpecificPower,
+    frequency_exponent: f64,
+    flux_density_exponent: f64,
+) -> SpecificPower {
+    return coefficient
+        * frequency.get::<hertz>().abs().powf(frequency_exponent)
+        * flux_density
+            .get::<tesla>()
+            .abs()
+            .powf(flux_density_exponent);
+}
+
+/**
+Returns the coefficient `k` of the Steinmetz equation which results in the
+specific loss `reference_loss` at 50 Hz and 1.5 T.
+
+During fitting, the specific loss at this point is optimized instead of `k`
+itself. Since `k` strongly depends on the exponents (a change of `α` by 0.1
+alters `50^α` by almost 50 %), this keeps the optimization problem well
+conditioned.
+ */
+fn coefficient_from_reference_loss(
+    reference_loss: f64,
+    frequency_exponent: f64,
+    flux_density_exponent: f64,
+) -> SpecificPower {
+    return SpecificPower::new::<watt_per_kilogram>(
+        reference_loss / (50.0f64.powf(frequency_exponent) * 1.5f64.powf(flux_density_exponent)),
+    );
+}
+
+/**
+Cost function for fitting the parameters of a [`SteinmetzModel`]. This struct
+is the [`SteinmetzModel`] counterpart to
+[`FitLossCurve`](crate::iron_losses::jordan_model::FitLossCurve).
+ */
+pub struct FitSteinmetzCurve(FlatLossData);
+
+/// Optimization result of fitting a [`FitSteinmetzCurve`] with the
+/// [`FitSolver`](crate::iron_losses::jordan_model::FitSolver).
+pub type FitSteinmetzCurveResult = NelderMeadResult<FitSteinmetzCurve>;
+
+impl CostFunction for FitSteinmetzCurve {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, argmin::core::Error> {
+        let coefficient = coefficient_from_reference_loss(p[0], p[1], p[2]);
+        Ok(self.0.squared_error(|flux_density, frequency| {
+            losses(flux_density, frequency, coefficient, p[1], p[2])
+        }))
+    }
+}
+
+impl IronLossData {
+    /**
+    Performs least-square fitting of all the datapoints in `self` into the
+    Steinmetz equation using the [`argmin`] crate. This is the
+    [`SteinmetzModel`] counterpart to
+    [`IronLossData::solve_for_coefficients`]. The first element of the best
+    parameter vector is the specific loss at 50 Hz and 1.5 T in W/kg, the
+    second one the frequency exponent `α` and the third one the flux density
+    exponent `β`. Optimizing the loss at this point instead of the coefficient
+    `k` keeps the fit well conditioned; the [`TryFrom`] implementation of
+    [`SteinmetzModel`] converts it back into `k`.
+     */
+    pub fn solve_for_steinmetz_parameters(
+        &self,
+    ) -> Result<FitSteinmetzCurveResult, FailedCoefficientCalculation> {
+        // The frequency exponent can only be determined from at least two
+        // characteristics, the flux density exponent needs at least two
+        // datapoints per characteristic.
+        self.check_minimum_characteristic_count(2, 2)?;
+
+        let fit = FitSteinmetzCurve(FlatLossData::new(self));
+
+        // Specific loss at 50 Hz and 1.5 T in W/kg, exponents are unitless. Typical values for
+        // electrical steel are 1 < α < 2 and 1.5 < β < 3.
+        let start_values = vec![
+            vec![1.0f64, 1.5f64, 2.0f64],
+            vec![2.0f64, 1.2f64, 1.8f64],
+            vec![3.0f64, 1.8f64, 2.2f64],
+            vec![1.5f64, 1.0f64, 2.5f64],
+        ];
+
+        let config = SolverConfig {
+            max_iterations: 500,
+            min_iterations: 0,
+            sd_tolerance: 0.0001,
+        };
+        return run_nelder_mead(fit, start_values, config);
+    }
+}
+
+impl TryFrom<IronLossData> for SteinmetzModel {
+    type Error = FailedCoefficientCalculation;
+    fn try_from(value: IronLossData) -> Result<Self, Self::Error> {
+        return (&value).try_into();
+    }
+}
+
+impl TryFrom<&IronLossData> for SteinmetzModel {
+    type Error = FailedCoefficientCalculation;
+
+    fn try_from(value: &IronLossData) -> Result<Self, Self::Error> {
+        let res = value.solve_for_steinmetz_parameters()?;
+        let solution = res
+            .state
+            .get_best_param()
+            .ok_or_else(FailedCoefficientCalculation::missing_solution)?;
+
+        return Ok(SteinmetzModel {
+            coefficient: coefficient_from_reference_loss(solution[0], solution[1], solution[2]),
+            frequency_exponent: solution[1],
+            flux_density_exponent: solution[2],
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError;
+
+    #[derive(Deserialize)]
+    pub(super) struct SteinmetzModelAlias {
+        #[serde(deserialize_with = "deserialize_quantity")]
+        coefficient: SpecificPower,
+        frequency_exponent: f64,
+        flux_density_exponent: f64,
+    }
+
+    #[derive(DeserializeUntaggedVerboseError)]
+    pub(super) enum SteinmetzModelDeEnum {
+        SteinmetzModelAlias(SteinmetzModelAlias),
+        IronLossData(IronLossData),
+    }
+
+    impl TryFrom<SteinmetzModelDeEnum> for SteinmetzModel {
+        type Error = FailedCoefficientCalculation;
+
+        fn try_from(value: SteinmetzModelDeEnum) -> Result<Self, Self::Error> {
+            match value {
+                SteinmetzModelDeEnum::SteinmetzModelAlias(alias) => Ok(SteinmetzModel {
+                    coefficient: alias.coefficient,
+                    frequency_exponent: alias.frequency_exponent,
+                    flux_density_exponent: alias.flux_density_exponent,
+                }),
+                SteinmetzModelDeEnum::IronLossData(iron_loss_data) => iron_loss_data.try_into(),
+            }
+        }
+    }
+}
@@ -20,7 +20,12 @@ doc = ::embed_doc_image::embed_image!("jordan_model.svg", "docs/img/jordan_model
 #![doc = include_str!("../docs/main.md")]
 #![deny(missing_docs)]
 
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "database")]
+pub mod database;
 pub mod iron_losses;
+pub mod lookup_table;
 pub mod material;
 pub mod prelude;
 pub mod relative_permeability;
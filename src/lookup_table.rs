@@ -0,0 +1,217 @@
+/*!
+Look-up tables with linear interpolation for material properties.
+
+Many material properties are specified by manufacturers as tables, e.g. the
+electrical resistivity at a couple of temperatures. This module offers the
+[`LookupTable1D`] struct, which linearly interpolates between the given
+datapoints and implements [`IsQuantityFunction`]. Therefore it can be used in
+the `Function` variant of [`VarQuantity`](var_quantity::VarQuantity) for any
+field of a [`Material`](crate::material::Material).
+ */
+
+use var_quantity::{DynQuantity, IsQuantityFunction};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/**
+A look-up table for a single input quantity with linear interpolation.
+
+The table consists of the input values [`LookupTable1D::x`] and the output
+values [`LookupTable1D::y`]. When the table is called with a slice of
+conditions, the first condition whose unit matches that of the input values is
+used to interpolate linearly between the two adjacent datapoints. Outside the
+range of the input values, the output is clamped to the first respectively last
+output value. If none of the conditions matches the unit of the input values,
+the first output value is returned.
+
+All input values need to have the same unit and need to be strictly increasing.
+Likewise, all output values need to have the same unit. Since the output type
+is a [`DynQuantity`], the same struct can be used for any material property; the
+unit of the output values is checked when the table is wrapped into a
+[`QuantityFunction`](var_quantity::QuantityFunction).
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let table = LookupTable1D::new(
+    vec![
+        ThermodynamicTemperature::new::<degree_celsius>(20.0).into(),
+        ThermodynamicTemperature::new::<degree_celsius>(120.0).into(),
+    ],
+    vec![
+        ElectricalResistivity::new::<ohm_meter>(1.7e-8).into(),
+        ElectricalResistivity::new::<ohm_meter>(2.4e-8).into(),
+    ],
+)
+.unwrap();
+let resistivity: VarQuantity<ElectricalResistivity> =
+    VarQuantity::try_from_quantity_function(table).unwrap();
+
+// Interpolation between the datapoints
+let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(70.0).into()];
+approx::assert_abs_diff_eq!(
+    resistivity.get(conditions).get::<ohm_meter>(),
+    2.05e-8,
+    epsilon = 1e-15
+);
+
+// Clamping outside the datapoint range
+let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(200.0).into()];
+approx::assert_abs_diff_eq!(
+    resistivity.get(conditions).get::<ohm_meter>(),
+    2.4e-8,
+    epsilon = 1e-15
+);
+```
+ */
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "serde_impl::LookupTable1DAlias"))]
+pub struct LookupTable1D {
+    x: Vec<DynQuantity<f64>>,
+    y: Vec<DynQuantity<f64>>,
+}
+
+impl LookupTable1D {
+    /**
+    Returns a new [`LookupTable1D`], provided that the given input data is
+    valid. This is the case if none of the error cases of the
+    [`LookupTableError`] are fulfilled.
+     */
+    pub fn new(
+        x: Vec<DynQuantity<f64>>,
+        y: Vec<DynQuantity<f64>>,
+    ) -> Result<Self, LookupTableError> {
+        if x.is_empty() {
+            return Err(LookupTableError::Empty);
+        }
+        if x.len() != y.len() {
+            return Err(LookupTableError::IneqNumElements {
+                x: x.len(),
+                y: y.len(),
+            });
+        }
+        for index in 1..x.len() {
+            if x[index].unit != x[0].unit || y[index].unit != y[0].unit {
+                return Err(LookupTableError::InconsistentUnit { index });
+            }
+            if x[index].value <= x[index - 1].value {
+                return Err(LookupTableError::NotStrictlyIncreasing { index });
+            }
+        }
+        return Ok(Self { x, y });
+    }
+
+    /// Returns the input values of the table.
+    pub fn x(&self) -> &[DynQuantity<f64>] {
+        return self.x.as_slice();
+    }
+
+    /// Returns the output values of the table.
+    pub fn y(&self) -> &[DynQuantity<f64>] {
+        return self.y.as_slice();
+    }
+
+    /// Linearly interpolates the output value for the raw input `value`.
+    fn interpolate(&self, value: f64) -> f64 {
+        let last = self.x.len() - 1;
+        if value <= self.x[0].value {
+            return self.y[0].value;
+        }
+        if value >= self.x[last].value {
+            return self.y[last].value;
+        }
+        let upper = self.x.partition_point(|x| x.value <= value);
+        let lower = upper - 1;
+        let ratio = (value - self.x[lower].value) / (self.x[upper].value - self.x[lower].value);
+        return self.y[lower].value + ratio * (self.y[upper].value - self.y[lower].value);
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for LookupTable1D {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        let mut output = self.y[0];
+        for condition in conditions {
+            if condition.unit == self.x[0].unit {
+                output.value = self.interpolate(condition.value);
+                break;
+            }
+        }
+        return output;
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/**
+An error enum describing why the input data of a [`LookupTable1D`] is invalid.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum LookupTableError {
+    /// The table does not contain any datapoints.
+    Empty,
+    /// The number of input and output values is not equal.
+    IneqNumElements {
+        /// Number of input values.
+        x: usize,
+        /// Number of output values.
+        y: usize,
+    },
+    /// The unit of the input or output value at `index` differs from that of
+    /// the first datapoint.
+    InconsistentUnit {
+        /// Index of the offending datapoint.
+        index: usize,
+    },
+    /// The input value at `index` is not larger than its predecessor.
+    NotStrictlyIncreasing {
+        /// Index of the offending datapoint.
+        index: usize,
+    },
+}
+
+impl std::fmt::Display for LookupTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupTableError::Empty => write!(f, "the look-up table must not be empty"),
+            LookupTableError::IneqNumElements { x, y } => write!(
+                f,
+                "number of input values ({x}) and output values ({y}) must be equal"
+            ),
+            LookupTableError::InconsistentUnit { index } => write!(
+                f,
+                "unit of datapoint {index} differs from that of the first datapoint"
+            ),
+            LookupTableError::NotStrictlyIncreasing { index } => {
+                write!(f, "input value {index} must be larger than its predecessor")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupTableError {}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub(super) struct LookupTable1DAlias {
+        x: Vec<DynQuantity<f64>>,
+        y: Vec<DynQuantity<f64>>,
+    }
+
+    impl TryFrom<LookupTable1DAlias> for LookupTable1D {
+        type Error = LookupTableError;
+
+        fn try_from(value: LookupTable1DAlias) -> Result<Self, LookupTableError> {
+            return LookupTable1D::new(value.x, value.y);
+        }
+    }
+}
@@ -11,13 +11,32 @@ use var_quantity::VarQuantity;
 #[cfg(feature = "serde")]
 use std::ffi::OsStr;
 
-use std::{fmt::Debug, mem};
+#[cfg(feature = "serde")]
+use var_quantity::deserialize_quantity;
+
+use std::{
+    fmt::Debug,
+    hash::{DefaultHasher, Hash, Hasher},
+    mem,
+};
+use var_quantity::unary::FirstOrderTaylor;
 use var_quantity::uom::si::{
-    electrical_resistivity::ohm_meter, f64::*, magnetic_field_strength::ampere_per_meter,
-    magnetic_flux_density::tesla, magnetic_permeability::henry_per_meter,
-    mass_density::kilogram_per_cubic_meter, specific_heat_capacity::joule_per_kilogram_kelvin,
-    specific_power::watt_per_kilogram, thermal_conductivity::watt_per_meter_kelvin,
+    electrical_conductivity::siemens_per_meter,
+    electrical_resistivity::ohm_meter,
+    f64::*,
+    frequency::hertz,
+    magnetic_field_strength::ampere_per_meter,
+    magnetic_flux_density::tesla,
+    magnetic_permeability::henry_per_meter,
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    specific_power::watt_per_kilogram,
+    thermal_conductance::watt_per_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
 };
+use var_quantity::{DynQuantity, IsQuantity, IsQuantityFunction, PredefUnit, Unit};
 
 use crate::iron_losses::*;
 use crate::relative_permeability::*;
@@ -102,8 +121,17 @@ are also used when a property field is missing during deserialization. It also
 implements [`DatabaseEntry`] which is very useful when maintaining e.g. a
 database of motors: Commonly used materials such as copper for the wire only
 need to be defined once and can then be reused across all motors.
+
+Two [`Material`]s are equal if all of their fields are equal. Constant
+properties and the predefined models (e.g. [`JordanModel`] or
+[`FerromagneticPermeability`]) are compared structurally. A
+[`RelativePermeability::Function`] or [`IronLosses::Function`] is never equal
+to any other value, not even to itself, since the contained trait objects
+cannot be compared reliably. Functions within the other properties
+([`VarQuantity::Function`]) are compared via
+[`IsQuantityFunction::dyn_eq`].
 */
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct Material {
@@ -162,6 +190,60 @@ pub struct Material {
     /// Defaults to 0 W/(m * K).
     #[cfg_attr(feature = "serde", serde(default = "default_thermal_conductivity"))]
     pub thermal_conductivity: VarQuantity<ThermalConductivity>,
+
+    /// Curie temperature of `self`. Above this temperature, a ferromagnetic
+    /// material loses its ferromagnetic properties.
+    ///
+    /// Defaults to `None` (unknown or not applicable).
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "deserialize_optional_temperature"
+        )
+    )]
+    pub curie_temperature: Option<ThermodynamicTemperature>,
+
+    /// Stress-relief annealing temperature of `self`.
+    ///
+    /// Defaults to `None` (unknown or not applicable).
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "deserialize_optional_temperature"
+        )
+    )]
+    pub annealing_temperature: Option<ThermodynamicTemperature>,
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let relative_permeability_eq =
+            match (&self.relative_permeability, &other.relative_permeability) {
+                (RelativePermeability::Function(_), _) | (_, RelativePermeability::Function(_)) => {
+                    false
+                }
+                (first, second) => first == second,
+            };
+        let iron_losses_eq = match (&self.iron_losses, &other.iron_losses) {
+            (IronLosses::Function(_), _) | (_, IronLosses::Function(_)) => false,
+            (first, second) => first == second,
+        };
+        return self.name == other.name
+            && relative_permeability_eq
+            && iron_losses_eq
+            && self.remanence == other.remanence
+            && self.intrinsic_coercivity == other.intrinsic_coercivity
+            && self.electrical_resistivity == other.electrical_resistivity
+            && self.mass_density == other.mass_density
+            && self.heat_capacity == other.heat_capacity
+            && self.thermal_conductivity == other.thermal_conductivity
+            && self.curie_temperature == other.curie_temperature
+            && self.annealing_temperature == other.annealing_temperature;
+    }
 }
 
 impl Material {
@@ -175,6 +257,54 @@ impl Material {
         return mem::replace(&mut self.name, name);
     }
 
+    /**
+    Returns a clone of `self` with the name `new_name`.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let copper = MaterialBuilder::new().name("Copper").mass_density(
+        VarQuantity::Constant(MassDensity::new::<kilogram_per_cubic_meter>(8960.0)),
+    ).build();
+    let winding_a = copper.clone_with_name("Copper (winding A)".to_string());
+    assert_eq!(winding_a.name(), "Copper (winding A)");
+    assert_eq!(winding_a.mass_density(), copper.mass_density());
+    ```
+     */
+    pub fn clone_with_name(&self, new_name: String) -> Material {
+        let mut material = self.clone();
+        material.name = new_name;
+        return material;
+    }
+
+    /**
+    Returns a clone of `self` whose relative permeability is adjusted to the
+    given `iron_fill_factor`.
+
+    If the relative permeability is a
+    [`RelativePermeability::FerromagneticPermeability`], it is recreated from
+    its raw magnetization curve using
+    [`FerromagneticPermeability::change_iron_fill_factor`]. Any error from this
+    method (e.g. an invalid fill factor or a missing raw curve) is propagated.
+    All other variants of the relative permeability are cloned unchanged.
+     */
+    pub fn clone_with_iron_fill_factor(
+        &self,
+        iron_fill_factor: f64,
+    ) -> Result<Material, InvalidInputData> {
+        let mut material = self.clone();
+        if let RelativePermeability::FerromagneticPermeability(permeability) =
+            &self.relative_permeability
+        {
+            material.relative_permeability = RelativePermeability::FerromagneticPermeability(
+                permeability.change_iron_fill_factor(iron_fill_factor)?,
+            );
+        }
+        return Ok(material);
+    }
+
     /// Returns the relative permeability of `self`.
     pub fn relative_permeability(&self) -> &RelativePermeability {
         return &self.relative_permeability;
@@ -237,6 +367,52 @@ impl Material {
         return mem::replace(&mut self.electrical_resistivity, property);
     }
 
+    /**
+    Sets a temperature-dependent electrical resistivity and returns `self`.
+
+    The resistivity is modeled by a [`FirstOrderTaylor`] expansion
+    `ρ(T) = base_value * (1 + temperature_coefficient * (T - reference_temperature))`,
+    where `temperature_coefficient` is the relative change of the resistivity
+    per kelvin (e.g. `0.00393` for copper). If the [`FirstOrderTaylor`]
+    expansion cannot be constructed or does not return a resistivity, the
+    [`UnitsNotEqual`](var_quantity::UnitsNotEqual) error is returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let copper = Material::default()
+        .with_temperature_dependent_resistivity(
+            ElectricalResistivity::new::<ohm_meter>(1.0 / 56.0e6),
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            0.00393,
+        )
+        .unwrap();
+    let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(120.0).into()];
+    approx::assert_abs_diff_eq!(
+        copper.electrical_resistivity().get(conditions).get::<ohm_meter>(),
+        1.393 / 56.0e6,
+        epsilon = 1e-12
+    );
+    ```
+     */
+    pub fn with_temperature_dependent_resistivity(
+        mut self,
+        base_value: ElectricalResistivity,
+        reference_temperature: ThermodynamicTemperature,
+        temperature_coefficient: f64,
+    ) -> Result<Self, var_quantity::UnitsNotEqual> {
+        let dimensionless: DynQuantity<f64> = 1.0.into();
+        let slope = DynQuantity::new(
+            temperature_coefficient,
+            dimensionless.unit / Unit::from(PredefUnit::Temperature),
+        );
+        let taylor = FirstOrderTaylor::new(base_value.into(), slope, reference_temperature.into())?;
+        self.electrical_resistivity = VarQuantity::try_from_quantity_function(taylor)?;
+        return Ok(self);
+    }
+
     /// Returns the mass density of `self`.
     pub fn mass_density(&self) -> &VarQuantity<MassDensity> {
         return &self.mass_density;
@@ -275,8 +451,1696 @@ impl Material {
     ) -> VarQuantity<ThermalConductivity> {
         return mem::replace(&mut self.thermal_conductivity, property);
     }
+
+    /// Returns the Curie temperature of `self` (if known).
+    pub fn curie_temperature(&self) -> Option<ThermodynamicTemperature> {
+        return self.curie_temperature;
+    }
+
+    /// Sets a new Curie temperature and returns the old one.
+    pub fn set_curie_temperature(
+        &mut self,
+        property: Option<ThermodynamicTemperature>,
+    ) -> Option<ThermodynamicTemperature> {
+        return mem::replace(&mut self.curie_temperature, property);
+    }
+
+    /// Returns the stress-relief annealing temperature of `self` (if known).
+    pub fn annealing_temperature(&self) -> Option<ThermodynamicTemperature> {
+        return self.annealing_temperature;
+    }
+
+    /// Sets a new stress-relief annealing temperature and returns the old one.
+    pub fn set_annealing_temperature(
+        &mut self,
+        property: Option<ThermodynamicTemperature>,
+    ) -> Option<ThermodynamicTemperature> {
+        return mem::replace(&mut self.annealing_temperature, property);
+    }
+
+    /**
+    Returns the iron losses per volume for the given `conditions`.
+
+    This is the product of the specific iron losses and the mass density, both
+    evaluated for `conditions` (see [`IronLosses::get_density`]). It is e.g.
+    needed as the volumetric heat source in thermal simulations.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+    use stem_material::uom::si::volumetric_power_density::watt_per_cubic_meter;
+
+    let mut material = Material::default();
+    material.set_iron_losses(IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.0)));
+    material.set_mass_density(VarQuantity::Constant(
+        MassDensity::new::<kilogram_per_cubic_meter>(7650.0),
+    ));
+
+    // 2 W/kg * 7650 kg/m³ = 15300 W/m³
+    let loss_density = material.iron_loss_density(&[]);
+    approx::assert_abs_diff_eq!(loss_density.get::<watt_per_cubic_meter>(), 15300.0, epsilon = 1e-9);
+    ```
+     */
+    pub fn iron_loss_density(&self, conditions: &[DynQuantity<f64>]) -> VolumetricPowerDensity {
+        return self
+            .iron_losses
+            .get_density(conditions, self.mass_density.get(conditions));
+    }
+
+    /**
+    Returns the thermal conductance `G = λ * A / L` of a body made of `self`
+    with the given `length` and `cross_section_area` for the given
+    `conditions`, where `λ` is the thermal conductivity.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut copper = Material::default();
+    copper.set_thermal_conductivity(VarQuantity::Constant(
+        ThermalConductivity::new::<watt_per_meter_kelvin>(400.0),
+    ));
+    let conductance = copper.thermal_conductance(
+        &[],
+        Length::new::<meter>(0.1),
+        Area::new::<square_meter>(1e-4),
+    );
+    approx::assert_abs_diff_eq!(conductance.get::<watt_per_kelvin>(), 0.4, epsilon = 1e-12);
+    assert_eq!(
+        copper.thermal_resistance(&[], Length::new::<meter>(0.1), Area::new::<square_meter>(1e-4)),
+        1.0 / conductance.get::<watt_per_kelvin>()
+    );
+    ```
+     */
+    pub fn thermal_conductance(
+        &self,
+        conditions: &[DynQuantity<f64>],
+        length: Length,
+        cross_section_area: Area,
+    ) -> ThermalConductance {
+        return self.thermal_conductivity.get(conditions) * cross_section_area / length;
+    }
+
+    /**
+    Returns the thermal resistance `R = L / (λ * A)` in K/W of a body made of
+    `self` with the given `length` and `cross_section_area` for the given
+    `conditions`, where `λ` is the thermal conductivity.
+
+    Since [uom](crate::uom) does not offer a quantity for the thermal
+    resistance, the value is returned as a raw [`f64`] in K/W. It is the
+    inverse of [`Material::thermal_conductance`].
+     */
+    pub fn thermal_resistance(
+        &self,
+        conditions: &[DynQuantity<f64>],
+        length: Length,
+        cross_section_area: Area,
+    ) -> f64 {
+        return 1.0
+            / self
+                .thermal_conductance(conditions, length, cross_section_area)
+                .get::<watt_per_kelvin>();
+    }
+
+    /**
+    Returns the electrical resistance `R = ρ * L / A` of a conductor made of
+    `self` with the given `length` and `cross_section_area` for the given
+    `conditions`, where `ρ` is the electrical resistivity.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut copper = Material::default();
+    copper.set_electrical_resistivity(VarQuantity::Constant(
+        ElectricalResistivity::new::<ohm_meter>(1.75e-8),
+    ));
+    let resistance = copper.electrical_resistance(
+        &[],
+        Length::new::<meter>(1.0),
+        Area::new::<square_millimeter>(1.0),
+    );
+    approx::assert_abs_diff_eq!(resistance.get::<ohm>(), 0.0175, epsilon = 1e-12);
+    ```
+     */
+    pub fn electrical_resistance(
+        &self,
+        conditions: &[DynQuantity<f64>],
+        length: Length,
+        cross_section_area: Area,
+    ) -> ElectricalResistance {
+        return self.electrical_resistivity.get(conditions) * length / cross_section_area;
+    }
+
+    /**
+    Returns the electrical conductivity `σ = 1 / ρ` of `self` for the given
+    `conditions`, where `ρ` is the electrical resistivity. For an infinite
+    resistivity (the default value, i.e. an isolator), the conductivity is
+    zero.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut copper = Material::default();
+    assert_eq!(copper.electrical_conductivity(&[]).get::<siemens_per_meter>(), 0.0);
+
+    copper.set_electrical_resistivity(VarQuantity::Constant(
+        ElectricalResistivity::new::<ohm_meter>(1.0 / 56.0e6),
+    ));
+    approx::assert_relative_eq!(
+        copper.electrical_conductivity(&[]).get::<siemens_per_meter>(),
+        56.0e6,
+        epsilon = 1e-9
+    );
+    ```
+     */
+    pub fn electrical_conductivity(
+        &self,
+        conditions: &[DynQuantity<f64>],
+    ) -> ElectricalConductivity {
+        let resistivity = self.electrical_resistivity.get(conditions);
+        return ElectricalConductivity::new::<siemens_per_meter>(
+            resistivity.get::<ohm_meter>().recip(),
+        );
+    }
+
+    /**
+    Returns `true` if `self` is a ferromagnetic material.
+
+    This is the case if the relative permeability is a
+    [`RelativePermeability::FerromagneticPermeability`], a
+    [`RelativePermeability::CubicSplinePermeability`] or a
+    [`RelativePermeability::Constant`] larger than 1.01. Relative permeabilities
+    defined by a [`RelativePermeability::Function`] are not considered to be
+    ferromagnetic. Since no temperature is given, the
+    [`curie_temperature`](Material::curie_temperature) is not considered, see
+    [`Material::is_ferromagnetic_at`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut material = Material::default();
+    assert!(!material.is_ferromagnetic());
+
+    material.set_relative_permeability(RelativePermeability::Constant(1000.0));
+    assert!(material.is_ferromagnetic());
+    ```
+     */
+    #[inline]
+    pub fn is_ferromagnetic(&self) -> bool {
+        match &self.relative_permeability {
+            RelativePermeability::Constant(val) => return *val > 1.01,
+            RelativePermeability::FerromagneticPermeability(_) => return true,
+            RelativePermeability::CubicSplinePermeability(_) => return true,
+            RelativePermeability::Function(_) => return false,
+        }
+    }
+
+    /**
+    Returns `true` if `self` is a ferromagnetic material at the given
+    `temperature`.
+
+    This is the case if [`Material::is_ferromagnetic`] returns `true` and
+    `temperature` is below the [`curie_temperature`](Material::curie_temperature)
+    of `self`. If the Curie temperature is not known, only the former condition
+    is checked. A `NaN` temperature is not considered to be at or above the
+    Curie temperature.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut material = Material::default();
+    material.set_relative_permeability(RelativePermeability::Constant(1000.0));
+    material.set_curie_temperature(Some(ThermodynamicTemperature::new::<degree_celsius>(770.0)));
+
+    assert!(material.is_ferromagnetic_at(ThermodynamicTemperature::new::<degree_celsius>(20.0)));
+    assert!(!material.is_ferromagnetic_at(ThermodynamicTemperature::new::<degree_celsius>(800.0)));
+    ```
+     */
+    #[inline]
+    pub fn is_ferromagnetic_at(&self, temperature: ThermodynamicTemperature) -> bool {
+        if let Some(curie_temperature) = self.curie_temperature
+            && temperature >= curie_temperature
+        {
+            return false;
+        }
+        return self.is_ferromagnetic();
+    }
+
+    /**
+    Returns `true` if `self` is a permanent magnet, i.e. if its remanence
+    evaluated without any conditions is not zero.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut material = Material::default();
+    assert!(!material.is_permanent_magnet());
+
+    material.set_remanence(VarQuantity::Constant(MagneticFluxDensity::new::<tesla>(1.2)));
+    assert!(material.is_permanent_magnet());
+    ```
+     */
+    #[inline]
+    pub fn is_permanent_magnet(&self) -> bool {
+        return self.remanence.get(&[]).get::<tesla>() != 0.0;
+    }
+
+    /**
+    Returns the flux density `B` which results from the given `field_strength`.
+
+    For a ferromagnetic material, `B = µ0 * µr(B) * H` is an implicit equation
+    in `B`, which is solved with the Newton-Raphson method. The relative
+    permeability is evaluated for `conditions`, with any field strength or flux
+    density contained in `conditions` being replaced by the current iterate. The
+    initial guess is obtained from the relative permeability for
+    `field_strength` (which is already very close to the solution for the
+    spline-based models such as [`FerromagneticPermeability`]). The derivative
+    `dµr/dB` is calculated analytically via
+    [`FerromagneticPermeability::dmu_r_dB`] for a
+    [`RelativePermeability::FerromagneticPermeability`] and approximated with
+    central differences for all other models. The iteration
+    stops once the change of `B` between two iterations is less than or equal
+    to `tolerance` (in tesla). If this does not happen within `max_iter`
+    iterations, a [`ConvergenceError`] is returned.
+
+    For a [`RelativePermeability::Constant`], the equation is solved directly
+    without iteration.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let material = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(permeability))
+        .build();
+
+    let h = MagneticFieldStrength::new::<ampere_per_meter>(1000.0);
+    let b = material.compute_flux_density(h, &[], 1e-9, 50).unwrap();
+    approx::assert_abs_diff_eq!(b.get::<tesla>(), 1.5, epsilon = 0.02);
+    ```
+     */
+    pub fn compute_flux_density(
+        &self,
+        field_strength: MagneticFieldStrength,
+        conditions: &[DynQuantity<f64>],
+        tolerance: f64,
+        max_iter: usize,
+    ) -> Result<MagneticFluxDensity, ConvergenceError> {
+        let h = field_strength.get::<ampere_per_meter>();
+        if let RelativePermeability::Constant(mu_r) = &self.relative_permeability {
+            return Ok(MagneticFluxDensity::new::<tesla>(
+                VACUUM_PERMEABILITY_UNITLESS * mu_r * h,
+            ));
+        }
+
+        let mu_r = |b: f64| -> f64 {
+            let b = MagneticFluxDensity::new::<tesla>(b);
+            return self
+                .relative_permeability
+                .get(&with_magnetic_condition(b.into(), conditions));
+        };
+
+        // Initial guess from µr(H)
+        let mut b = VACUUM_PERMEABILITY_UNITLESS
+            * h
+            * self
+                .relative_permeability
+                .get(&with_magnetic_condition(field_strength.into(), conditions));
+
+        let dmu_r_db = |b: f64| -> f64 {
+            if let RelativePermeability::FerromagneticPermeability(model) =
+                &self.relative_permeability
+            {
+                // Derivative of the clamped µr(|B|) evaluated by `get`
+                if mu_r(b) <= 1.0 {
+                    return 0.0;
+                }
+                return b.signum() * model.dmu_r_dB(MagneticFluxDensity::new::<tesla>(b.abs()));
+            }
+            let delta = 1e-6 * b.abs().max(1.0);
+            return (mu_r(b + delta) - mu_r(b - delta)) / (2.0 * delta);
+        };
+
+        let mut step = f64::INFINITY;
+        for _ in 0..max_iter {
+            let residual = b - VACUUM_PERMEABILITY_UNITLESS * h * mu_r(b);
+            let derivative = 1.0 - VACUUM_PERMEABILITY_UNITLESS * h * dmu_r_db(b);
+
+            // Fall back to a fixed-point iteration if the derivative is unusable
+            step = if derivative.is_finite() && derivative != 0.0 {
+                residual / derivative
+            } else {
+                residual
+            };
+            b -= step;
+            if step.abs() <= tolerance {
+                return Ok(MagneticFluxDensity::new::<tesla>(b));
+            }
+        }
+        return Err(ConvergenceError {
+            iterations: max_iter,
+            last_step: step.abs(),
+        });
+    }
+
+    /**
+    Returns the field strength `H` which is needed to obtain the given
+    `flux_density`.
+
+    This is the counterpart to [`Material::compute_flux_density`]. Since the
+    relative permeability of the predefined models is a function of `B`, the
+    field strength can be calculated directly via `H = B / (µ0 * µr(B))`, with
+    `µr(B)` being evaluated for `conditions` (any field strength or flux density
+    contained in `conditions` is replaced by `flux_density`).
+
+    A [`RelativePermeability::Function`] might depend on the field strength
+    instead of the flux density. Therefore, it is evaluated with both the flux
+    density and the current field strength iterate as conditions, and the
+    equation `B = µ0 * µr(H) * H` is solved with the Newton-Raphson method. If
+    the iteration does not converge within 100 iterations, a
+    [`ConvergenceError`] is returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let material = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(permeability))
+        .build();
+
+    let b = MagneticFluxDensity::new::<tesla>(1.5);
+    let h = material.compute_field_strength(b, &[]).unwrap();
+    let b_back = material.compute_flux_density(h, &[], 1e-12, 50).unwrap();
+    approx::assert_abs_diff_eq!(b.get::<tesla>(), b_back.get::<tesla>(), epsilon = 1e-6);
+    ```
+     */
+    pub fn compute_field_strength(
+        &self,
+        flux_density: MagneticFluxDensity,
+        conditions: &[DynQuantity<f64>],
+    ) -> Result<MagneticFieldStrength, ConvergenceError> {
+        const MAX_ITER: usize = 100;
+        const REL_TOLERANCE: f64 = 1e-12;
+
+        let b = flux_density.get::<tesla>();
+        let conditions = with_magnetic_condition(flux_density.into(), conditions);
+        if !matches!(
+            self.relative_permeability,
+            RelativePermeability::Function(_)
+        ) {
+            let mu_r = self.relative_permeability.get(&conditions);
+            return Ok(MagneticFieldStrength::new::<ampere_per_meter>(
+                b / (VACUUM_PERMEABILITY_UNITLESS * mu_r),
+            ));
+        }
+
+        let mu_r = |h: f64| -> f64 {
+            let mut conditions = conditions.clone();
+            conditions.insert(1, MagneticFieldStrength::new::<ampere_per_meter>(h).into());
+            return self.relative_permeability.get(&conditions);
+        };
+
+        let mut h = b / (VACUUM_PERMEABILITY_UNITLESS * mu_r(0.0));
+        let mut step = f64::INFINITY;
+        for _ in 0..MAX_ITER {
+            let residual = VACUUM_PERMEABILITY_UNITLESS * h * mu_r(h) - b;
+            let delta = 1e-6 * h.abs().max(1.0);
+            let derivative = VACUUM_PERMEABILITY_UNITLESS
+                * ((h + delta) * mu_r(h + delta) - (h - delta) * mu_r(h - delta))
+                / (2.0 * delta);
+
+            // Fall back to a fixed-point iteration if the derivative is unusable
+            step = if derivative.is_finite() && derivative != 0.0 {
+                residual / derivative
+            } else {
+                residual / (VACUUM_PERMEABILITY_UNITLESS * mu_r(h))
+            };
+            h -= step;
+            if step.abs() <= REL_TOLERANCE * h.abs().max(1.0) {
+                return Ok(MagneticFieldStrength::new::<ampere_per_meter>(h));
+            }
+        }
+        return Err(ConvergenceError {
+            iterations: MAX_ITER,
+            last_step: step.abs(),
+        });
+    }
+
+    /**
+    Returns the maximum energy product `(BH)max` of a permanent magnet at the
+    given `temperature`.
+
+    The demagnetization curve is approximated as a straight line from
+    `(H = 0, B = remanence)` to `(H = intrinsic_coercivity, B = 0)`, where both
+    remanence and intrinsic coercivity are evaluated at `temperature`. The
+    curve is sampled and the maximum of the product `B * H` is returned. The
+    energy product has the unit J/m³, which is dimensionally equal to the unit
+    of [`Pressure`].
+
+    If either the remanence or the intrinsic coercivity is not positive,
+    `self` is not a permanent magnet and `None` is returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+    use stem_material::uom::si::pressure::pascal;
+
+    let mut magnet = Material::default();
+    assert!(magnet.energy_product_bh_max(ThermodynamicTemperature::new::<degree_celsius>(20.0)).is_none());
+
+    magnet.set_remanence(VarQuantity::Constant(MagneticFluxDensity::new::<tesla>(1.2)));
+    magnet.set_intrinsic_coercivity(VarQuantity::Constant(
+        MagneticFieldStrength::new::<ampere_per_meter>(900_000.0),
+    ));
+
+    // For a linear demagnetization curve, the maximum is Br * Hc / 4
+    let bh_max = magnet
+        .energy_product_bh_max(ThermodynamicTemperature::new::<degree_celsius>(20.0))
+        .unwrap();
+    approx::assert_abs_diff_eq!(bh_max.get::<pascal>(), 1.2 * 900_000.0 / 4.0, epsilon = 1e-6);
+    ```
+     */
+    pub fn energy_product_bh_max(&self, temperature: ThermodynamicTemperature) -> Option<Pressure> {
+        const NUM_SAMPLES: usize = 1000;
+
+        let conditions = &[temperature.into()];
+        let remanence = self.remanence.get(conditions).get::<tesla>();
+        let coercivity = self
+            .intrinsic_coercivity
+            .get(conditions)
+            .get::<ampere_per_meter>();
+        if remanence <= 0.0 || coercivity <= 0.0 {
+            return None;
+        }
+
+        // Linear approximation of the demagnetization curve B(H)
+        let flux_density = |field_strength: f64| remanence * (1.0 - field_strength / coercivity);
+
+        let mut bh_max = 0.0;
+        for idx in 0..=NUM_SAMPLES {
+            let field_strength = coercivity * idx as f64 / NUM_SAMPLES as f64;
+            bh_max = f64::max(bh_max, flux_density(field_strength) * field_strength);
+        }
+        return Some(Pressure::new::<pascal>(bh_max));
+    }
+
+    /**
+    Merges `self` with `other` field by field and returns the result.
+
+    A field is considered to be set if it differs from the corresponding field
+    of [`Material::default`]. If a field is only set in one of the two
+    materials, this value is used. If it is set in both materials, `priority`
+    decides which one wins. This is useful e.g. in hierarchical configurations
+    where a global default material is overridden by a local specification.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let global = MaterialBuilder::new()
+        .name("Steel")
+        .mass_density(VarQuantity::Constant(MassDensity::new::<kilogram_per_cubic_meter>(7650.0)))
+        .relative_permeability(RelativePermeability::Constant(1000.0))
+        .build();
+    let local = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::Constant(500.0))
+        .build();
+
+    let merged = local.clone().merge_with(global.clone(), MergePriority::Self_);
+    assert_eq!(merged.name(), "Steel");
+    assert_eq!(merged.mass_density(), global.mass_density());
+    assert_eq!(merged.relative_permeability(), &RelativePermeability::Constant(500.0));
+
+    let merged = local.merge_with(global, MergePriority::Other);
+    assert_eq!(merged.relative_permeability(), &RelativePermeability::Constant(1000.0));
+    ```
+     */
+    pub fn merge_with(self, other: Material, priority: MergePriority) -> Material {
+        let default = Material::default();
+        let (mut preferred, mut fallback) = match priority {
+            MergePriority::Self_ => (self, other),
+            MergePriority::Other => (other, self),
+        };
+
+        // Use the value of the fallback if the preferred one is not set
+        macro_rules! merge_field {
+            ($field:ident) => {
+                if preferred.$field == default.$field {
+                    mem::swap(&mut preferred.$field, &mut fallback.$field);
+                }
+            };
+        }
+
+        merge_field!(name);
+        merge_field!(relative_permeability);
+        merge_field!(iron_losses);
+        merge_field!(remanence);
+        merge_field!(intrinsic_coercivity);
+        merge_field!(electrical_resistivity);
+        merge_field!(mass_density);
+        merge_field!(heat_capacity);
+        merge_field!(thermal_conductivity);
+        merge_field!(curie_temperature);
+        merge_field!(annealing_temperature);
+        return preferred;
+    }
+
+    /**
+    Checks whether the properties of `self` are physically reasonable.
+
+    All properties are evaluated for a set of standard conditions, which are
+    all combinations of the temperatures -40 °C, 20 °C and 180 °C, the flux
+    densities 0 T, 1 T and 2 T and the frequencies 0 Hz, 50 Hz and 1000 Hz.
+    For constant properties, this is equivalent to checking the constant value
+    itself. A property is flagged as invalid if it is negative (or, in case of
+    the relative permeability, smaller than one) for at least one of these
+    conditions. Since the quantity functions of non-constant properties are
+    arbitrary, this check cannot prove their validity for all conditions.
+
+    The check does not short-circuit: If one or more properties are invalid, a
+    [`MaterialValidationError`] is returned for each of them, containing the
+    first violating value found.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut material = Material::default();
+    assert!(material.validate().is_ok());
+
+    material.mass_density = VarQuantity::Constant(MassDensity::new::<kilogram_per_cubic_meter>(-1.0));
+    material.relative_permeability = 0.5.into();
+    let errors = material.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    ```
+     */
+    pub fn validate(&self) -> Result<(), Vec<MaterialValidationError>> {
+        let conditions = standard_conditions();
+        let mut errors = Vec::new();
+
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.relative_permeability.get(c),
+            |v| v < 1.0,
+        ) {
+            errors.push(MaterialValidationError::PermeabilityBelowOne(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.iron_losses.get(c),
+            |v| v.get::<watt_per_kilogram>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeIronLosses(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.remanence.get(c),
+            |v| v.get::<tesla>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeRemanence(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.intrinsic_coercivity.get(c),
+            |v| v.get::<ampere_per_meter>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeIntrinsicCoercivity(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.electrical_resistivity.get(c),
+            |v| v.get::<ohm_meter>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeElectricalResistivity(
+                value,
+            ));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.mass_density.get(c),
+            |v| v.get::<kilogram_per_cubic_meter>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeMassDensity(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.heat_capacity.get(c),
+            |v| v.get::<joule_per_kilogram_kelvin>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeHeatCapacity(value));
+        }
+        if let Some(value) = first_violation(
+            &conditions,
+            |c| self.thermal_conductivity.get(c),
+            |v| v.get::<watt_per_meter_kelvin>() < 0.0,
+        ) {
+            errors.push(MaterialValidationError::NegativeThermalConductivity(value));
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        return Err(errors);
+    }
+
+    /**
+    Visits all properties of `self` with the given `visitor`.
+
+    The properties are visited in the order of the fields of [`Material`],
+    starting with the name. Constant properties are passed to the `visit_*`
+    method matching their type, properties which are not constant (e.g.
+    [`VarQuantity::Function`] or the models of [`RelativePermeability`] and
+    [`IronLosses`]) are passed to [`MaterialPropertyVisitor::visit_function`].
+    Properties which are not set (the `Option` fields) are passed to
+    [`MaterialPropertyVisitor::visit_none`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    #[derive(Default)]
+    struct Collector {
+        constants: Vec<String>,
+        functions: Vec<String>,
+    }
+
+    impl MaterialPropertyVisitor for Collector {
+        fn visit_mass_density(&mut self, name: &str, value: MassDensity) {
+            self.constants.push(format!("{name}: {}", value.get::<kilogram_per_cubic_meter>()));
+        }
+
+        fn visit_function(&mut self, name: &str) {
+            self.functions.push(name.to_string());
+        }
+    }
+
+    let material = MaterialBuilder::new()
+        .iron_losses(IronLosses::JordanModel(JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(1.0),
+            SpecificPower::new::<watt_per_kilogram>(0.5),
+        )))
+        .build();
+
+    let mut collector = Collector::default();
+    material.accept(&mut collector);
+    assert_eq!(collector.constants, vec!["mass_density: 1000".to_string()]);
+    assert_eq!(collector.functions, vec!["iron_losses".to_string()]);
+    ```
+     */
+    pub fn accept(&self, visitor: &mut dyn MaterialPropertyVisitor) {
+        macro_rules! visit_property {
+            ($field:ident, $method:ident) => {
+                match &self.$field {
+                    VarQuantity::Constant(v) => visitor.$method(stringify!($field), *v),
+                    _ => visitor.visit_function(stringify!($field)),
+                }
+            };
+        }
+
+        macro_rules! visit_optional_property {
+            ($field:ident, $method:ident) => {
+                match self.$field {
+                    Some(v) => visitor.$method(stringify!($field), v),
+                    None => visitor.visit_none(stringify!($field)),
+                }
+            };
+        }
+
+        visitor.visit_str("name", &self.name);
+        match &self.relative_permeability {
+            RelativePermeability::Constant(v) => visitor.visit_f64("relative_permeability", *v),
+            _ => visitor.visit_function("relative_permeability"),
+        }
+        match &self.iron_losses {
+            IronLosses::Constant(v) => visitor.visit_specific_power("iron_losses", *v),
+            _ => visitor.visit_function("iron_losses"),
+        }
+        visit_property!(remanence, visit_magnetic_flux_density);
+        visit_property!(intrinsic_coercivity, visit_magnetic_field_strength);
+        visit_property!(electrical_resistivity, visit_electrical_resistivity);
+        visit_property!(mass_density, visit_mass_density);
+        visit_property!(heat_capacity, visit_specific_heat_capacity);
+        visit_property!(thermal_conductivity, visit_thermal_conductivity);
+        visit_optional_property!(curie_temperature, visit_temperature);
+        visit_optional_property!(annealing_temperature, visit_temperature);
+    }
+
+    /**
+    Returns a fingerprint of `self` which can be used e.g. as a key for caching
+    simulation results in a [`HashMap`](std::collections::HashMap).
+
+    The name, the enum variants and all constant properties (via the bit
+    pattern of their SI value, see [`f64::to_bits`]) are fed into a
+    [`DefaultHasher`]. The relative permeability and iron loss models defined in
+    this crate (e.g. [`FerromagneticPermeability`] or [`JordanModel`])
+    contribute their parameters, i.e. the spline knots respectively the model
+    coefficients. For `Function` variants, only the name of the underlying
+    function type is hashed, since its parameters are not accessible through
+    [`IsQuantityFunction`]. With the `serde` feature, this is the stable name
+    the type is registered with for deserialization; otherwise, the
+    [`TypeId`](std::any::TypeId) of the type is used.
+
+    Equal materials have equal fingerprints, with the exception of constants
+    which compare equal despite different bit patterns (`0.0` and `-0.0`).
+    Since the [`DefaultHasher`] algorithm is not stable across Rust versions,
+    the fingerprint should not be persisted. This method
+    is deliberately not provided as a [`Hash`] implementation, since [`f64`]
+    does not implement [`Hash`] and [`Material`] does not implement [`Eq`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let copper = MaterialBuilder::new().name("Copper").mass_density(
+        VarQuantity::Constant(MassDensity::new::<kilogram_per_cubic_meter>(8960.0)),
+    ).build();
+    assert_eq!(copper.material_hash(), copper.clone().material_hash());
+
+    let aluminium = copper.clone_with_name("Aluminium".to_string());
+    assert_ne!(copper.material_hash(), aluminium.material_hash());
+    ```
+     */
+    pub fn material_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        macro_rules! hash_property {
+            ($field:ident) => {
+                match &self.$field {
+                    VarQuantity::Constant(v) => {
+                        0u8.hash(&mut hasher);
+                        v.value.to_bits().hash(&mut hasher);
+                    }
+                    VarQuantity::Function(function) => {
+                        1u8.hash(&mut hasher);
+                        hash_function_type(function.as_ref(), &mut hasher);
+                    }
+                }
+            };
+        }
+
+        macro_rules! hash_optional_property {
+            ($field:ident) => {
+                self.$field.map(|v| v.value.to_bits()).hash(&mut hasher);
+            };
+        }
+
+        self.name.hash(&mut hasher);
+        mem::discriminant(&self.relative_permeability).hash(&mut hasher);
+        match &self.relative_permeability {
+            RelativePermeability::Constant(v) => v.to_bits().hash(&mut hasher),
+            RelativePermeability::FerromagneticPermeability(model) => {
+                for spline in [&model.from_field_strength, &model.from_flux_density] {
+                    hash_values(spline.xs(), &mut hasher);
+                    hash_values(spline.ys(), &mut hasher);
+                }
+            }
+            RelativePermeability::CubicSplinePermeability(model) => {
+                for spline in [&model.from_field_strength, &model.from_flux_density] {
+                    hash_values(spline.x(), &mut hasher);
+                    hash_values(spline.y(), &mut hasher);
+                }
+            }
+            RelativePermeability::Function(function) => {
+                hash_function_type(function.as_ref(), &mut hasher)
+            }
+        }
+        mem::discriminant(&self.iron_losses).hash(&mut hasher);
+        match &self.iron_losses {
+            IronLosses::Constant(v) => v.value.to_bits().hash(&mut hasher),
+            IronLosses::JordanModel(model) => hash_jordan_model(model, &mut hasher),
+            IronLosses::SteinmetzModel(model) => hash_values(
+                &[
+                    model.coefficient.value,
+                    model.frequency_exponent,
+                    model.flux_density_exponent,
+                ],
+                &mut hasher,
+            ),
+            IronLosses::TemperatureScaledJordanModel(model) => {
+                hash_jordan_model(&model.base_model, &mut hasher);
+                hash_values(
+                    &[
+                        model.reference_temperature.value,
+                        model.hysteresis_temp_coefficient,
+                        model.eddy_current_temp_coefficient,
+                    ],
+                    &mut hasher,
+                );
+            }
+            IronLosses::GeneralizedJordanModel(model) => hash_values(
+                &[
+                    model.hysteresis_coefficient.value,
+                    model.eddy_current_coefficient.value,
+                    model.hysteresis_frequency_exponent,
+                    model.eddy_current_frequency_exponent,
+                ],
+                &mut hasher,
+            ),
+            IronLosses::Function(function) => hash_function_type(function.as_ref(), &mut hasher),
+        }
+        hash_property!(remanence);
+        hash_property!(intrinsic_coercivity);
+        hash_property!(electrical_resistivity);
+        hash_property!(mass_density);
+        hash_property!(heat_capacity);
+        hash_property!(thermal_conductivity);
+        hash_optional_property!(curie_temperature);
+        hash_optional_property!(annealing_temperature);
+        return hasher.finish();
+    }
 }
 
+/**
+Reset methods.
+
+Each of these methods resets a single property of a [`Material`] back to the
+value used by [`Material::default`] (which is also the value used for missing
+fields during deserialization). The documentation of the individual fields of
+[`Material`] lists these default values.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let mut material = MaterialBuilder::new()
+    .name("Iron")
+    .relative_permeability(1000.0)
+    .build();
+material.reset_relative_permeability();
+assert_eq!(material.relative_permeability(), Material::default().relative_permeability());
+assert_eq!(material.name(), "Iron");
+```
+ */
+impl Material {
+    /// Resets the relative permeability of `self` to its default value.
+    pub fn reset_relative_permeability(&mut self) {
+        self.relative_permeability = default_relative_permeability();
+    }
+
+    /// Resets the specific iron losses of `self` to its default value.
+    pub fn reset_iron_losses(&mut self) {
+        self.iron_losses = default_iron_losses();
+    }
+
+    /// Resets the remanence of `self` to its default value.
+    pub fn reset_remanence(&mut self) {
+        self.remanence = default_remanence();
+    }
+
+    /// Resets the intrinsic coercivity of `self` to its default value.
+    pub fn reset_intrinsic_coercivity(&mut self) {
+        self.intrinsic_coercivity = default_intrinsic_coercivity();
+    }
+
+    /// Resets the electrical resistivity of `self` to its default value.
+    pub fn reset_electrical_resistivity(&mut self) {
+        self.electrical_resistivity = default_electrical_resistivity();
+    }
+
+    /// Resets the mass density of `self` to its default value.
+    pub fn reset_mass_density(&mut self) {
+        self.mass_density = default_mass_density();
+    }
+
+    /// Resets the specific heat capacity of `self` to its default value.
+    pub fn reset_heat_capacity(&mut self) {
+        self.heat_capacity = default_heat_capacity();
+    }
+
+    /// Resets the thermal conductivity of `self` to its default value.
+    pub fn reset_thermal_conductivity(&mut self) {
+        self.thermal_conductivity = default_thermal_conductivity();
+    }
+
+    /// Resets the Curie temperature of `self` to its default value.
+    pub fn reset_curie_temperature(&mut self) {
+        self.curie_temperature = None;
+    }
+
+    /// Resets the stress-relief annealing temperature of `self` to its default value.
+    pub fn reset_annealing_temperature(&mut self) {
+        self.annealing_temperature = None;
+    }
+}
+
+/**
+Feeds the bit patterns of `values` into `hasher`, see [`Material::material_hash`].
+ */
+fn hash_values(values: &[f64], hasher: &mut DefaultHasher) {
+    for value in values {
+        value.to_bits().hash(hasher);
+    }
+}
+
+/**
+Feeds the coefficients of `model` into `hasher`, see [`Material::material_hash`].
+ */
+fn hash_jordan_model(model: &JordanModel, hasher: &mut DefaultHasher) {
+    hash_values(
+        &[
+            model.hysteresis_coefficient.value,
+            model.eddy_current_coefficient.value,
+        ],
+        hasher,
+    );
+}
+
+/**
+Feeds the name of the concrete type behind `function` into `hasher`, see
+[`Material::material_hash`].
+ */
+fn hash_function_type(function: &dyn IsQuantityFunction, hasher: &mut DefaultHasher) {
+    // The typetag name is stable, but only available with the serde feature
+    #[cfg(feature = "serde")]
+    function.typetag_name().hash(hasher);
+    #[cfg(not(feature = "serde"))]
+    (function as &dyn std::any::Any).type_id().hash(hasher);
+}
+
+/**
+String-based setter methods.
+
+Each of these methods parses a string such as `"8960 kg/m^3"` into a
+[`DynQuantity`] and, if its unit matches that of the property, replaces the
+property with a constant value. Only constant values can be set this way; a
+function can never be created from a string. This is useful e.g. for
+configuration systems which update individual properties at runtime.
+[`Material::set_property_by_name`] dispatches to these methods using the field
+name of the property.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let mut material = Material::default();
+material.set_mass_density_from_str("8960 kg/m^3").unwrap();
+assert_eq!(
+    material.mass_density().get(&[]),
+    MassDensity::new::<kilogram_per_cubic_meter>(8960.0)
+);
+
+// Wrong unit
+assert!(material.set_mass_density_from_str("8960 W/kg").is_err());
+```
+ */
+#[cfg(feature = "serde")]
+impl Material {
+    /**
+    Sets the property with the field name `name` (e.g. `"mass_density"`) to
+    the value parsed from `value`.
+
+    For `"name"`, `value` is used as the new name directly. For all other
+    fields, the corresponding `set_*_from_str` method is called. If `name` does
+    not match any field, [`PropertySetError::UnknownProperty`] is returned.
+    In case of an error, `self` is left unchanged.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mut material = Material::default();
+    material.set_property_by_name("name", "Copper").unwrap();
+    material.set_property_by_name("electrical_resistivity", "1.7e-8 Ohm*m").unwrap();
+    assert_eq!(material.name(), "Copper");
+    approx::assert_abs_diff_eq!(
+        material.electrical_resistivity().get(&[]).get::<ohm_meter>(),
+        1.7e-8,
+        epsilon = 1e-20
+    );
+
+    assert!(matches!(
+        material.set_property_by_name("colour", "red"),
+        Err(PropertySetError::UnknownProperty(_))
+    ));
+    ```
+     */
+    pub fn set_property_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), PropertySetError> {
+        match name {
+            "name" => self.name = value.to_string(),
+            "relative_permeability" => self.set_relative_permeability_from_str(value)?,
+            "iron_losses" => self.set_iron_losses_from_str(value)?,
+            "remanence" => self.set_remanence_from_str(value)?,
+            "intrinsic_coercivity" => self.set_intrinsic_coercivity_from_str(value)?,
+            "electrical_resistivity" => self.set_electrical_resistivity_from_str(value)?,
+            "mass_density" => self.set_mass_density_from_str(value)?,
+            "heat_capacity" => self.set_heat_capacity_from_str(value)?,
+            "thermal_conductivity" => self.set_thermal_conductivity_from_str(value)?,
+            "curie_temperature" => self.set_curie_temperature_from_str(value)?,
+            "annealing_temperature" => self.set_annealing_temperature_from_str(value)?,
+            _ => return Err(PropertySetError::UnknownProperty(name.to_string())),
+        }
+        return Ok(());
+    }
+
+    /// Sets the relative permeability of `self` to the dimensionless constant
+    /// parsed from `s`.
+    pub fn set_relative_permeability_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.relative_permeability =
+            RelativePermeability::Constant(parse_property("relative_permeability", s)?);
+        return Ok(());
+    }
+
+    /// Sets the specific iron losses of `self` to the constant parsed from `s`.
+    pub fn set_iron_losses_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.iron_losses = IronLosses::Constant(parse_property("iron_losses", s)?);
+        return Ok(());
+    }
+
+    /// Sets the remanence of `self` to the constant parsed from `s`.
+    pub fn set_remanence_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.remanence = VarQuantity::Constant(parse_property("remanence", s)?);
+        return Ok(());
+    }
+
+    /// Sets the intrinsic coercivity of `self` to the constant parsed from `s`.
+    pub fn set_intrinsic_coercivity_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.intrinsic_coercivity =
+            VarQuantity::Constant(parse_property("intrinsic_coercivity", s)?);
+        return Ok(());
+    }
+
+    /// Sets the electrical resistivity of `self` to the constant parsed from `s`.
+    pub fn set_electrical_resistivity_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.electrical_resistivity =
+            VarQuantity::Constant(parse_property("electrical_resistivity", s)?);
+        return Ok(());
+    }
+
+    /// Sets the mass density of `self` to the constant parsed from `s`.
+    pub fn set_mass_density_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.mass_density = VarQuantity::Constant(parse_property("mass_density", s)?);
+        return Ok(());
+    }
+
+    /// Sets the specific heat capacity of `self` to the constant parsed from `s`.
+    pub fn set_heat_capacity_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.heat_capacity = VarQuantity::Constant(parse_property("heat_capacity", s)?);
+        return Ok(());
+    }
+
+    /// Sets the thermal conductivity of `self` to the constant parsed from `s`.
+    pub fn set_thermal_conductivity_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.thermal_conductivity =
+            VarQuantity::Constant(parse_property("thermal_conductivity", s)?);
+        return Ok(());
+    }
+
+    /// Sets the Curie temperature of `self` to the value parsed from `s`.
+    pub fn set_curie_temperature_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.curie_temperature = Some(parse_property("curie_temperature", s)?);
+        return Ok(());
+    }
+
+    /// Sets the stress-relief annealing temperature of `self` to the value
+    /// parsed from `s`.
+    pub fn set_annealing_temperature_from_str(&mut self, s: &str) -> Result<(), PropertySetError> {
+        self.annealing_temperature = Some(parse_property("annealing_temperature", s)?);
+        return Ok(());
+    }
+}
+
+/**
+Parses `s` into a [`DynQuantity`] and converts it into `T`. `property` is the
+field name used in the error message if the unit does not match.
+ */
+#[cfg(feature = "serde")]
+fn parse_property<T: TryFrom<DynQuantity<f64>>>(
+    property: &'static str,
+    s: &str,
+) -> Result<T, PropertySetError> {
+    let quantity: DynQuantity<f64> = s.parse().map_err(PropertySetError::InvalidQuantity)?;
+    return T::try_from(quantity).map_err(|_| PropertySetError::UnexpectedUnit {
+        property,
+        unit: quantity.unit,
+    });
+}
+
+/**
+Errors which can occur when setting a property of a [`Material`] from a string,
+e.g. via [`Material::set_property_by_name`].
+ */
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum PropertySetError {
+    /// The given name does not match any property of a [`Material`].
+    UnknownProperty(String),
+    /// The string could not be parsed into a [`DynQuantity`].
+    InvalidQuantity(<DynQuantity<f64> as std::str::FromStr>::Err),
+    /// The unit of the parsed quantity does not match that of the property.
+    UnexpectedUnit {
+        /// Field name of the property.
+        property: &'static str,
+        /// Unit of the parsed quantity.
+        unit: Unit,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for PropertySetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertySetError::UnknownProperty(name) => {
+                write!(f, "material has no property named {name}.")
+            }
+            PropertySetError::InvalidQuantity(error) => return std::fmt::Display::fmt(error, f),
+            PropertySetError::UnexpectedUnit { property, unit } => write!(
+                f,
+                "got a quantity with unit {unit:?}, which does not match the unit of property {property}."
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for PropertySetError {}
+
+/**
+Decides which [`Material`] wins in [`Material::merge_with`] if a field is set
+in both materials.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePriority {
+    /// The value of the material `merge_with` is called on wins.
+    Self_,
+    /// The value of the material passed to `merge_with` wins.
+    Other,
+}
+
+/**
+A visitor for the properties of a [`Material`], see [`Material::accept`].
+
+Each method receives the name of the visited field (e.g. `"mass_density"`) and,
+if the property is constant, its value. All methods have an empty default
+implementation, so implementors only need to override the methods for the
+properties they are interested in.
+ */
+pub trait MaterialPropertyVisitor {
+    /// Visits a string property (the name of the material).
+    fn visit_str(&mut self, _name: &str, _value: &str) {}
+
+    /// Visits a constant dimensionless property (the relative permeability).
+    fn visit_f64(&mut self, _name: &str, _value: f64) {}
+
+    /// Visits a constant specific power (the iron losses).
+    fn visit_specific_power(&mut self, _name: &str, _value: SpecificPower) {}
+
+    /// Visits a constant magnetic flux density (the remanence).
+    fn visit_magnetic_flux_density(&mut self, _name: &str, _value: MagneticFluxDensity) {}
+
+    /// Visits a constant magnetic field strength (the intrinsic coercivity).
+    fn visit_magnetic_field_strength(&mut self, _name: &str, _value: MagneticFieldStrength) {}
+
+    /// Visits a constant electrical resistivity.
+    fn visit_electrical_resistivity(&mut self, _name: &str, _value: ElectricalResistivity) {}
+
+    /// Visits a constant mass density.
+    fn visit_mass_density(&mut self, _name: &str, _value: MassDensity) {}
+
+    /// Visits a constant specific heat capacity.
+    fn visit_specific_heat_capacity(&mut self, _name: &str, _value: SpecificHeatCapacity) {}
+
+    /// Visits a constant thermal conductivity.
+    fn visit_thermal_conductivity(&mut self, _name: &str, _value: ThermalConductivity) {}
+
+    /// Visits a temperature (the Curie or annealing temperature).
+    fn visit_temperature(&mut self, _name: &str, _value: ThermodynamicTemperature) {}
+
+    /// Visits a property which is not constant, e.g. a
+    /// [`VarQuantity::Function`] or a model such as a [`JordanModel`].
+    fn visit_function(&mut self, _name: &str) {}
+
+    /// Visits an optional property which is not set.
+    fn visit_none(&mut self, _name: &str) {}
+}
+
+/**
+Convenience accessors for the variants of a [`VarQuantity`].
+
+Since [`VarQuantity`] is defined in the [var_quantity] crate, these accessors
+are provided as an extension trait. It is part of the
+[prelude](crate::prelude), so the methods can be called directly on the
+properties of a [`Material`]. Together with the `?` operator, this replaces
+manual matching on the variants.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+fn mass_density_in_kg_per_m3(material: &Material) -> Option<f64> {
+    let mass_density = material.mass_density().try_as_constant()?;
+    return Some(mass_density.get::<kilogram_per_cubic_meter>());
+}
+
+let material = Material::default();
+assert_eq!(mass_density_in_kg_per_m3(&material), Some(1000.0));
+assert!(material.mass_density().try_as_function().is_none());
+```
+ */
+pub trait VarQuantityExt<T> {
+    /// Returns a reference to the value if `self` is a
+    /// [`VarQuantity::Constant`].
+    fn try_as_constant(&self) -> Option<&T>;
+
+    /// Returns a reference to the underlying function if `self` is a
+    /// [`VarQuantity::Function`].
+    fn try_as_function(&self) -> Option<&dyn IsQuantityFunction>;
+}
+
+impl<T: IsQuantity> VarQuantityExt<T> for VarQuantity<T> {
+    fn try_as_constant(&self) -> Option<&T> {
+        match self {
+            VarQuantity::Constant(value) => return Some(value),
+            _ => return None,
+        }
+    }
+
+    fn try_as_function(&self) -> Option<&dyn IsQuantityFunction> {
+        match self {
+            VarQuantity::Function(quantity_function) => return Some(quantity_function.as_ref()),
+            _ => return None,
+        }
+    }
+}
+
+/**
+Prints the name of the material followed by one line per property. Constant
+properties are printed with their value in SI units, all other properties with
+a short description of their model.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let material = Material::default();
+let text = material.to_string();
+assert!(text.starts_with("default_name"));
+assert!(text.contains("mass_density: 1000 kg/m³"));
+```
+ */
+impl std::fmt::Display for Material {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Constant values are printed in SI units, functions are only named
+        macro_rules! write_property {
+            ($field:ident, $unit:ty, $unit_str:expr) => {
+                match &self.$field {
+                    VarQuantity::Constant(v) => writeln!(
+                        f,
+                        "  {}: {} {}",
+                        stringify!($field),
+                        v.get::<$unit>(),
+                        $unit_str
+                    )?,
+                    _ => writeln!(f, "  {}: function", stringify!($field))?,
+                }
+            };
+        }
+
+        writeln!(f, "{}", self.name)?;
+        writeln!(f, "  relative_permeability: {}", self.relative_permeability)?;
+        writeln!(f, "  iron_losses: {}", self.iron_losses)?;
+        write_property!(remanence, tesla, "T");
+        write_property!(intrinsic_coercivity, ampere_per_meter, "A/m");
+        write_property!(electrical_resistivity, ohm_meter, "Ω·m");
+        write_property!(mass_density, kilogram_per_cubic_meter, "kg/m³");
+        write_property!(heat_capacity, joule_per_kilogram_kelvin, "J/(kg·K)");
+        write_property!(thermal_conductivity, watt_per_meter_kelvin, "W/(m·K)");
+        if let Some(temperature) = self.curie_temperature {
+            writeln!(f, "  curie_temperature: {} K", temperature.get::<kelvin>())?;
+        }
+        if let Some(temperature) = self.annealing_temperature {
+            writeln!(
+                f,
+                "  annealing_temperature: {} K",
+                temperature.get::<kelvin>()
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+/**
+Returns all combinations of the standard temperatures, flux densities and
+frequencies used by [`Material::validate`].
+ */
+fn standard_conditions() -> Vec<[DynQuantity<f64>; 3]> {
+    let mut conditions = Vec::with_capacity(27);
+    for temperature in [-40.0, 20.0, 180.0] {
+        for flux_density in [0.0, 1.0, 2.0] {
+            for frequency in [0.0, 50.0, 1000.0] {
+                conditions.push([
+                    ThermodynamicTemperature::new::<degree_celsius>(temperature).into(),
+                    MagneticFluxDensity::new::<tesla>(flux_density).into(),
+                    Frequency::new::<hertz>(frequency).into(),
+                ]);
+            }
+        }
+    }
+    return conditions;
+}
+
+/**
+Evaluates `property` for all `conditions` and returns the first value for
+which `violates` returns true.
+ */
+fn first_violation<T, P, V>(
+    conditions: &[[DynQuantity<f64>; 3]],
+    property: P,
+    violates: V,
+) -> Option<T>
+where
+    T: Copy,
+    P: Fn(&[DynQuantity<f64>]) -> T,
+    V: Fn(T) -> bool,
+{
+    return conditions
+        .iter()
+        .map(|c| property(c.as_slice()))
+        .find(|value| violates(*value));
+}
+
+/**
+Returns `conditions` with all field strengths and flux densities removed and
+`value` inserted at the first position. This makes sure that a relative
+permeability is evaluated for `value`.
+ */
+fn with_magnetic_condition(
+    value: DynQuantity<f64>,
+    conditions: &[DynQuantity<f64>],
+) -> Vec<DynQuantity<f64>> {
+    let field_strength_unit = Unit::from(PredefUnit::MagneticFieldStrength);
+    let flux_density_unit = Unit::from(PredefUnit::MagneticFluxDensity);
+
+    let mut output = Vec::with_capacity(conditions.len() + 1);
+    output.push(value);
+    output.extend(
+        conditions
+            .iter()
+            .filter(|c| c.unit != field_strength_unit && c.unit != flux_density_unit)
+            .cloned(),
+    );
+    return output;
+}
+
+/**
+A linear temperature model for the remanence of a permanent magnet.
+
+The remanence of permanent magnets decreases approximately linearly with
+rising temperature. This struct models this behaviour as
+`Br(T) = remanence_at_reference + temperature_coefficient * (T - reference_temperature)`
+if the temperature coefficient is given in T/K or as
+`Br(T) = remanence_at_reference * (1 + temperature_coefficient * (T - reference_temperature))`
+if [`TemperatureDependentRemanence::relative_coefficient`] is `true` and the
+temperature coefficient is therefore given as fraction per kelvin (e.g.
+`-0.0012` for a typical NdFeB magnet with -0.12 %/K). In both cases, the
+resulting remanence is clamped at zero.
+
+The primary interface is [`TemperatureDependentRemanence::remanence`]. The
+struct also implements [`IsQuantityFunction`] and can therefore be used as the
+[`remanence`](Material::remanence) of a [`Material`]. When called, the first
+temperature found in the conditions is used; if no temperature is given, the
+remanence at the reference temperature is returned.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let model = TemperatureDependentRemanence::new_relative(
+    MagneticFluxDensity::new::<tesla>(1.2),
+    ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    -0.0012,
+);
+let br = model.remanence(ThermodynamicTemperature::new::<degree_celsius>(120.0));
+approx::assert_abs_diff_eq!(br.get::<tesla>(), 1.2 * 0.88, epsilon = 1e-12);
+
+let magnet = MaterialBuilder::new()
+    .remanence(VarQuantity::try_from_quantity_function(model).unwrap())
+    .build();
+let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(120.0).into()];
+approx::assert_abs_diff_eq!(
+    magnet.remanence().get(conditions).get::<tesla>(),
+    1.2 * 0.88,
+    epsilon = 1e-12
+);
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemperatureDependentRemanence {
+    /// Remanence at the reference temperature.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub remanence_at_reference: MagneticFluxDensity,
+    /// Temperature at which
+    /// [`TemperatureDependentRemanence::remanence_at_reference`] is valid.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub reference_temperature: ThermodynamicTemperature,
+    /// Change of the remanence per kelvin, either in T/K or as fraction per
+    /// kelvin (see [`TemperatureDependentRemanence::relative_coefficient`]).
+    pub temperature_coefficient: f64,
+    /// If `true`, [`TemperatureDependentRemanence::temperature_coefficient`]
+    /// is interpreted as fraction of
+    /// [`TemperatureDependentRemanence::remanence_at_reference`] per kelvin,
+    /// otherwise it is interpreted in T/K. Defaults to `false` during
+    /// deserialization.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub relative_coefficient: bool,
+}
+
+impl TemperatureDependentRemanence {
+    /**
+    Returns a new [`TemperatureDependentRemanence`] whose
+    `temperature_coefficient` is given in T/K.
+     */
+    pub fn new(
+        remanence_at_reference: MagneticFluxDensity,
+        reference_temperature: ThermodynamicTemperature,
+        temperature_coefficient: f64,
+    ) -> Self {
+        return Self {
+            remanence_at_reference,
+            reference_temperature,
+            temperature_coefficient,
+            relative_coefficient: false,
+        };
+    }
+
+    /**
+    Returns a new [`TemperatureDependentRemanence`] whose
+    `temperature_coefficient` is given as fraction of the
+    `remanence_at_reference` per kelvin.
+     */
+    pub fn new_relative(
+        remanence_at_reference: MagneticFluxDensity,
+        reference_temperature: ThermodynamicTemperature,
+        temperature_coefficient: f64,
+    ) -> Self {
+        return Self {
+            remanence_at_reference,
+            reference_temperature,
+            temperature_coefficient,
+            relative_coefficient: true,
+        };
+    }
+
+    /**
+    Returns the remanence at the given `temperature`, clamped at zero.
+     */
+    pub fn remanence(&self, temperature: ThermodynamicTemperature) -> MagneticFluxDensity {
+        let delta = temperature.get::<kelvin>() - self.reference_temperature.get::<kelvin>();
+        let reference = self.remanence_at_reference.get::<tesla>();
+        let remanence = if self.relative_coefficient {
+            reference * (1.0 + self.temperature_coefficient * delta)
+        } else {
+            reference + self.temperature_coefficient * delta
+        };
+        return MagneticFluxDensity::new::<tesla>(remanence.max(0.0));
+    }
+}
+
+impl std::fmt::Display for TemperatureDependentRemanence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = if self.relative_coefficient {
+            "1/K"
+        } else {
+            "T/K"
+        };
+        write!(
+            f,
+            "{} T at {} K (temperature coefficient: {} {unit})",
+            self.remanence_at_reference.get::<tesla>(),
+            self.reference_temperature.get::<kelvin>(),
+            self.temperature_coefficient
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for TemperatureDependentRemanence {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        for factor in conditions {
+            if let Ok(temperature) = ThermodynamicTemperature::try_from(*factor) {
+                return self.remanence(temperature).into();
+            }
+        }
+        return self.remanence_at_reference.into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+/**
+Error returned by iterative solvers such as [`Material::compute_flux_density`]
+if they did not converge within the given maximum number of iterations.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvergenceError {
+    /// Number of performed iterations.
+    pub iterations: usize,
+    /// Absolute change of the solution (in SI units) in the last iteration.
+    pub last_step: f64,
+}
+
+impl std::fmt::Display for ConvergenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "solver did not converge within {} iterations (last step: {}).",
+            self.iterations, self.last_step
+        )
+    }
+}
+
+impl std::error::Error for ConvergenceError {}
+
+/**
+A physically unreasonable property of a [`Material`] found by
+[`Material::validate`]. Each variant contains the first violating value.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaterialValidationError {
+    /// The relative permeability is smaller than one.
+    PermeabilityBelowOne(f64),
+    /// The specific iron losses are negative.
+    NegativeIronLosses(SpecificPower),
+    /// The remanence is negative.
+    NegativeRemanence(MagneticFluxDensity),
+    /// The intrinsic coercivity is negative.
+    NegativeIntrinsicCoercivity(MagneticFieldStrength),
+    /// The electrical resistivity is negative.
+    NegativeElectricalResistivity(ElectricalResistivity),
+    /// The mass density is negative.
+    NegativeMassDensity(MassDensity),
+    /// The specific heat capacity is negative.
+    NegativeHeatCapacity(SpecificHeatCapacity),
+    /// The thermal conductivity is negative.
+    NegativeThermalConductivity(ThermalConductivity),
+}
+
+impl std::fmt::Display for MaterialValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaterialValidationError::PermeabilityBelowOne(value) => {
+                write!(f, "relative permeability must be at least 1, is {value}.")
+            }
+            MaterialValidationError::NegativeIronLosses(value) => write!(
+                f,
+                "iron losses must not be negative, are {} W/kg.",
+                value.get::<watt_per_kilogram>()
+            ),
+            MaterialValidationError::NegativeRemanence(value) => write!(
+                f,
+                "remanence must not be negative, is {} T.",
+                value.get::<tesla>()
+            ),
+            MaterialValidationError::NegativeIntrinsicCoercivity(value) => write!(
+                f,
+                "intrinsic coercivity must not be negative, is {} A/m.",
+                value.get::<ampere_per_meter>()
+            ),
+            MaterialValidationError::NegativeElectricalResistivity(value) => write!(
+                f,
+                "electrical resistivity must not be negative, is {} Ohm*m.",
+                value.get::<ohm_meter>()
+            ),
+            MaterialValidationError::NegativeMassDensity(value) => write!(
+                f,
+                "mass density must not be negative, is {} kg/m³.",
+                value.get::<kilogram_per_cubic_meter>()
+            ),
+            MaterialValidationError::NegativeHeatCapacity(value) => write!(
+                f,
+                "specific heat capacity must not be negative, is {} J/(kg*K).",
+                value.get::<joule_per_kilogram_kelvin>()
+            ),
+            MaterialValidationError::NegativeThermalConductivity(value) => write!(
+                f,
+                "thermal conductivity must not be negative, is {} W/(m*K).",
+                value.get::<watt_per_meter_kelvin>()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MaterialValidationError {}
+
 impl Default for Material {
     fn default() -> Self {
         return Material {
@@ -289,10 +2153,144 @@ impl Default for Material {
             mass_density: default_mass_density(),
             heat_capacity: default_heat_capacity(),
             thermal_conductivity: default_thermal_conductivity(),
+            curie_temperature: None,
+            annealing_temperature: None,
         };
     }
 }
 
+/**
+A builder for [`Material`].
+
+The builder starts from [`Material::default`] and provides a fluent setter for
+each field, mirroring the `set_*` methods of [`Material`]. Fields which are not
+set keep their default values. The setters accept anything which can be
+converted into the field type, e.g. an [`f64`] for the relative permeability.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let material = MaterialBuilder::new()
+    .name("Copper")
+    .relative_permeability(1.0)
+    .electrical_resistivity(VarQuantity::Constant(
+        ElectricalResistivity::new::<ohm_meter>(1.68e-8),
+    ))
+    .mass_density(VarQuantity::Constant(
+        MassDensity::new::<kilogram_per_cubic_meter>(8960.0),
+    ))
+    .build();
+
+assert_eq!(material.name(), "Copper");
+```
+ */
+#[derive(Clone, Debug, Default)]
+pub struct MaterialBuilder(Material);
+
+impl MaterialBuilder {
+    /// Returns a new builder whose fields are initialized with [`Material::default`].
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Sets the name of the [`Material`] (see [`Material::set_name`]).
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.0.name = name.into();
+        return self;
+    }
+
+    /// Sets the relative permeability of the [`Material`] (see [`Material::set_relative_permeability`]).
+    pub fn relative_permeability(mut self, property: impl Into<RelativePermeability>) -> Self {
+        self.0.relative_permeability = property.into();
+        return self;
+    }
+
+    /// Sets the specific iron losses of the [`Material`] (see [`Material::set_iron_losses`]).
+    pub fn iron_losses(mut self, property: impl Into<IronLosses>) -> Self {
+        self.0.iron_losses = property.into();
+        return self;
+    }
+
+    /// Sets the remanence of the [`Material`] (see [`Material::set_remanence`]).
+    pub fn remanence(mut self, property: impl Into<VarQuantity<MagneticFluxDensity>>) -> Self {
+        self.0.remanence = property.into();
+        return self;
+    }
+
+    /// Sets the intrinsic coercivity of the [`Material`] (see [`Material::set_intrinsic_coercivity`]).
+    pub fn intrinsic_coercivity(
+        mut self,
+        property: impl Into<VarQuantity<MagneticFieldStrength>>,
+    ) -> Self {
+        self.0.intrinsic_coercivity = property.into();
+        return self;
+    }
+
+    /// Sets the electrical resistivity of the [`Material`] (see [`Material::set_electrical_resistivity`]).
+    pub fn electrical_resistivity(
+        mut self,
+        property: impl Into<VarQuantity<ElectricalResistivity>>,
+    ) -> Self {
+        self.0.electrical_resistivity = property.into();
+        return self;
+    }
+
+    /// Sets a temperature-dependent electrical resistivity of the [`Material`] (see [`Material::with_temperature_dependent_resistivity`]).
+    pub fn temperature_dependent_resistivity(
+        mut self,
+        base_value: ElectricalResistivity,
+        reference_temperature: ThermodynamicTemperature,
+        temperature_coefficient: f64,
+    ) -> Result<Self, var_quantity::UnitsNotEqual> {
+        self.0 = self.0.with_temperature_dependent_resistivity(
+            base_value,
+            reference_temperature,
+            temperature_coefficient,
+        )?;
+        return Ok(self);
+    }
+
+    /// Sets the mass density of the [`Material`] (see [`Material::set_mass_density`]).
+    pub fn mass_density(mut self, property: impl Into<VarQuantity<MassDensity>>) -> Self {
+        self.0.mass_density = property.into();
+        return self;
+    }
+
+    /// Sets the specific heat capacity of the [`Material`] (see [`Material::set_heat_capacity`]).
+    pub fn heat_capacity(mut self, property: impl Into<VarQuantity<SpecificHeatCapacity>>) -> Self {
+        self.0.heat_capacity = property.into();
+        return self;
+    }
+
+    /// Sets the thermal conductivity of the [`Material`] (see [`Material::set_thermal_conductivity`]).
+    pub fn thermal_conductivity(
+        mut self,
+        property: impl Into<VarQuantity<ThermalConductivity>>,
+    ) -> Self {
+        self.0.thermal_conductivity = property.into();
+        return self;
+    }
+
+    /// Sets the Curie temperature.
+    pub fn curie_temperature(mut self, property: ThermodynamicTemperature) -> Self {
+        self.0.curie_temperature = Some(property);
+        return self;
+    }
+
+    /// Sets the stress-relief annealing temperature.
+    pub fn annealing_temperature(mut self, property: ThermodynamicTemperature) -> Self {
+        self.0.annealing_temperature = Some(property);
+        return self;
+    }
+
+    /// Returns the [`Material`] built from the previously set fields.
+    pub fn build(self) -> Material {
+        return self.0;
+    }
+}
+
 #[cfg(feature = "serde")]
 #[typetag::serde]
 impl DatabaseEntry for Material {
@@ -332,3 +2330,21 @@ fn default_heat_capacity() -> VarQuantity<SpecificHeatCapacity> {
 fn default_thermal_conductivity() -> VarQuantity<ThermalConductivity> {
     return VarQuantity::Constant(ThermalConductivity::new::<watt_per_meter_kelvin>(0.0));
 }
+
+/**
+Deserializes an optional temperature, which can be given either as a raw value
+in kelvin or as a string with units (see [`deserialize_quantity`]).
+ */
+#[cfg(feature = "serde")]
+fn deserialize_optional_temperature<'de, D>(
+    deserializer: D,
+) -> Result<Option<ThermodynamicTemperature>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_quantity")] ThermodynamicTemperature);
+
+    let wrapper: Option<Wrapper> = Option::deserialize(deserializer)?;
+    return Ok(wrapper.map(|wrapper| wrapper.0));
+}
@@ -5,7 +5,12 @@ is that one can include use `stem_material::prelude::*` to work efficiently with
 this crate.
  */
 
+#[cfg(feature = "csv")]
+pub use crate::csv::*;
+#[cfg(feature = "database")]
+pub use crate::database::*;
 pub use crate::iron_losses::*;
+pub use crate::lookup_table::*;
 pub use crate::material::*;
 pub use crate::relative_permeability::*;
 pub use crate::si::*;
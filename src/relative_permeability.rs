@@ -65,8 +65,6 @@ doc = ::embed_doc_image::embed_image!("relative_permeability", "docs/img/relativ
     doc = "**Doc images not enabled**. Compile docs with `cargo doc --features 'doc-images'` and Rust version >= 1.54."
 )]
 
-use std::f64::INFINITY;
-
 use akima_spline::AkimaSpline;
 
 use var_quantity::uom::si::f64::*;
@@ -76,13 +74,27 @@ use var_quantity::{DynQuantity, PredefUnit, Unit};
 use var_quantity::{IsQuantityFunction, QuantityFunction};
 
 #[cfg(feature = "serde")]
-use var_quantity::deserialize_vec_of_quantities;
+use var_quantity::{deserialize_quantity, deserialize_vec_of_quantities};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::material::{VACUUM_PERMEABILITY, VACUUM_PERMEABILITY_UNITLESS};
 
+/**
+Flux density in tesla at which the extrapolated `µr(B)` spline reaches a
+relative permeability of 1 (see the
+[module-level documentation](crate::relative_permeability)).
+ */
+const SATURATION_FLUX_DENSITY_UNITLESS: f64 = 100.0;
+
+/**
+Relative tolerance used when comparing the support points and extrapolation
+coefficients of two splines in the [`PartialEq`] implementation of
+[`FerromagneticPermeability`].
+ */
+const SPLINE_EQ_TOLERANCE: f64 = 1e-12;
+
 /**
 A specialized variant of [`VarQuantity<f64>`](var_quantity::VarQuantity) for
 relative permeability.
@@ -110,10 +122,18 @@ pub enum RelativePermeability {
      */
     FerromagneticPermeability(FerromagneticPermeability),
     /**
+    Like [`RelativePermeability::FerromagneticPermeability`], but using the
+    natural cubic splines of [`CubicSplinePermeability`] instead of Akima
+    splines.
+     */
+    CubicSplinePermeability(CubicSplinePermeability),
+    /**
     Catch-all variant for any non-constant behaviour. Arbitrary behaviour
     can be realized with the contained [`IsQuantityFunction`] trait object, as
     long as the unit constraint outlined in the
-    [`VarQuantity`](var_quantity::VarQuantity) docstring is upheld.
+    [`VarQuantity`](var_quantity::VarQuantity) docstring is upheld. Via the
+    [`PermeabilityModel`] implementation of [`QuantityFunction<f64>`], the
+    contained function is also usable as a [`PermeabilityModel`].
      */
     Function(QuantityFunction<f64>),
 }
@@ -127,6 +147,7 @@ impl serde::Serialize for RelativePermeability {
         #[derive(Serialize)]
         enum FerromagneticPermeabilityEnum<'a> {
             FerromagneticPermeability(&'a FerromagneticPermeability),
+            CubicSplinePermeability(&'a CubicSplinePermeability),
         }
 
         #[derive(Serialize)]
@@ -144,6 +165,11 @@ impl serde::Serialize for RelativePermeability {
                     FerromagneticPermeabilityEnum::FerromagneticPermeability(fp),
                 )
             }
+            RelativePermeability::CubicSplinePermeability(cp) => {
+                RelativePermeabilitySerde::FerromagneticPermeabilityEnum(
+                    FerromagneticPermeabilityEnum::CubicSplinePermeability(cp),
+                )
+            }
             RelativePermeability::Function(quantity_function) => {
                 RelativePermeabilitySerde::Function(quantity_function)
             }
@@ -163,11 +189,12 @@ impl<'de> serde::Deserialize<'de> for RelativePermeability {
         /**
         This is a "fake" enum which just exists so the tag
         "FerromagneticPermeability" is deserialized correctly into [`RelativePermeability::FerromagneticPermeability`] instead of
-        [`RelativePermeability::Function`].
+        [`RelativePermeability::Function`] (likewise for "CubicSplinePermeability").
          */
         #[derive(Deserialize)]
         enum FerromagneticPermeabilityEnum {
             FerromagneticPermeability(FerromagneticPermeability),
+            CubicSplinePermeability(CubicSplinePermeability),
         }
 
         #[derive(deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError)]
@@ -184,6 +211,9 @@ impl<'de> serde::Deserialize<'de> for RelativePermeability {
                 FerromagneticPermeabilityEnum::FerromagneticPermeability(jordan_model) => {
                     RelativePermeability::FerromagneticPermeability(jordan_model)
                 }
+                FerromagneticPermeabilityEnum::CubicSplinePermeability(model) => {
+                    RelativePermeability::CubicSplinePermeability(model)
+                }
             },
             RelativePermeabilitySerde::Function(quantity_function) => {
                 RelativePermeability::Function(quantity_function)
@@ -198,14 +228,77 @@ impl RelativePermeability {
     Matches against `self` and calculates the iron losses (or just return the
     value in case of the [`RelativePermeability::Constant`]) variant).
     */
+    #[inline]
     pub fn get(&self, conditions: &[DynQuantity<f64>]) -> f64 {
         match self {
-            Self::Constant(val) => val.clone(),
+            Self::Constant(val) => *val,
             Self::FerromagneticPermeability(model) => model.call(conditions).try_into().expect("implementation of FerromagneticPermeability makes sure the returned value is always a f64"),
+            Self::CubicSplinePermeability(model) => model.call(conditions).try_into().expect("implementation of CubicSplinePermeability makes sure the returned value is always a f64"),
             Self::Function(fun) => fun.call(conditions),
         }
     }
 
+    /**
+    Returns the absolute permeability `µ = µ0 * µr`, with `µr` being the
+    relative permeability returned by [`RelativePermeability::get`] for the
+    given `conditions`.
+     */
+    pub fn get_absolute(&self, conditions: &[DynQuantity<f64>]) -> MagneticPermeability {
+        return *VACUUM_PERMEABILITY * self.get(conditions);
+    }
+
+    /**
+    Returns the relative permeability for the given `flux_density`.
+
+    This is a shorthand for [`PermeabilityModel::mu_r_from_b`] which does not
+    require the trait to be in scope. Contrary to [`RelativePermeability::get`],
+    no [`DynQuantity`] slice needs to be constructed.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = RelativePermeability::Constant(500.0);
+    assert_eq!(permeability.get_at_b(MagneticFluxDensity::new::<tesla>(1.0)), 500.0);
+    ```
+     */
+    #[inline]
+    pub fn get_at_b(&self, flux_density: MagneticFluxDensity) -> f64 {
+        return self.mu_r_from_b(flux_density);
+    }
+
+    /**
+    Returns the relative permeability for the given `field_strength`. See
+    [`RelativePermeability::get_at_b`].
+     */
+    #[inline]
+    pub fn get_at_h(&self, field_strength: MagneticFieldStrength) -> f64 {
+        return self.mu_r_from_h(field_strength);
+    }
+
+    /**
+    Returns a reference to the contained [`FerromagneticPermeability`] if
+    `self` is a [`RelativePermeability::FerromagneticPermeability`].
+     */
+    pub fn try_as_ferromagnetic(&self) -> Option<&FerromagneticPermeability> {
+        match self {
+            Self::FerromagneticPermeability(model) => return Some(model),
+            _ => return None,
+        }
+    }
+
+    /**
+    Returns a mutable reference to the contained [`FerromagneticPermeability`]
+    if `self` is a [`RelativePermeability::FerromagneticPermeability`].
+     */
+    pub fn try_as_ferromagnetic_mut(&mut self) -> Option<&mut FerromagneticPermeability> {
+        match self {
+            Self::FerromagneticPermeability(model) => return Some(model),
+            _ => return None,
+        }
+    }
+
     /**
     Returns a reference to the underlying function if `self` is a
     [`RelativePermeability::Function`].
@@ -218,6 +311,17 @@ impl RelativePermeability {
     }
 }
 
+impl std::fmt::Display for RelativePermeability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constant(val) => write!(f, "constant: {val}"),
+            Self::FerromagneticPermeability(model) => model.fmt(f),
+            Self::CubicSplinePermeability(model) => model.fmt(f),
+            Self::Function(_) => write!(f, "function"),
+        }
+    }
+}
+
 impl TryFrom<Box<dyn IsQuantityFunction>> for RelativePermeability {
     type Error = var_quantity::UnitsNotEqual;
 
@@ -257,8 +361,13 @@ For both [`MagnetizationCurve`] and [`PolarizationCurve`], there are dedicated
 constructors
 [`from_magnetization`](FerromagneticPermeability::from_magnetization) and
 [`from_polarization`](FerromagneticPermeability::from_polarization) which
-(fallibly) create the splines out of raw data. Additionally, it is also possible
-to build the struct directly from its fields using manually created splines.
+(fallibly) create the splines out of raw data. These constructors also store the
+raw [`MagnetizationCurve`], which allows recomputing the splines e.g. for a
+different iron fill factor via
+[`change_iron_fill_factor`](FerromagneticPermeability::change_iron_fill_factor).
+Additionally, it is also possible to build the struct directly from manually
+created splines via
+[`from_splines`](FerromagneticPermeability::from_splines).
 
 # Usage in `Material`
 
@@ -273,9 +382,14 @@ relative permeability is returned. Otherwise, the relative permeability at 0 T /
 
 # Serialization and deserialization
 
-A [`FerromagneticPermeability`] has no hidden fields and is therefore serialized
-as a struct of two [`AkimaSpline`]s. It can be deserialized from the serialized
-representation of the following structs:
+A [`FerromagneticPermeability`] is serialized as a struct of two
+[`AkimaSpline`]s and, if available, the raw [`MagnetizationCurve`] it was
+created from (field `original_curve`) together with the [`SampleStrategy`]
+used for resampling it (field `sample_strategy`, omitted for the default
+strategy). To keep the serialized output small, the raw curve can be dropped
+by rebuilding the struct via
+[`from_splines`](FerromagneticPermeability::from_splines). It can be
+deserialized from the serialized representation of the following structs:
 
 1) Its own "native" representation
 2) A [`MagnetizationCurve`]
@@ -287,7 +401,7 @@ directly and then the constructors
 [`from_polarization`](FerromagneticPermeability::from_polarization) are used to
 create a [`FerromagneticPermeability`] instance.
  */
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -298,6 +412,53 @@ pub struct FerromagneticPermeability {
     pub from_field_strength: AkimaSpline,
     /// Spline representing the function `f(B) = µr`.
     pub from_flux_density: AkimaSpline,
+    /// Raw datapoints the splines have been created from (if available).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    original_curve: Option<MagnetizationCurve>,
+    /// Strategy used to resample the original curve.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "SampleStrategy::is_default")
+    )]
+    sample_strategy: SampleStrategy,
+}
+
+/**
+Two [`FerromagneticPermeability`]s are equal if the support points and
+extrapolation coefficients of their splines are equal within a relative
+tolerance of 1e-12. This makes the comparison robust against rounding errors
+e.g. from a serialization roundtrip. The
+[`original_curve`](FerromagneticPermeability::original_curve) and the
+[`SampleStrategy`] are not compared, since they do not influence the relative
+permeability.
+ */
+impl PartialEq for FerromagneticPermeability {
+    fn eq(&self, other: &Self) -> bool {
+        return spline_knots_eq(&self.from_field_strength, &other.from_field_strength)
+            && spline_knots_eq(&self.from_flux_density, &other.from_flux_density);
+    }
+}
+
+/**
+Compares the support points and extrapolation coefficients of `first` and
+`second` within [`SPLINE_EQ_TOLERANCE`].
+ */
+fn spline_knots_eq(first: &AkimaSpline, second: &AkimaSpline) -> bool {
+    fn values_approx_eq(first: &[f64], second: &[f64]) -> bool {
+        return first.len() == second.len()
+            && first.iter().zip(second.iter()).all(|(a, b)| {
+                a == b || (a - b).abs() <= SPLINE_EQ_TOLERANCE * a.abs().max(b.abs())
+            });
+    }
+    let extrap_approx_eq = |first: Option<&[f64]>, second: Option<&[f64]>| match (first, second) {
+        (Some(first), Some(second)) => values_approx_eq(first, second),
+        (None, None) => true,
+        _ => false,
+    };
+    return values_approx_eq(first.xs(), second.xs())
+        && values_approx_eq(first.ys(), second.ys())
+        && extrap_approx_eq(first.extrapl(), second.extrapl())
+        && extrap_approx_eq(first.extrapr(), second.extrapr());
 }
 
 impl FerromagneticPermeability {
@@ -308,112 +469,588 @@ impl FerromagneticPermeability {
     error enum.
      */
     pub fn from_magnetization(raw_curve: MagnetizationCurve) -> Result<Self, InvalidInputData> {
-        let (field_strength, flux_density) = sample_bh_curve(
-            raw_curve.field_strength.as_slice(),
-            raw_curve.flux_density.as_slice(),
-            0.02,
-        )?;
+        return Self::from_magnetization_with_strategy(raw_curve, SampleStrategy::default());
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] from a [`MagnetizationCurve`]
+    which is resampled according to the given [`SampleStrategy`].
+
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization) uses
+    [`SampleStrategy::default`]. A smaller step width or change tolerance
+    results in more support points and therefore in a more accurate, but also
+    slower permeability characteristic. The step width must be positive and
+    finite and at most 1 000 000 steps may be needed to reach the largest field
+    strength of the curve, the change tolerance must be non-negative and finite.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        [0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+            .into_iter()
+            .map(MagneticFieldStrength::new::<ampere_per_meter>)
+            .collect(),
+        [0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+            .into_iter()
+            .map(MagneticFluxDensity::new::<tesla>)
+            .collect(),
+        1.0,
+    )
+    .unwrap();
+    let strategy = SampleStrategy {
+        step_width: MagneticFieldStrength::new::<ampere_per_meter>(5.0),
+        change_tolerance: 0.01,
+    };
+    let permeability =
+        FerromagneticPermeability::from_magnetization_with_strategy(curve.clone(), strategy)
+            .unwrap();
+    assert!(permeability.original_curve().is_some());
+
+    // Invalid strategies are rejected
+    for (step_width, change_tolerance) in [(0.0, 0.01), (5.0, -0.01), (5.0, f64::NAN), (1e-3, 0.01)] {
+        let strategy = SampleStrategy {
+            step_width: MagneticFieldStrength::new::<ampere_per_meter>(step_width),
+            change_tolerance,
+        };
+        assert!(
+            FerromagneticPermeability::from_magnetization_with_strategy(curve.clone(), strategy)
+                .is_err()
+        );
+    }
+    ```
+     */
+    pub fn from_magnetization_with_strategy(
+        raw_curve: MagnetizationCurve,
+        strategy: SampleStrategy,
+    ) -> Result<Self, InvalidInputData> {
+        let samples = magnetization_samples(&raw_curve, &strategy)?;
+        let mut permeability = Self::from_permeability_samples(samples)?;
+        permeability.original_curve = Some(raw_curve);
+        permeability.sample_strategy = strategy;
+        return Ok(permeability);
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] directly from field strength
+    values in A/m and flux density values in T without any preprocessing.
+
+    Contrary to
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization), the
+    datapoints are neither resampled nor checked, values left of the
+    permeability maximum are not discarded and the relative permeability is not
+    modified to be strictly decreasing. Only the adjustment for the
+    `iron_fill_factor` and the extrapolation described in the
+    [module-level documentation](crate::relative_permeability) are applied.
+    Datapoints with a field strength of zero are skipped.
+
+    **The caller must ensure that the data is valid**: Both vectors need to
+    have the same length, the field strength and the flux density need to be
+    strictly increasing, the resulting relative permeability should be strictly
+    decreasing and the data should be finely sampled. Otherwise, the resulting
+    splines might oscillate or become inconsistent with each other. This
+    function is meant for performance-critical paths where the data is already
+    known to be well-formed.
+
+    # Examples
 
-        // ==========================================================================
-        // Start of curve creation
+    ```
+    use stem_material::prelude::*;
 
-        // Calculate relative permeability
+    let permeability = FerromagneticPermeability::from_magnetization_unchecked(
+        vec![50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0],
+        vec![0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8],
+        1.0,
+    )
+    .unwrap();
+    assert!(permeability.original_curve().is_none());
+    ```
+     */
+    pub fn from_magnetization_unchecked(
+        field_strength: Vec<f64>,
+        flux_density: Vec<f64>,
+        iron_fill_factor: f64,
+    ) -> Result<Self, akima_spline::BuildError> {
+        let mut field_strength_spline: Vec<f64> = Vec::with_capacity(field_strength.len());
         let mut induction: Vec<f64> = Vec::with_capacity(field_strength.len());
         let mut permeability: Vec<f64> = Vec::with_capacity(field_strength.len());
-        let mut field_strength_spline: Vec<f64> = Vec::with_capacity(field_strength.len());
-
-        for (hi, bi) in field_strength
-            .iter()
-            .map(|value| value.get::<ampere_per_meter>().clone())
-            .zip(
-                flux_density
-                    .iter()
-                    .map(|value| value.get::<tesla>().clone()),
-            )
-        {
+        for (hi, bi) in field_strength.into_iter().zip(flux_density) {
             if hi != 0.0 {
-                // Adjust for the iron fill factor
-                let b_red = bi * raw_curve.iron_fill_factor
-                    + (1.0 - raw_curve.iron_fill_factor) * hi * VACUUM_PERMEABILITY_UNITLESS;
-
-                // Calculate with the reduced flux density
-                let mu_r = b_red / (hi * VACUUM_PERMEABILITY_UNITLESS);
+                let b_red = bi * iron_fill_factor
+                    + (1.0 - iron_fill_factor) * hi * VACUUM_PERMEABILITY_UNITLESS;
                 field_strength_spline.push(hi);
                 induction.push(b_red);
-                permeability.push(mu_r);
+                permeability.push(b_red / (hi * VACUUM_PERMEABILITY_UNITLESS));
             }
         }
 
-        let mut idx_max = None;
-        let mut min_value = std::f64::NEG_INFINITY;
-        for (idx, value) in permeability.iter().enumerate() {
-            if *value > min_value {
-                min_value = *value;
-                idx_max = Some(idx);
-            }
+        // If no datapoints are given, building the splines fails anyway
+        let (slope_field_strength, slope_flux_density) =
+            match (induction.last(), permeability.last()) {
+                (Some(induction), Some(permeability)) => {
+                    saturation_slopes(*induction, *permeability)
+                }
+                _ => (0.0, 0.0),
+            };
+
+        let from_field_strength = AkimaSpline::new(
+            field_strength_spline,
+            permeability.clone(),
+            Some(vec![0.0]),
+            Some(vec![slope_field_strength]),
+        )?;
+        let from_flux_density = AkimaSpline::new(
+            induction,
+            permeability,
+            Some(vec![0.0]),
+            Some(vec![slope_flux_density]),
+        )?;
+        return Ok(Self::from_splines(from_field_strength, from_flux_density));
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] from raw `(H, B)` pairs, where
+    `H` is given in A/m and `B` is given in T.
+
+    This is a shorthand for creating a [`MagnetizationCurve`] from the pairs
+    and then calling
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization).
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    assert!(permeability.get(MagneticFluxDensity::new::<tesla>(1.0)) > 1.0);
+
+    // Invalid iron fill factor
+    assert!(FerromagneticPermeability::from_bh_pairs_si(&[(100.0, 0.5), (200.0, 1.0)], 1.1).is_err());
+    ```
+     */
+    pub fn from_bh_pairs_si(
+        pairs: &[(f64, f64)],
+        iron_fill_factor: f64,
+    ) -> Result<Self, InvalidInputData> {
+        let field_strength = pairs
+            .iter()
+            .map(|(h, _)| MagneticFieldStrength::new::<ampere_per_meter>(*h))
+            .collect();
+        let flux_density = pairs
+            .iter()
+            .map(|(_, b)| MagneticFluxDensity::new::<tesla>(*b))
+            .collect();
+        let raw_curve = MagnetizationCurve::new(field_strength, flux_density, iron_fill_factor)?;
+        return Self::from_magnetization(raw_curve);
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] directly from a table of flux
+    density values and the corresponding relative permeabilities, as it is
+    provided by some manufacturers instead of a `B(H)` curve.
+
+    The relative permeability and the flux density are adjusted for the
+    `iron_fill_factor` in the same way as in
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization). The
+    field strength values needed for the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline are then derived via `H = B / (µ0 * µr)`. Contrary to
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization), the
+    table values are used as spline support points directly without resampling.
+
+    This process can fail for the reasons described in the [`InvalidInputData`]
+    error enum. In particular, the iron fill factor needs to be larger than 0
+    and at most 1, the flux density values need to be strictly increasing, all
+    relative permeabilities need to be positive and the derived field strength
+    values need to be strictly increasing as well (otherwise, an
+    [`InvalidInputData::AkimaBuildError`] is returned).
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let flux_density = vec![
+        MagneticFluxDensity::new::<tesla>(0.5),
+        MagneticFluxDensity::new::<tesla>(1.0),
+        MagneticFluxDensity::new::<tesla>(1.5),
+        MagneticFluxDensity::new::<tesla>(1.8),
+        MagneticFluxDensity::new::<tesla>(2.0),
+    ];
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    approx::assert_abs_diff_eq!(
+        permeability.get(MagneticFluxDensity::new::<tesla>(1.5)),
+        1500.0,
+        epsilon = 1e-6
+    );
+
+    // Relative permeabilities must be positive
+    assert!(FerromagneticPermeability::from_mu_r_table(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 0.0],
+        1.0,
+    ).is_err());
+
+    // The iron fill factor must be larger than 0
+    assert!(FerromagneticPermeability::from_mu_r_table(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        0.0,
+    ).is_err());
+
+    // The table must not be empty
+    assert!(FerromagneticPermeability::from_mu_r_table(vec![], vec![], 1.0).is_err());
+
+    // The field strength H = B / (µ0 * µr) must be strictly increasing
+    assert!(FerromagneticPermeability::from_mu_r_table(
+        flux_density,
+        vec![5000.0, 1000.0, 2000.0, 500.0, 100.0],
+        1.0,
+    ).is_err());
+    ```
+     */
+    pub fn from_mu_r_table(
+        flux_density: Vec<MagneticFluxDensity>,
+        mu_r: Vec<f64>,
+        iron_fill_factor: f64,
+    ) -> Result<Self, InvalidInputData> {
+        if iron_fill_factor.is_nan() || iron_fill_factor <= 0.0 || iron_fill_factor > 1.0 {
+            return Err(InvalidInputData::IronFillFactor(iron_fill_factor));
         }
-        let idx_max = idx_max.expect("Guaranteed to have at least one value by the constructor");
+        if flux_density.len() != mu_r.len() {
+            return Err(InvalidInputData::IneqNumElementsPermeability {
+                flux_density: flux_density.len(),
+                permeability: mu_r.len(),
+            });
+        }
+        if flux_density.is_empty() {
+            return Err(InvalidInputData::AkimaBuildError(
+                akima_spline::BuildError::MinFivePointsNeeded,
+            ));
+        }
+        if let Some(value) = mu_r.iter().find(|value| value.is_nan() || **value <= 0.0) {
+            return Err(InvalidInputData::NonPositivePermeability(*value));
+        }
+        check_strictly_increasing(&flux_density, |index| {
+            InvalidInputData::NonMonotonicFluxDensity { index }
+        })?;
 
-        // Remove all values "left" of idx_max
-        let field_strength_right_of_maximum = &field_strength_spline[idx_max..];
-        let induction_right_of_maximum = &induction[idx_max..];
-        let permeability_right_of_maximum = &permeability[idx_max..];
-        let field_strength = field_strength_right_of_maximum.to_vec();
-        let induction = induction_right_of_maximum.to_vec();
-        let mut permeability = permeability_right_of_maximum.to_vec();
+        let mut field_strength: Vec<f64> = Vec::with_capacity(flux_density.len());
+        let mut induction: Vec<f64> = Vec::with_capacity(flux_density.len());
+        let mut permeability: Vec<f64> = Vec::with_capacity(flux_density.len());
 
-        // Modify mu_r(B) to ensure strictly decreasing behaviour.
-        if permeability.len() > 2 {
-            for idx in (0..(permeability.len() - 2)).rev() {
-                if permeability[idx] < permeability[idx + 1] {
-                    let m = (permeability[idx + 1] - permeability[idx + 2])
-                        / (induction[idx + 1] - induction[idx + 2]);
+        for (bi, mu_r) in flux_density
+            .iter()
+            .map(|value| value.get::<tesla>())
+            .zip(mu_r)
+        {
+            let hi = bi / (VACUUM_PERMEABILITY_UNITLESS * mu_r);
 
-                    // Calculate the new y-value with the gradient
-                    permeability[idx] =
-                        permeability[idx + 1] + m * (induction[idx + 1] - induction[idx + 2]);
-                }
-            }
+            // Adjust for the iron fill factor
+            let b_red = bi * iron_fill_factor
+                + (1.0 - iron_fill_factor) * hi * VACUUM_PERMEABILITY_UNITLESS;
+            let mu_r_red = mu_r * iron_fill_factor + (1.0 - iron_fill_factor);
+
+            field_strength.push(hi);
+            induction.push(b_red);
+            permeability.push(mu_r_red);
         }
 
-        // Extrapolation function for induction values larger than induction[end].
-        let induction_1 = *induction
-            .last()
-            .expect("Guaranteed to have at least one value by the constructor");
-        let induction_2 = 100.0;
-        let permeability_1 = *permeability
-            .last()
-            .expect("Guaranteed to have at least one value by the constructor");
-        let permeability_2 = 1.0;
-        let field_strength_1 = induction_1 / (VACUUM_PERMEABILITY_UNITLESS * permeability_1);
-        let field_strength_2 = induction_2 / (VACUUM_PERMEABILITY_UNITLESS * permeability_2);
+        return Self::from_permeability_samples(PermeabilitySamples::new(
+            field_strength,
+            induction,
+            permeability,
+        ));
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] and a [`LossTangentCurve`] from a
+    complex permeability `µ* = µ' - j * µ''` as it is reported by e.g.
+    impedance analyzers.
+
+    The real part `µ'` is the lossless part of the relative permeability and
+    is used to create the [`FerromagneticPermeability`] via
+    [`from_mu_r_table`](FerromagneticPermeability::from_mu_r_table) (including
+    the adjustment for the `iron_fill_factor`). The imaginary part `µ''`
+    corresponds to the losses and is returned as the loss tangent
+    `tan(δ) = µ'' / µ'` over the flux density, which can be used to model the
+    iron losses separately. Both curves use the same flux density axis, i.e.
+    the flux density adjusted for the `iron_fill_factor`.
 
-        // Create the mu_r(field_strength)-curce
-        let mr = (permeability_2 - permeability_1) / (field_strength_2 - field_strength_1);
+    This process can fail for the reasons described in the [`InvalidInputData`]
+    error enum. In particular, all three vectors need to have the same length
+    and the real part is subject to the same checks as in
+    [`from_mu_r_table`](FerromagneticPermeability::from_mu_r_table).
 
-        // Extrapolate with a horizontal line from the permeability maximum to the left
-        let ml = 0.0;
+    # Examples
 
-        let extrapl = Some(vec![ml]);
-        let extrapr = Some(vec![mr]);
-        let from_field_strength =
-            AkimaSpline::new(field_strength, permeability.clone(), extrapl, extrapr)
-                .expect("values are guaranteed to be in ascending order");
+    ```
+    use stem_material::prelude::*;
+
+    let flux_density = vec![
+        MagneticFluxDensity::new::<tesla>(0.5),
+        MagneticFluxDensity::new::<tesla>(1.0),
+        MagneticFluxDensity::new::<tesla>(1.5),
+        MagneticFluxDensity::new::<tesla>(1.8),
+        MagneticFluxDensity::new::<tesla>(2.0),
+    ];
+    let (permeability, loss_tangent) = FerromagneticPermeability::from_complex_permeability(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        vec![50.0, 80.0, 60.0, 25.0, 10.0],
+        1.0,
+    )
+    .unwrap();
+    approx::assert_abs_diff_eq!(
+        permeability.get(MagneticFluxDensity::new::<tesla>(1.0)),
+        4000.0,
+        epsilon = 1e-6
+    );
+    approx::assert_abs_diff_eq!(
+        loss_tangent.get(MagneticFluxDensity::new::<tesla>(1.0)),
+        0.02,
+        epsilon = 1e-9
+    );
+
+    // With an iron fill factor below 1, the datapoint at 1 T is moved to the
+    // adjusted flux density `B * (f + (1 - f) / µ')` in both curves
+    let (permeability, loss_tangent) = FerromagneticPermeability::from_complex_permeability(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        vec![50.0, 80.0, 60.0, 25.0, 10.0],
+        0.95,
+    )
+    .unwrap();
+    let b = MagneticFluxDensity::new::<tesla>(0.95 + 0.05 / 4000.0);
+    approx::assert_abs_diff_eq!(permeability.get(b), 0.95 * 4000.0 + 0.05, epsilon = 1e-6);
+    approx::assert_abs_diff_eq!(loss_tangent.get(b), 0.02, epsilon = 1e-9);
+
+    // The field strength derived from the real part must be strictly increasing
+    assert!(FerromagneticPermeability::from_complex_permeability(
+        flux_density.clone(),
+        vec![5000.0, 1000.0, 2000.0, 500.0, 100.0],
+        vec![50.0, 80.0, 60.0, 25.0, 10.0],
+        1.0,
+    ).is_err());
+
+    // Empty tables and an iron fill factor of zero are rejected as well
+    assert!(FerromagneticPermeability::from_complex_permeability(
+        vec![], vec![], vec![], 1.0
+    ).is_err());
+    assert!(FerromagneticPermeability::from_complex_permeability(
+        flux_density,
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        vec![50.0, 80.0, 60.0, 25.0, 10.0],
+        0.0,
+    ).is_err());
+    ```
+     */
+    pub fn from_complex_permeability(
+        flux_density: Vec<MagneticFluxDensity>,
+        real_part: Vec<f64>,
+        imaginary_part: Vec<f64>,
+        iron_fill_factor: f64,
+    ) -> Result<(FerromagneticPermeability, LossTangentCurve), InvalidInputData> {
+        if flux_density.len() != imaginary_part.len() {
+            return Err(InvalidInputData::IneqNumElementsPermeability {
+                flux_density: flux_density.len(),
+                permeability: imaginary_part.len(),
+            });
+        }
+        let tan_delta: Vec<f64> = real_part
+            .iter()
+            .zip(imaginary_part.iter())
+            .map(|(real, imag)| imag / real)
+            .collect();
 
-        // Create the mu_r(flux_density)-curce
-        let mr = (permeability_2 - permeability_1) / (induction_2 - induction_1);
+        // Same iron fill factor adjustment as in `from_mu_r_table`, so both
+        // splines share the flux density axis
+        let induction: Vec<f64> = flux_density
+            .iter()
+            .zip(real_part.iter())
+            .map(|(b, mu_r)| {
+                let b = b.get::<tesla>();
+                let h = b / (VACUUM_PERMEABILITY_UNITLESS * mu_r);
+                return b * iron_fill_factor
+                    + (1.0 - iron_fill_factor) * h * VACUUM_PERMEABILITY_UNITLESS;
+            })
+            .collect();
+
+        // Validates the flux density and the real part before the loss tangent
+        // spline is built
+        let permeability = Self::from_mu_r_table(flux_density, real_part, iron_fill_factor)?;
+
+        // Extrapolate with horizontal lines on both sides
+        let from_flux_density =
+            AkimaSpline::new(induction, tan_delta, Some(vec![0.0]), Some(vec![0.0]))?;
+        return Ok((permeability, LossTangentCurve { from_flux_density }));
+    }
 
+    /**
+    Creates the splines from already calculated (and iron fill factor-adjusted)
+    support points. All values left of the permeability maximum are discarded
+    and the remaining permeability values are modified to be strictly decreasing
+    as described in the [module-level documentation](crate::relative_permeability).
+     */
+    fn from_permeability_samples(samples: PermeabilitySamples) -> Result<Self, InvalidInputData> {
         // Extrapolate with a horizontal line from the permeability maximum to the left
-        let ml = 0.0;
+        let extrapl = Some(vec![0.0]);
+        let extrapr = Some(vec![samples.slope_field_strength]);
+        let from_field_strength = AkimaSpline::new(
+            samples.field_strength,
+            samples.permeability.clone(),
+            extrapl,
+            extrapr,
+        )?;
 
-        let extrapl = Some(vec![ml]);
-        let extrapr = Some(vec![mr]);
-        let from_flux_density = AkimaSpline::new(induction, permeability, extrapl, extrapr)?;
+        let extrapl = Some(vec![0.0]);
+        let extrapr = Some(vec![samples.slope_flux_density]);
+        let from_flux_density =
+            AkimaSpline::new(samples.induction, samples.permeability, extrapl, extrapr)?;
 
-        return Ok(Self {
+        return Ok(Self::from_splines(from_field_strength, from_flux_density));
+    }
+
+    /**
+    Constructs a [`FerromagneticPermeability`] directly from the two splines
+    `µr(H)` and `µr(B)`. The splines are used as-is, hence the user is
+    responsible for making sure that they are consistent with each other.
+    Since no raw data is available, the returned instance does not have an
+    [`original_curve`](FerromagneticPermeability::original_curve).
+     */
+    pub fn from_splines(from_field_strength: AkimaSpline, from_flux_density: AkimaSpline) -> Self {
+        return Self {
             from_field_strength,
             from_flux_density,
-        });
+            original_curve: None,
+            sample_strategy: SampleStrategy::default(),
+        };
+    }
+
+    /**
+    Returns the raw [`MagnetizationCurve`] `self` was created from. This is
+    `None` if `self` was created via
+    [`from_splines`](FerromagneticPermeability::from_splines) or
+    [`from_mu_r_table`](FerromagneticPermeability::from_mu_r_table).
+     */
+    pub fn original_curve(&self) -> Option<&MagnetizationCurve> {
+        return self.original_curve.as_ref();
+    }
+
+    /**
+    Returns the number of support points (knots) of the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline.
+    This can be used to judge whether the raw curve was over- or undersampled.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.5),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.8),
+            MagneticFluxDensity::new::<tesla>(2.0),
+        ],
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+
+    // The table values are used as support points directly
+    assert_eq!(permeability.spline_knot_count_b(), 5);
+    assert_eq!(permeability.spline_knot_count_h(), 5);
+    ```
+     */
+    pub fn spline_knot_count_b(&self) -> usize {
+        return self.from_flux_density.xs().len();
+    }
+
+    /**
+    Returns the number of support points (knots) of the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline. This can be used to judge whether the raw curve was over- or
+    undersampled.
+     */
+    pub fn spline_knot_count_h(&self) -> usize {
+        return self.from_field_strength.xs().len();
+    }
+
+    /**
+    Recomputes the splines from the [original
+    curve](FerromagneticPermeability::original_curve) using the
+    `new_fill_factor` instead of the iron fill factor of the original curve.
+    The curve is resampled with the same [`SampleStrategy`] that was used to
+    create `self`.
+
+    This is useful if e.g. the sheet thickness or the coating of a lamination
+    stack changes while the material itself stays the same. Returns an
+    [`InvalidInputData::MissingOriginalCurve`] if `self` has no original curve
+    and an [`InvalidInputData::IronFillFactor`] if `new_fill_factor` is not
+    between 0 and 1.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        [0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+            .into_iter()
+            .map(MagneticFieldStrength::new::<ampere_per_meter>)
+            .collect(),
+        [0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+            .into_iter()
+            .map(MagneticFluxDensity::new::<tesla>)
+            .collect(),
+        1.0,
+    )
+    .unwrap();
+    let massive = FerromagneticPermeability::from_magnetization(curve).unwrap();
+    let laminated = massive.change_iron_fill_factor(0.95).unwrap();
+
+    let b = MagneticFluxDensity::new::<tesla>(1.0);
+    assert!(laminated.get(b) < massive.get(b));
+    assert_eq!(laminated.original_curve().unwrap().iron_fill_factor(), 0.95);
+    ```
+     */
+    pub fn change_iron_fill_factor(
+        &self,
+        new_fill_factor: f64,
+    ) -> Result<FerromagneticPermeability, InvalidInputData> {
+        let original_curve = self
+            .original_curve
+            .as_ref()
+            .ok_or(InvalidInputData::MissingOriginalCurve)?;
+        let curve = MagnetizationCurve::new(
+            original_curve.field_strength.clone(),
+            original_curve.flux_density.clone(),
+            new_fill_factor,
+        )?;
+        return Self::from_magnetization_with_strategy(curve, self.sample_strategy);
     }
 
     /**
@@ -433,25 +1070,882 @@ impl FerromagneticPermeability {
     pub fn get<T: FieldStrengthOrFluxDensity>(&self, value: T) -> f64 {
         return value.permeability(&self);
     }
-}
 
-#[cfg_attr(feature = "serde", typetag::serde)]
-impl IsQuantityFunction for FerromagneticPermeability {
-    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
-        for f in conditions {
-            if f.unit == Unit::from(PredefUnit::MagneticFieldStrength) {
-                return self
-                    .from_field_strength
-                    .eval_infallible(f.value.abs())
-                    .clamp(1.0, INFINITY)
-                    .into();
-            } else if f.unit == Unit::from(PredefUnit::MagneticFluxDensity) {
-                return self
-                    .from_flux_density
-                    .eval_infallible(f.value.abs())
-                    .clamp(1.0, INFINITY)
-                    .into();
-            }
+    /**
+    Parses `s` as a [`DynQuantity`] (e.g. `"0.5 T"` or `"100 A/m"`) and returns
+    the relative permeability for it. Depending on the unit of the parsed
+    quantity, it is interpreted either as a magnetic flux density or as a
+    magnetic field strength.
+
+    This method fails if `s` cannot be parsed or if its unit is neither that of
+    a magnetic flux density nor that of a magnetic field strength, see
+    [`PermeabilityParseError`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.5),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.8),
+            MagneticFluxDensity::new::<tesla>(2.0),
+        ],
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    assert_eq!(
+        permeability.get_from_str("1.0 T").unwrap(),
+        permeability.get(MagneticFluxDensity::new::<tesla>(1.0))
+    );
+    assert!(permeability.get_from_str("1.0 W/kg").is_err());
+    ```
+     */
+    #[cfg(feature = "serde")]
+    pub fn get_from_str(&self, s: &str) -> Result<f64, PermeabilityParseError> {
+        let quantity: DynQuantity<f64> =
+            s.parse().map_err(PermeabilityParseError::InvalidQuantity)?;
+        if let Ok(flux_density) = MagneticFluxDensity::try_from(quantity) {
+            return Ok(self.get(flux_density));
+        }
+        if let Ok(field_strength) = MagneticFieldStrength::try_from(quantity) {
+            return Ok(self.get(field_strength));
+        }
+        return Err(PermeabilityParseError::UnexpectedUnit(quantity.unit));
+    }
+
+    /**
+    Returns the absolute permeability `µ = µ0 * µr` for the given magnetic
+    field strength or flux density.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.5),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.8),
+            MagneticFluxDensity::new::<tesla>(2.0),
+        ],
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    let b = MagneticFluxDensity::new::<tesla>(1.0);
+    approx::assert_abs_diff_eq!(
+        permeability.absolute_permeability(b).get::<henry_per_meter>(),
+        4000.0 * VACUUM_PERMEABILITY_UNITLESS,
+        epsilon = 1e-12
+    );
+    ```
+     */
+    pub fn absolute_permeability<T: FieldStrengthOrFluxDensity>(
+        &self,
+        value: T,
+    ) -> MagneticPermeability {
+        return *VACUUM_PERMEABILITY * self.get(value);
+    }
+
+    /**
+    Evaluates the relative permeability for all given `flux_densities` at once.
+
+    Element `i` of the returned vector is identical to
+    [`get`](FerromagneticPermeability::get) called with `flux_densities[i]`. The
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline
+    is evaluated directly in a tight loop, hence there is neither dynamic
+    dispatch nor a scan of the conditions as in the [`IsQuantityFunction`]
+    implementation. This is useful for e.g. building look-up tables for a FEM
+    solver.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+
+    let grid: Vec<_> = (1..=20)
+        .map(|i| MagneticFluxDensity::new::<tesla>(0.1 * i as f64))
+        .collect();
+    let values = permeability.evaluate_at_grid_b(&grid);
+    assert_eq!(values.len(), grid.len());
+    assert_eq!(values[9], permeability.get(grid[9]));
+    ```
+     */
+    pub fn evaluate_at_grid_b(&self, flux_densities: &[MagneticFluxDensity]) -> Vec<f64> {
+        return flux_densities
+            .iter()
+            .map(|b| self.from_flux_density.eval_infallible(b.get::<tesla>()))
+            .collect();
+    }
+
+    /**
+    Evaluates the relative permeability for all given `field_strengths` at once.
+
+    This is the field strength counterpart to
+    [`evaluate_at_grid_b`](FerromagneticPermeability::evaluate_at_grid_b),
+    which evaluates the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline directly.
+     */
+    pub fn evaluate_at_grid_h(&self, field_strengths: &[MagneticFieldStrength]) -> Vec<f64> {
+        return field_strengths
+            .iter()
+            .map(|h| {
+                self.from_field_strength
+                    .eval_infallible(h.get::<ampere_per_meter>())
+            })
+            .collect();
+    }
+
+    /**
+    Returns the derivative `dµr/dB` of the relative permeability with respect to
+    the magnetic flux density in 1/T.
+
+    The derivative is calculated analytically from the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline.
+    Outside the range of the support points, the slope of the extrapolation
+    polynomial is returned (see the
+    [module-level documentation](crate::relative_permeability)). Like
+    [`FerromagneticPermeability::get`], this method does not go through
+    dynamic dispatch and is therefore suited for e.g. assembling the Jacobian
+    matrix within an iterative solver.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(50.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(200.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(500.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(1000.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(5000.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(10000.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(0.6),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.2),
+            MagneticFluxDensity::new::<tesla>(1.4),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.7),
+            MagneticFluxDensity::new::<tesla>(1.8),
+        ],
+        1.0,
+    )
+    .expect("valid input data");
+    let permeability = FerromagneticPermeability::from_magnetization(curve).unwrap();
+
+    // The relative permeability decreases with increasing flux density
+    assert!(permeability.dmu_r_dB(MagneticFluxDensity::new::<tesla>(1.5)) < 0.0);
+    ```
+     */
+    #[allow(non_snake_case)]
+    pub fn dmu_r_dB(&self, flux_density: MagneticFluxDensity) -> f64 {
+        return spline_derivative(&self.from_flux_density, flux_density.get::<tesla>());
+    }
+
+    /**
+    Returns the derivative `dµr/dH` of the relative permeability with respect to
+    the magnetic field strength in m/A.
+
+    The derivative is calculated from the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline. See [`FerromagneticPermeability::dmu_r_dB`] for details.
+     */
+    #[allow(non_snake_case)]
+    pub fn dmu_r_dH(&self, field_strength: MagneticFieldStrength) -> f64 {
+        return spline_derivative(
+            &self.from_field_strength,
+            field_strength.get::<ampere_per_meter>(),
+        );
+    }
+
+    /**
+    Reconstructs the `B(H)` curve from the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline.
+
+    For each of the given `field_strength_points`, `µr(H)` is evaluated and the
+    corresponding flux density is calculated via `B = µ0 * µr * H`. Since the
+    splines are modified during construction (see the
+    [module-level documentation](crate::relative_permeability)), the returned
+    pairs can deviate from the raw input data. Comparing both is a useful way to
+    check the input data e.g. for a wrong iron fill factor.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let field_strength: Vec<MagneticFieldStrength> =
+        [0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+            .into_iter()
+            .map(MagneticFieldStrength::new::<ampere_per_meter>)
+            .collect();
+    let flux_density: Vec<MagneticFluxDensity> = [0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let curve = MagnetizationCurve::new(field_strength.clone(), flux_density, 1.0).unwrap();
+    let permeability = FerromagneticPermeability::from_magnetization(curve).unwrap();
+
+    let pairs = permeability.to_bh_pairs(&field_strength);
+    assert_eq!(pairs.len(), 8);
+    assert_eq!(pairs[0].1.get::<tesla>(), 0.0);
+    approx::assert_abs_diff_eq!(pairs[5].1.get::<tesla>(), 1.5, epsilon = 0.02);
+    ```
+     */
+    pub fn to_bh_pairs(
+        &self,
+        field_strength_points: &[MagneticFieldStrength],
+    ) -> Vec<(MagneticFieldStrength, MagneticFluxDensity)> {
+        return field_strength_points
+            .iter()
+            .map(|field_strength| {
+                let h = field_strength.get::<ampere_per_meter>();
+                let mu_r = self.from_field_strength.eval_infallible(h);
+                let flux_density =
+                    MagneticFluxDensity::new::<tesla>(VACUUM_PERMEABILITY_UNITLESS * mu_r * h);
+                (*field_strength, flux_density)
+            })
+            .collect();
+    }
+
+    /**
+    Returns the maximum relative permeability of `self`.
+
+    The maximum is searched on the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline
+    between 0 T and its last support point. For permeabilities created from a
+    [`MagnetizationCurve`], the spline is strictly monotonously decreasing and
+    extrapolated with a horizontal line to the left (see the
+    [module-level documentation](crate::relative_permeability)), hence the
+    maximum is the value at 0 T. Splines passed to
+    [`from_splines`](FerromagneticPermeability::from_splines) may however have
+    their maximum anywhere within the search range.
+
+    # Examples
+
+    ```
+    use akima_spline::AkimaSpline;
+    use stem_material::prelude::*;
+
+    // Spline with its maximum between the support points
+    let from_flux_density = AkimaSpline::new(
+        vec![0.0, 0.5, 1.0, 1.5, 2.0],
+        vec![1000.0, 4000.0, 3000.0, 1000.0, 100.0],
+        None,
+        None,
+    )
+    .unwrap();
+    let from_field_strength = AkimaSpline::new(
+        vec![0.0, 100.0, 300.0, 1000.0, 10000.0],
+        vec![1000.0, 4000.0, 3000.0, 1000.0, 100.0],
+        None,
+        None,
+    )
+    .unwrap();
+    let permeability =
+        FerromagneticPermeability::from_splines(from_field_strength, from_flux_density);
+
+    assert!(permeability.max_permeability() >= 4000.0);
+    assert!(permeability.max_permeability() > permeability.initial_permeability());
+    ```
+     */
+    pub fn max_permeability(&self) -> f64 {
+        return self.max_permeability_point().1;
+    }
+
+    /**
+    Returns the initial relative permeability of `self`, i.e. the relative
+    permeability at zero flux density.
+
+    Physically, this is the slope `µr = B / (µ0 * H)` of the initial
+    magnetization curve of a completely demagnetized material in the limit of
+    vanishing field strength. It is a common datasheet parameter of soft
+    magnetic materials. The value is taken from the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density)
+    spline; since both splines are extrapolated with a horizontal line towards
+    zero, it is identical to the value of the
+    [`from_field_strength`](FerromagneticPermeability::from_field_strength)
+    spline at zero field strength for permeabilities created from a
+    [`MagnetizationCurve`]. For these, it is also equal to
+    [`FerromagneticPermeability::max_permeability`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.5),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.8),
+            MagneticFluxDensity::new::<tesla>(2.0),
+        ],
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    assert_eq!(
+        permeability.initial_permeability(),
+        permeability.get(MagneticFluxDensity::new::<tesla>(0.0))
+    );
+    ```
+     */
+    pub fn initial_permeability(&self) -> f64 {
+        return self.from_flux_density.eval_infallible(0.0);
+    }
+
+    /**
+    Returns the flux density at which the maximum relative permeability
+    (see [`max_permeability`](FerromagneticPermeability::max_permeability))
+    occurs. If the maximum is reached on a plateau, the largest flux density of
+    the plateau is returned. For permeabilities created from a
+    [`MagnetizationCurve`], this is the first support point of the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline,
+    since the relative permeability is constant left of it.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let permeability = FerromagneticPermeability::from_mu_r_table(
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.5),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.7),
+            MagneticFluxDensity::new::<tesla>(1.8),
+            MagneticFluxDensity::new::<tesla>(2.0),
+        ],
+        vec![4000.0, 5000.0, 1500.0, 800.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+
+    approx::assert_abs_diff_eq!(permeability.max_permeability(), 5000.0, epsilon = 1e-6);
+    approx::assert_abs_diff_eq!(
+        permeability.max_permeability_at_flux_density().get::<tesla>(),
+        1.0,
+        epsilon = 1e-6
+    );
+    ```
+     */
+    pub fn max_permeability_at_flux_density(&self) -> MagneticFluxDensity {
+        return MagneticFluxDensity::new::<tesla>(self.max_permeability_point().0);
+    }
+
+    /**
+    Returns the flux density in T and the value of the maximum relative
+    permeability on the `from_flux_density` spline between 0 T and its last
+    support point. Each spline segment is sampled at
+    `SAMPLES_PER_SEGMENT` equidistant points, which is sufficient to locate
+    the maximum of the cubic segment polynoms with a negligible error.
+     */
+    fn max_permeability_point(&self) -> (f64, f64) {
+        const SAMPLES_PER_SEGMENT: usize = 64;
+
+        let spline = &self.from_flux_density;
+        let xs = spline.xs();
+        let mut max = (0.0, spline.eval_infallible(0.0));
+
+        let mut check = |b: f64| {
+            if b < 0.0 {
+                return;
+            }
+            let mu_r = spline.eval_infallible(b);
+            if mu_r >= max.1 {
+                max = (b, mu_r);
+            }
+        };
+        for segment in xs.windows(2) {
+            let step = (segment[1] - segment[0]) / SAMPLES_PER_SEGMENT as f64;
+            for i in 0..SAMPLES_PER_SEGMENT {
+                check(segment[0] + i as f64 * step);
+            }
+        }
+        if let Some(last) = xs.last() {
+            check(*last);
+        }
+        return max;
+    }
+
+    /**
+    Returns the smallest flux density at which the relative permeability is
+    equal to or below `threshold_mu_r`.
+
+    The flux density is found via bisection on the
+    [`from_flux_density`](FerromagneticPermeability::from_flux_density) spline,
+    which is strictly monotonously decreasing by construction. The search range
+    starts at 0 T and ends at the last support point of the spline, since the
+    linear extrapolation beyond it (see the
+    [module-level documentation](crate::relative_permeability)) is not backed
+    by measurement data. If `threshold_mu_r` is not reached within this range,
+    `None` is returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(50.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(200.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(500.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(1000.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(5000.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(10000.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(0.6),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.2),
+            MagneticFluxDensity::new::<tesla>(1.4),
+            MagneticFluxDensity::new::<tesla>(1.5),
+            MagneticFluxDensity::new::<tesla>(1.7),
+            MagneticFluxDensity::new::<tesla>(1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let permeability = FerromagneticPermeability::from_magnetization(curve).unwrap();
+
+    let b = permeability.saturation_flux_density(500.0).unwrap();
+    approx::assert_abs_diff_eq!(permeability.get(b), 500.0, epsilon = 1e-3);
+
+    // The relative permeability at the last support point is about 143, lower
+    // values are only reached in the extrapolation region
+    assert!(permeability.saturation_flux_density(100.0).is_none());
+    ```
+     */
+    pub fn saturation_flux_density(&self, threshold_mu_r: f64) -> Option<MagneticFluxDensity> {
+        let mut lower = 0.0;
+        let mut upper = *self.from_flux_density.xs().last()?;
+
+        if self.from_flux_density.eval_infallible(lower) <= threshold_mu_r {
+            return Some(MagneticFluxDensity::new::<tesla>(lower));
+        }
+        if self.from_flux_density.eval_infallible(upper) > threshold_mu_r {
+            return None;
+        }
+
+        // Invariant: µr(lower) > threshold_mu_r >= µr(upper)
+        while upper - lower > 1e-9 {
+            let middle = 0.5 * (lower + upper);
+            if self.from_flux_density.eval_infallible(middle) > threshold_mu_r {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        return Some(MagneticFluxDensity::new::<tesla>(upper));
+    }
+
+    /**
+    Returns the flux density at which the relative permeability drops to 2,
+    which is a common approximation of the saturation flux density. See
+    [`saturation_flux_density`](FerromagneticPermeability::saturation_flux_density).
+
+    Since the search does not extend into the extrapolation region, this
+    function only returns a value if the magnetization curve has been measured
+    deep into saturation. For typical datasheet curves ending at 1.8 T to 2 T,
+    `µr` is still far above 2 at the last support point and `None` is returned.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    // Datasheet curve up to 1.8 T: µr ≈ 143 at the last support point
+    let mut pairs = vec![
+        (0.0, 0.0),
+        (50.0, 0.6),
+        (100.0, 1.0),
+        (200.0, 1.2),
+        (500.0, 1.4),
+        (1000.0, 1.5),
+        (5000.0, 1.7),
+        (10000.0, 1.8),
+    ];
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(&pairs, 1.0).unwrap();
+    assert!(permeability.approximate_saturation_flux_density().is_none());
+
+    // Curve measured deep into saturation: µr ≈ 1.2 at the last support point
+    pairs.extend([(1e5, 2.1), (1e6, 2.6), (2e6, 3.1)]);
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(&pairs, 1.0).unwrap();
+    let b = permeability.approximate_saturation_flux_density().unwrap();
+    approx::assert_abs_diff_eq!(permeability.get(b), 2.0, epsilon = 1e-6);
+    assert!(b.get::<tesla>() > 2.1 && b.get::<tesla>() < 3.1);
+    ```
+     */
+    pub fn approximate_saturation_flux_density(&self) -> Option<MagneticFluxDensity> {
+        return self.saturation_flux_density(2.0);
+    }
+
+    /**
+    Returns the effective permeability of two materials in magnetic series,
+    e.g. a stator tooth and a yoke made of different grades.
+
+    Both materials are assumed to have the same cross-section, so they carry
+    the same flux density `B`. The magnetic voltages add up, hence the
+    effective field strength is
+    `H(B) = length_ratio * H1(B) + (1 - length_ratio) * H2(B)` with
+    `length_ratio = L1 / (L1 + L2)`. This combined characteristic is evaluated
+    at 200 logarithmically spaced flux densities from 0.01 T up to the highest
+    flux density covered by the support points of both materials (plus the
+    origin) and the result is constructed from these datapoints via
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization).
+
+    This function fails if `length_ratio` is not between 0 and 1 or if the
+    combined characteristic is not strictly increasing.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mat_a = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let mat_b = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (100.0, 0.6),
+            (200.0, 1.0),
+            (400.0, 1.2),
+            (1000.0, 1.4),
+            (2000.0, 1.5),
+            (10000.0, 1.7),
+            (20000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let combined = FerromagneticPermeability::series_combination(&mat_a, &mat_b, 0.5).unwrap();
+
+    // The combined permeability lies between those of the individual materials
+    let b = MagneticFluxDensity::new::<tesla>(1.2);
+    assert!(combined.get(b) < mat_a.get(b));
+    assert!(combined.get(b) > mat_b.get(b));
+    ```
+     */
+    pub fn series_combination(
+        mat1: &FerromagneticPermeability,
+        mat2: &FerromagneticPermeability,
+        length_ratio: f64,
+    ) -> Result<Self, InvalidInputData> {
+        if !(0.0..=1.0).contains(&length_ratio) {
+            return Err(InvalidInputData::CombinationRatio(length_ratio));
+        }
+
+        // Stay within the flux density range covered by both characteristics,
+        // the saturation extrapolation is added by `from_magnetization` again.
+        let max_flux_density = mat1
+            .from_flux_density
+            .xmax()
+            .min(mat2.from_flux_density.xmax());
+
+        let mut field_strength = vec![MagneticFieldStrength::new::<ampere_per_meter>(0.0)];
+        let mut flux_density = vec![MagneticFluxDensity::new::<tesla>(0.0)];
+        for b in combination_grid(0.01, max_flux_density) {
+            let h1 = b / (VACUUM_PERMEABILITY_UNITLESS * mat1.from_flux_density.eval_infallible(b));
+            let h2 = b / (VACUUM_PERMEABILITY_UNITLESS * mat2.from_flux_density.eval_infallible(b));
+            let h = length_ratio * h1 + (1.0 - length_ratio) * h2;
+            field_strength.push(MagneticFieldStrength::new::<ampere_per_meter>(h));
+            flux_density.push(MagneticFluxDensity::new::<tesla>(b));
+        }
+        return Self::from_magnetization(MagnetizationCurve::new(
+            field_strength,
+            flux_density,
+            1.0,
+        )?);
+    }
+
+    /**
+    Returns the effective permeability of two materials in magnetic parallel,
+    e.g. two laminations of different grades stacked on top of each other.
+
+    Both materials are exposed to the same field strength `H`. The fluxes add
+    up, hence the effective flux density is
+    `B(H) = area_ratio * B1(H) + (1 - area_ratio) * B2(H)` with
+    `area_ratio = A1 / (A1 + A2)`. This combined characteristic is evaluated
+    at 200 logarithmically spaced field strengths from 1 A/m up to the highest
+    field strength covered by the support points of both materials (plus the
+    origin) and the result is constructed from these datapoints via
+    [`from_magnetization`](FerromagneticPermeability::from_magnetization).
+
+    This function fails if `area_ratio` is not between 0 and 1 or if the
+    combined characteristic is not strictly increasing.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let mat_a = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let combined = FerromagneticPermeability::parallel_combination(&mat_a, &mat_a, 0.3).unwrap();
+
+    // Combining a material with itself does not change its characteristic
+    for h in [500.0, 2000.0, 9000.0] {
+        let h = MagneticFieldStrength::new::<ampere_per_meter>(h);
+        approx::assert_relative_eq!(combined.get(h), mat_a.get(h), max_relative = 1e-3);
+    }
+    ```
+     */
+    pub fn parallel_combination(
+        mat1: &FerromagneticPermeability,
+        mat2: &FerromagneticPermeability,
+        area_ratio: f64,
+    ) -> Result<Self, InvalidInputData> {
+        if !(0.0..=1.0).contains(&area_ratio) {
+            return Err(InvalidInputData::CombinationRatio(area_ratio));
+        }
+
+        // Stay within the field strength range covered by both characteristics,
+        // the saturation extrapolation is added by `from_magnetization` again.
+        let max_field_strength = mat1
+            .from_field_strength
+            .xmax()
+            .min(mat2.from_field_strength.xmax());
+
+        let mut field_strength = vec![MagneticFieldStrength::new::<ampere_per_meter>(0.0)];
+        let mut flux_density = vec![MagneticFluxDensity::new::<tesla>(0.0)];
+        for h in combination_grid(1.0, max_field_strength) {
+            let mu_r = area_ratio * mat1.from_field_strength.eval_infallible(h)
+                + (1.0 - area_ratio) * mat2.from_field_strength.eval_infallible(h);
+            field_strength.push(MagneticFieldStrength::new::<ampere_per_meter>(h));
+            flux_density.push(MagneticFluxDensity::new::<tesla>(
+                VACUUM_PERMEABILITY_UNITLESS * mu_r * h,
+            ));
+        }
+        return Self::from_magnetization(MagnetizationCurve::new(
+            field_strength,
+            flux_density,
+            1.0,
+        )?);
+    }
+
+    /**
+    Returns `true` if `self` and `other` describe the same permeability curve
+    within the absolute tolerance `epsilon`.
+
+    Contrary to the [`PartialEq`] implementation, which compares the support
+    points and extrapolation coefficients of the splines within a tight
+    relative tolerance, this method evaluates both splines at 100
+    logarithmically spaced flux density values from 0.01 T to 5 T respectively
+    field strength values from 1 A/m to 1 MA/m and compares the resulting
+    relative permeabilities.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let flux_density: Vec<MagneticFluxDensity> = [0.5, 1.0, 1.5, 1.8, 2.0]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let reference = FerromagneticPermeability::from_mu_r_table(
+        flux_density.clone(),
+        vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    let perturbed = FerromagneticPermeability::from_mu_r_table(
+        flux_density,
+        vec![5000.5, 4000.0, 1500.0, 500.0, 100.0],
+        1.0,
+    )
+    .unwrap();
+    assert!(reference.approx_eq(&perturbed, 1.0));
+    assert!(!reference.approx_eq(&perturbed, 1e-3));
+    ```
+     */
+    pub fn approx_eq(&self, other: &FerromagneticPermeability, epsilon: f64) -> bool {
+        return splines_approx_eq(
+            &self.from_flux_density,
+            &other.from_flux_density,
+            0.01,
+            5.0,
+            epsilon,
+        ) && splines_approx_eq(
+            &self.from_field_strength,
+            &other.from_field_strength,
+            1.0,
+            1e6,
+            epsilon,
+        );
+    }
+}
+
+/**
+Returns 200 logarithmically spaced points between `start` and `end` (both
+inclusive), which are used to sample the combined characteristics in
+[`FerromagneticPermeability::series_combination`] and
+[`FerromagneticPermeability::parallel_combination`].
+ */
+fn combination_grid(start: f64, end: f64) -> impl Iterator<Item = f64> {
+    const NUM_SAMPLES: usize = 200;
+    let log_start = start.ln();
+    let log_step = (end.ln() - log_start) / (NUM_SAMPLES - 1) as f64;
+    return (0..NUM_SAMPLES).map(move |idx| (log_start + idx as f64 * log_step).exp());
+}
+
+/**
+Evaluates `first` and `second` at 100 logarithmically spaced points between
+`start` and `end` and returns `true` if all evaluations agree within `epsilon`.
+ */
+fn splines_approx_eq(
+    first: &AkimaSpline,
+    second: &AkimaSpline,
+    start: f64,
+    end: f64,
+    epsilon: f64,
+) -> bool {
+    const NUM_SAMPLES: usize = 100;
+    let log_start = start.ln();
+    let log_step = (end.ln() - log_start) / (NUM_SAMPLES - 1) as f64;
+    return (0..NUM_SAMPLES).all(|idx| {
+        let x = (log_start + idx as f64 * log_step).exp();
+        (first.eval_infallible(x) - second.eval_infallible(x)).abs() <= epsilon
+    });
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for FerromagneticPermeability {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        return f64::EPSILON;
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        return self.approx_eq(other, epsilon);
+    }
+}
+
+/**
+Returns the first derivative of `spline` at `x`. Within the range of the
+support points, the derivative of the spline segment is used. Outside of it,
+the derivative of the extrapolation polynomial is returned, which is zero if
+the spline has no extrapolation coefficients for that side (since
+[`AkimaSpline::eval_infallible`] then returns a constant value).
+ */
+fn spline_derivative(spline: &AkimaSpline, x: f64) -> f64 {
+    if let Some(derivative) = spline.derivative(x, 1) {
+        return derivative;
+    }
+    let (coefficients, x0) = if x < spline.xmin() {
+        (spline.extrapl(), spline.xmin())
+    } else {
+        (spline.extrapr(), spline.xmax())
+    };
+    let Some(coefficients) = coefficients else {
+        return 0.0;
+    };
+
+    // The coefficients are given in descending degree, the last one being the
+    // constant term. Differentiate the polynomial in (x - x0) via Horner's method.
+    let degree = coefficients.len() - 1;
+    let dx = x - x0;
+    let mut derivative = 0.0;
+    for (idx, coefficient) in coefficients[..degree].iter().enumerate() {
+        derivative = derivative * dx + (degree - idx) as f64 * coefficient;
+    }
+    return derivative;
+}
+
+/**
+Prints the maximum relative permeability of the characteristic and the number
+of support points of the
+[`from_flux_density`](FerromagneticPermeability::from_flux_density) spline.
+Since the splines are strictly monotonously decreasing and extrapolated with a
+horizontal line to the left, the maximum is the value at 0 T.
+ */
+impl std::fmt::Display for FerromagneticPermeability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FerromagneticPermeability (max µr = {}, {} spline knots)",
+            self.from_flux_density.eval_infallible(0.0),
+            self.from_flux_density.xs().len()
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for FerromagneticPermeability {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        for f in conditions {
+            if f.unit == Unit::from(PredefUnit::MagneticFieldStrength) {
+                return self
+                    .mu_r_from_h(MagneticFieldStrength::new::<ampere_per_meter>(f.value))
+                    .into();
+            } else if f.unit == Unit::from(PredefUnit::MagneticFluxDensity) {
+                return self
+                    .mu_r_from_b(MagneticFluxDensity::new::<tesla>(f.value))
+                    .into();
+            }
         }
         return self.from_flux_density.eval_infallible(0.0).into();
     }
@@ -462,38 +1956,544 @@ impl IsQuantityFunction for FerromagneticPermeability {
 }
 
 /**
-Sealed helper trait for [`FerromagneticPermeability::get`].
+Sealed helper trait for [`FerromagneticPermeability::get`].
+
+This sealed trait is implemented for [`MagneticFieldStrength`] and
+[`MagneticFluxDensity`] to enable [`FerromagneticPermeability::get`] to receive
+either of the two quantities as arguments. It is not meant to be implemented for
+any other types or to be used on its own.
+ */
+pub trait FieldStrengthOrFluxDensity: private::Sealed {
+    /**
+    Returns the relative `permeability` for `self`.
+
+    This function is used to implement [`FerromagneticPermeability::get`] and
+    not meant to be used on its own.
+     */
+    fn permeability(self, permeability: &FerromagneticPermeability) -> f64;
+}
+
+impl private::Sealed for MagneticFieldStrength {}
+
+impl FieldStrengthOrFluxDensity for MagneticFieldStrength {
+    fn permeability(self, permeability: &FerromagneticPermeability) -> f64 {
+        let raw = self.get::<ampere_per_meter>();
+        return permeability.from_field_strength.eval_infallible(raw);
+    }
+}
+
+impl private::Sealed for MagneticFluxDensity {}
+
+impl FieldStrengthOrFluxDensity for MagneticFluxDensity {
+    fn permeability(self, permeability: &FerromagneticPermeability) -> f64 {
+        let raw = self.get::<tesla>();
+        return permeability.from_flux_density.eval_infallible(raw);
+    }
+}
+
+/**
+Domain-specific interface for relative permeability models.
+
+Contrary to [`IsQuantityFunction`], this trait works directly with the typed
+quantities [`MagneticFluxDensity`] and [`MagneticFieldStrength`], hence an
+implementor does not need to know how these quantities are represented as
+[`DynQuantity`] conditions. It is implemented for
+[`FerromagneticPermeability`], [`RelativePermeability`] and
+[`QuantityFunction<f64>`] (the latter receives the given quantity as its only
+condition).
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+struct LinearModel;
+
+impl PermeabilityModel for LinearModel {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        return (1000.0 - 400.0 * b.get::<tesla>()).max(1.0);
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        return (1000.0 - 0.1 * h.get::<ampere_per_meter>()).max(1.0);
+    }
+}
+
+let model = LinearModel;
+assert_eq!(model.mu_r_from_b(MagneticFluxDensity::new::<tesla>(1.0)), 600.0);
+approx::assert_abs_diff_eq!(
+    model.absolute_permeability_from_b(MagneticFluxDensity::new::<tesla>(1.0)).get::<henry_per_meter>(),
+    600.0 * VACUUM_PERMEABILITY_UNITLESS,
+    epsilon = 1e-12
+);
+```
+ */
+pub trait PermeabilityModel {
+    /// Returns the relative permeability for the given flux density.
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64;
+
+    /// Returns the relative permeability for the given field strength.
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64;
+
+    /// Returns the absolute permeability `µ = µ0 * µr` for the given flux density.
+    fn absolute_permeability_from_b(&self, b: MagneticFluxDensity) -> MagneticPermeability {
+        return *VACUUM_PERMEABILITY * self.mu_r_from_b(b);
+    }
+
+    /// Returns the absolute permeability `µ = µ0 * µr` for the given field strength.
+    fn absolute_permeability_from_h(&self, h: MagneticFieldStrength) -> MagneticPermeability {
+        return *VACUUM_PERMEABILITY * self.mu_r_from_h(h);
+    }
+}
+
+impl PermeabilityModel for FerromagneticPermeability {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        return self
+            .from_flux_density
+            .eval_infallible(b.get::<tesla>().abs())
+            .clamp(1.0, f64::INFINITY);
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        return self
+            .from_field_strength
+            .eval_infallible(h.get::<ampere_per_meter>().abs())
+            .clamp(1.0, f64::INFINITY);
+    }
+}
+
+impl PermeabilityModel for QuantityFunction<f64> {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        return self.call(&[b.into()]);
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        return self.call(&[h.into()]);
+    }
+}
+
+impl PermeabilityModel for RelativePermeability {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        match self {
+            Self::Constant(val) => return *val,
+            Self::FerromagneticPermeability(model) => return model.mu_r_from_b(b),
+            Self::CubicSplinePermeability(model) => return model.mu_r_from_b(b),
+            Self::Function(fun) => return fun.mu_r_from_b(b),
+        }
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        match self {
+            Self::Constant(val) => return *val,
+            Self::FerromagneticPermeability(model) => return model.mu_r_from_h(h),
+            Self::CubicSplinePermeability(model) => return model.mu_r_from_h(h),
+            Self::Function(fun) => return fun.mu_r_from_h(h),
+        }
+    }
+}
+
+/**
+Relative permeability of a material with different characteristics along the
+d-axis and the q-axis.
+
+In e.g. the rotor of an interior permanent magnet motor, flux barriers lead to
+different effective `B(H)` characteristics along the d-axis and the q-axis.
+This struct holds one [`FerromagneticPermeability`] per axis, which can be
+accessed via [`AnisotropicFerromagneticPermeability::get_d_axis`] and
+[`AnisotropicFerromagneticPermeability::get_q_axis`].
+
+Since the conditions passed to [`IsQuantityFunction::call`] do not carry any
+information about the axis, the [`IsQuantityFunction`] and the
+[`PermeabilityModel`] implementations return the average of both axes.
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let flux_density: Vec<MagneticFluxDensity> = [0.5, 1.0, 1.5, 1.8, 2.0]
+    .into_iter()
+    .map(MagneticFluxDensity::new::<tesla>)
+    .collect();
+let d_axis = FerromagneticPermeability::from_mu_r_table(
+    flux_density.clone(),
+    vec![5000.0, 4000.0, 1500.0, 500.0, 100.0],
+    1.0,
+)
+.unwrap();
+let q_axis = FerromagneticPermeability::from_mu_r_table(
+    flux_density,
+    vec![15000.0, 12000.0, 4500.0, 1500.0, 300.0],
+    1.0,
+)
+.unwrap();
+let anisotropic = AnisotropicFerromagneticPermeability::new(d_axis, q_axis);
+
+let b = MagneticFluxDensity::new::<tesla>(1.0);
+approx::assert_abs_diff_eq!(anisotropic.get_d_axis(b), 4000.0, epsilon = 1e-6);
+approx::assert_abs_diff_eq!(anisotropic.get_q_axis(b), 12000.0, epsilon = 1e-6);
+approx::assert_abs_diff_eq!(anisotropic.mu_r_from_b(b), 8000.0, epsilon = 1e-6);
+```
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnisotropicFerromagneticPermeability {
+    /// Relative permeability along the d-axis.
+    pub d_axis: FerromagneticPermeability,
+    /// Relative permeability along the q-axis.
+    pub q_axis: FerromagneticPermeability,
+}
+
+impl AnisotropicFerromagneticPermeability {
+    /**
+    Creates a new [`AnisotropicFerromagneticPermeability`] from its fields.
+     */
+    pub fn new(d_axis: FerromagneticPermeability, q_axis: FerromagneticPermeability) -> Self {
+        return Self { d_axis, q_axis };
+    }
+
+    /**
+    Returns the relative permeability along the d-axis for the given magnetic
+    field strength or flux density.
+     */
+    pub fn get_d_axis<T: FieldStrengthOrFluxDensity>(&self, value: T) -> f64 {
+        return self.d_axis.get(value);
+    }
+
+    /**
+    Returns the relative permeability along the q-axis for the given magnetic
+    field strength or flux density.
+     */
+    pub fn get_q_axis<T: FieldStrengthOrFluxDensity>(&self, value: T) -> f64 {
+        return self.q_axis.get(value);
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for AnisotropicFerromagneticPermeability {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        let d_axis: f64 = self
+            .d_axis
+            .call(conditions)
+            .try_into()
+            .expect("implementation of FerromagneticPermeability makes sure the returned value is always a f64");
+        let q_axis: f64 = self
+            .q_axis
+            .call(conditions)
+            .try_into()
+            .expect("implementation of FerromagneticPermeability makes sure the returned value is always a f64");
+        return (0.5 * (d_axis + q_axis)).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+impl PermeabilityModel for AnisotropicFerromagneticPermeability {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        return 0.5 * (self.get_d_axis(b) + self.get_q_axis(b));
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        return 0.5 * (self.get_d_axis(h) + self.get_q_axis(h));
+    }
+}
+
+/**
+The loss tangent `tan(δ) = µ'' / µ'` of a complex permeability over the flux
+density.
+
+This struct is created together with a [`FerromagneticPermeability`] by
+[`FerromagneticPermeability::from_complex_permeability`]. Outside the range of
+the measured flux densities, the loss tangent is extrapolated with a horizontal
+line. It can be used to model the iron losses separately from the
+[`IronLosses`](crate::iron_losses::IronLosses) of a material.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LossTangentCurve {
+    /// Spline representing the function `f(B) = tan(δ)`.
+    pub from_flux_density: AkimaSpline,
+}
 
-This sealed trait is implemented for [`MagneticFieldStrength`] and
-[`MagneticFluxDensity`] to enable [`FerromagneticPermeability::get`] to receive
-either of the two quantities as arguments. It is not meant to be implemented for
-any other types or to be used on its own.
+impl LossTangentCurve {
+    /// Returns the loss tangent for the given magnetic flux density.
+    pub fn get(&self, flux_density: MagneticFluxDensity) -> f64 {
+        return self
+            .from_flux_density
+            .eval_infallible(flux_density.get::<tesla>().abs());
+    }
+}
+
+/**
+An alternative to [`FerromagneticPermeability`] which represents the functions
+`µr(H)` and `µr(B)` via [`NaturalCubicSpline`]s instead of [`AkimaSpline`]s.
+
+Akima splines are good at avoiding oscillations between the support points,
+but natural cubic splines are twice continuously differentiable, which can be
+beneficial e.g. for Newton-based solvers. Apart from the spline type, the
+construction is identical to that of [`FerromagneticPermeability`]: The raw
+curve is resampled, adjusted for the iron fill factor, all values left of the
+permeability maximum are discarded and the remaining values are modified to be
+strictly decreasing. Outside the support points, the same linear extrapolation
+is used (see the [module-level documentation](crate::relative_permeability)).
+
+# Examples
+
+```
+use stem_material::prelude::*;
+
+let curve = MagnetizationCurve::new(
+    [0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+        .into_iter()
+        .map(MagneticFieldStrength::new::<ampere_per_meter>)
+        .collect(),
+    [0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect(),
+    1.0,
+)
+.unwrap();
+let akima = FerromagneticPermeability::from_magnetization(curve.clone()).unwrap();
+let cubic = CubicSplinePermeability::from_magnetization(curve).unwrap();
+
+// Both models agree well at the support points
+let b = MagneticFluxDensity::new::<tesla>(1.4);
+let rel_diff = (akima.mu_r_from_b(b) - cubic.mu_r_from_b(b)).abs() / akima.mu_r_from_b(b);
+assert!(rel_diff < 0.01);
+```
  */
-pub trait FieldStrengthOrFluxDensity: private::Sealed {
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubicSplinePermeability {
+    /// Spline representing the function `f(H) = µr`.
+    pub from_field_strength: NaturalCubicSpline,
+    /// Spline representing the function `f(B) = µr`.
+    pub from_flux_density: NaturalCubicSpline,
+}
+
+impl CubicSplinePermeability {
     /**
-    Returns the relative `permeability` for `self`.
+    Constructs a [`CubicSplinePermeability`] from a [`MagnetizationCurve`].
 
-    This function is used to implement [`FerromagneticPermeability::get`] and
-    not meant to be used on its own.
+    This process can fail for the reasons described in the [`InvalidInputData`]
+    error enum.
      */
-    fn permeability(self, permeability: &FerromagneticPermeability) -> f64;
+    pub fn from_magnetization(raw_curve: MagnetizationCurve) -> Result<Self, InvalidInputData> {
+        let samples = magnetization_samples(&raw_curve, &SampleStrategy::default())?;
+
+        let from_field_strength = NaturalCubicSpline::new(
+            samples.field_strength,
+            samples.permeability.clone(),
+            0.0,
+            samples.slope_field_strength,
+        )?;
+        let from_flux_density = NaturalCubicSpline::new(
+            samples.induction,
+            samples.permeability,
+            0.0,
+            samples.slope_flux_density,
+        )?;
+
+        return Ok(Self {
+            from_field_strength,
+            from_flux_density,
+        });
+    }
+
+    /**
+    Constructs a [`CubicSplinePermeability`] from a [`PolarizationCurve`] by
+    converting it into a [`MagnetizationCurve`] first.
+
+    This process can fail for the reasons described in the [`InvalidInputData`]
+    error enum.
+     */
+    pub fn from_polarization(raw_curve: PolarizationCurve) -> Result<Self, InvalidInputData> {
+        return Self::from_magnetization(raw_curve.try_into()?);
+    }
 }
 
-impl private::Sealed for MagneticFieldStrength {}
+impl TryFrom<MagnetizationCurve> for CubicSplinePermeability {
+    type Error = InvalidInputData;
 
-impl FieldStrengthOrFluxDensity for MagneticFieldStrength {
-    fn permeability(self, permeability: &FerromagneticPermeability) -> f64 {
-        let raw = self.get::<ampere_per_meter>();
-        return permeability.from_field_strength.eval_infallible(raw);
+    fn try_from(value: MagnetizationCurve) -> Result<Self, InvalidInputData> {
+        return Self::from_magnetization(value);
     }
 }
 
-impl private::Sealed for MagneticFluxDensity {}
+impl TryFrom<PolarizationCurve> for CubicSplinePermeability {
+    type Error = InvalidInputData;
 
-impl FieldStrengthOrFluxDensity for MagneticFluxDensity {
-    fn permeability(self, permeability: &FerromagneticPermeability) -> f64 {
-        let raw = self.get::<tesla>();
-        return permeability.from_flux_density.eval_infallible(raw);
+    fn try_from(value: PolarizationCurve) -> Result<Self, InvalidInputData> {
+        return Self::from_polarization(value);
+    }
+}
+
+/**
+Prints the maximum relative permeability of the characteristic, which is the
+value at 0 T (see the [`Display`](std::fmt::Display) implementation of
+[`FerromagneticPermeability`]).
+ */
+impl std::fmt::Display for CubicSplinePermeability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubicSplinePermeability (max µr = {})",
+            self.from_flux_density.eval(0.0)
+        )
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl IsQuantityFunction for CubicSplinePermeability {
+    fn call(&self, conditions: &[DynQuantity<f64>]) -> DynQuantity<f64> {
+        for f in conditions {
+            if f.unit == Unit::from(PredefUnit::MagneticFieldStrength) {
+                return self
+                    .mu_r_from_h(MagneticFieldStrength::new::<ampere_per_meter>(f.value))
+                    .into();
+            } else if f.unit == Unit::from(PredefUnit::MagneticFluxDensity) {
+                return self
+                    .mu_r_from_b(MagneticFluxDensity::new::<tesla>(f.value))
+                    .into();
+            }
+        }
+        return self.from_flux_density.eval(0.0).into();
+    }
+
+    fn dyn_eq(&self, other: &dyn IsQuantityFunction) -> bool {
+        (other as &dyn std::any::Any).downcast_ref::<Self>() == Some(self)
+    }
+}
+
+impl PermeabilityModel for CubicSplinePermeability {
+    fn mu_r_from_b(&self, b: MagneticFluxDensity) -> f64 {
+        return self
+            .from_flux_density
+            .eval(b.get::<tesla>().abs())
+            .clamp(1.0, f64::INFINITY);
+    }
+
+    fn mu_r_from_h(&self, h: MagneticFieldStrength) -> f64 {
+        return self
+            .from_field_strength
+            .eval(h.get::<ampere_per_meter>().abs())
+            .clamp(1.0, f64::INFINITY);
+    }
+}
+
+/**
+A natural cubic spline with linear extrapolation, as used by
+[`CubicSplinePermeability`].
+
+Between the support points, the spline is a piecewise cubic polynomial which
+is twice continuously differentiable. Its second derivative vanishes at the
+first and the last support point ("natural" boundary conditions). Outside the
+support points, the spline is extrapolated linearly with the given slopes.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "serde_impl::NaturalCubicSplineDeserializeAlias")
+)]
+pub struct NaturalCubicSpline {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    // Derived from the support points, hence it is recalculated by
+    // `NaturalCubicSpline::new` on deserialization.
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    second_derivatives: Vec<f64>,
+    slope_left: f64,
+    slope_right: f64,
+}
+
+impl NaturalCubicSpline {
+    /**
+    Creates a new [`NaturalCubicSpline`] through the support points `x` and
+    `y`. Left of the first and right of the last support point, the spline is
+    extrapolated linearly with `slope_left` and `slope_right` respectively.
+
+    Returns [`InvalidInputData::CubicSplineBuildError`] if no support points
+    are given, if `x` and `y` have different lengths or if `x` is not strictly
+    increasing.
+     */
+    pub fn new(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        slope_left: f64,
+        slope_right: f64,
+    ) -> Result<Self, InvalidInputData> {
+        if x.is_empty() || x.len() != y.len() {
+            return Err(InvalidInputData::CubicSplineBuildError);
+        }
+        check_strictly_increasing(&x, |_| InvalidInputData::CubicSplineBuildError)?;
+
+        // Solve the tridiagonal system for the second derivatives at the
+        // support points (Thomas algorithm). The second derivatives at both
+        // ends are zero.
+        let n = x.len();
+        let mut second_derivatives = vec![0.0; n];
+        if n > 2 {
+            let mut diag = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+            for i in 1..(n - 1) {
+                let h_left = x[i] - x[i - 1];
+                let h_right = x[i + 1] - x[i];
+                diag[i] = 2.0 * (h_left + h_right);
+                rhs[i] = 6.0 * ((y[i + 1] - y[i]) / h_right - (y[i] - y[i - 1]) / h_left);
+                if i > 1 {
+                    let factor = h_left / diag[i - 1];
+                    diag[i] -= factor * h_left;
+                    rhs[i] -= factor * rhs[i - 1];
+                }
+            }
+            for i in (1..(n - 1)).rev() {
+                let h_right = x[i + 1] - x[i];
+                second_derivatives[i] = (rhs[i] - h_right * second_derivatives[i + 1]) / diag[i];
+            }
+        }
+
+        return Ok(Self {
+            x,
+            y,
+            second_derivatives,
+            slope_left,
+            slope_right,
+        });
+    }
+
+    /// Returns the x-values of the support points.
+    pub fn x(&self) -> &[f64] {
+        return self.x.as_slice();
+    }
+
+    /// Returns the y-values of the support points.
+    pub fn y(&self) -> &[f64] {
+        return self.y.as_slice();
+    }
+
+    /// Evaluates the spline at `x`. Returns [`f64::NAN`] if there are no support points.
+    pub fn eval(&self, x: f64) -> f64 {
+        let Some(last) = self.x.len().checked_sub(1) else {
+            return f64::NAN;
+        };
+        if x <= self.x[0] {
+            return self.y[0] + self.slope_left * (x - self.x[0]);
+        }
+        if x >= self.x[last] {
+            return self.y[last] + self.slope_right * (x - self.x[last]);
+        }
+
+        let idx = self.x.partition_point(|xi| *xi <= x) - 1;
+        let h = self.x[idx + 1] - self.x[idx];
+        let m0 = self.second_derivatives[idx];
+        let m1 = self.second_derivatives[idx + 1];
+        let t = x - self.x[idx];
+        let slope = (self.y[idx + 1] - self.y[idx]) / h - h * (2.0 * m0 + m1) / 6.0;
+        return self.y[idx] + t * (slope + t * (0.5 * m0 + t * (m1 - m0) / (6.0 * h)));
     }
 }
 
@@ -521,7 +2521,7 @@ material, no layer) and 0 (only layer). This iron fill factor has to be
 specified as an argument to [`MagnetizationCurve::new`]. Usually, its value is
 between 0.98 and 0.95, depending on the thickness of the sheet itself.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MagnetizationCurve {
     #[cfg_attr(
@@ -555,34 +2555,380 @@ impl MagnetizationCurve {
         0.95,
     ).is_ok());
 
-    // Unequal vector length
-    assert!(MagnetizationCurve::new(
-        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0)],
-        vec![MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(0.6)],
-        0.95,
-    ).is_err());
+    // Unequal vector length
+    assert!(MagnetizationCurve::new(
+        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0)],
+        vec![MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(0.6)],
+        0.95,
+    ).is_err());
+
+
+    // Invalid iron fill factor
+    assert!(MagnetizationCurve::new(
+        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0), MagneticFieldStrength::new::<ampere_per_meter>(150.0)],
+        vec![MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(0.6)],
+        1.1,
+    ).is_err());
+    ```
+     */
+    pub fn new(
+        field_strength: Vec<MagneticFieldStrength>,
+        flux_density: Vec<MagneticFluxDensity>,
+        iron_fill_factor: f64,
+    ) -> Result<Self, InvalidInputData> {
+        let data = MagnetizationCurve {
+            field_strength,
+            flux_density,
+            iron_fill_factor,
+        };
+        data.check()?;
+        return Ok(data);
+    }
+
+    /**
+    Like [`MagnetizationCurve::new`], but prepends the origin `(0 A/m, 0 T)` to
+    the datapoints if they do not already start with it.
+
+    Many manufacturer tables omit the origin since it is implied. However,
+    [`FerromagneticPermeability::from_magnetization`] relies on the origin
+    being part of the curve so that the flux density is zero at zero field
+    strength. If the first datapoint has a field strength or a flux density of
+    zero, but not both, the data is left unchanged (and will usually be
+    rejected by the monotonicity check).
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new_with_origin(
+        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0), MagneticFieldStrength::new::<ampere_per_meter>(150.0)],
+        vec![MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(0.6)],
+        0.95,
+    ).unwrap();
+    assert_eq!(curve.field_strength().len(), 3);
+    assert_eq!(curve.field_strength()[0].get::<ampere_per_meter>(), 0.0);
+    assert_eq!(curve.flux_density()[0].get::<tesla>(), 0.0);
+
+    // The origin is not added twice
+    let curve = MagnetizationCurve::new_with_origin(
+        curve.field_strength().to_vec(),
+        curve.flux_density().to_vec(),
+        0.95,
+    ).unwrap();
+    assert_eq!(curve.field_strength().len(), 3);
+    ```
+     */
+    pub fn new_with_origin(
+        mut field_strength: Vec<MagneticFieldStrength>,
+        mut flux_density: Vec<MagneticFluxDensity>,
+        iron_fill_factor: f64,
+    ) -> Result<Self, InvalidInputData> {
+        let starts_with_zero_h = field_strength
+            .first()
+            .is_some_and(|h| h.get::<ampere_per_meter>() == 0.0);
+        let starts_with_zero_b = flux_density
+            .first()
+            .is_some_and(|b| b.get::<tesla>() == 0.0);
+        if !starts_with_zero_h && !starts_with_zero_b {
+            field_strength.insert(0, MagneticFieldStrength::new::<ampere_per_meter>(0.0));
+            flux_density.insert(0, MagneticFluxDensity::new::<tesla>(0.0));
+        }
+        return Self::new(field_strength, flux_density, iron_fill_factor);
+    }
+
+    /**
+    Constructs a [`MagnetizationCurve`] from raw `(H, J)` pairs, where `H` is
+    given in A/m and the polarization `J` is given in T.
+
+    The flux density is calculated as `B = J + µ0 * H` for each pair. This is
+    a shorthand for creating a [`PolarizationCurve`] from the pairs and then
+    converting it into a [`MagnetizationCurve`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::from_jh_pairs_si(
+        &[(0.0, 0.0), (100.0, 0.5), (1000.0, 1.2)],
+        1.0,
+    )
+    .unwrap();
+    approx::assert_abs_diff_eq!(
+        curve.flux_density()[2].get::<tesla>(),
+        1.2 + 1000.0 * VACUUM_PERMEABILITY_UNITLESS,
+        epsilon = 1e-12
+    );
+
+    // Invalid iron fill factor
+    assert!(MagnetizationCurve::from_jh_pairs_si(&[(100.0, 0.5), (200.0, 1.0)], 1.1).is_err());
+    ```
+     */
+    pub fn from_jh_pairs_si(
+        pairs: &[(f64, f64)],
+        iron_fill_factor: f64,
+    ) -> Result<Self, InvalidInputData> {
+        let field_strength = pairs
+            .iter()
+            .map(|(h, _)| MagneticFieldStrength::new::<ampere_per_meter>(*h))
+            .collect();
+        let polarization = pairs
+            .iter()
+            .map(|(_, j)| MagneticFluxDensity::new::<tesla>(*j))
+            .collect();
+        return PolarizationCurve::new(field_strength, polarization, iron_fill_factor)?
+            .to_magnetization_curve();
+    }
+
+    /// Returns the magnetic field strength datapoints of `self`.
+    pub fn field_strength(&self) -> &[MagneticFieldStrength] {
+        return self.field_strength.as_slice();
+    }
+
+    /// Returns the magnetic flux density datapoints of `self`.
+    pub fn flux_density(&self) -> &[MagneticFluxDensity] {
+        return self.flux_density.as_slice();
+    }
+
+    /// Returns the iron fill factor of `self`.
+    pub fn iron_fill_factor(&self) -> f64 {
+        return self.iron_fill_factor;
+    }
+
+    /**
+    Multiplies all flux density values of `self` by `factor` and returns the
+    modified curve. The field strength values and the iron fill factor stay
+    unchanged.
+
+    This is a low-level escape hatch for data whose original iron fill factor
+    is unknown, so that
+    [`FerromagneticPermeability::change_iron_fill_factor`] cannot be used. In
+    all other cases, the iron fill factor should be specified via the
+    `iron_fill_factor` argument of [`MagnetizationCurve::new`] instead.
+
+    # Panics
+
+    Panics if `factor` is not positive and finite, since the scaled flux
+    density would not be strictly increasing anymore.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+        ],
+        0.95,
+    )
+    .unwrap()
+    .scale_flux_density(0.5);
+    assert_eq!(curve.flux_density()[1], MagneticFluxDensity::new::<tesla>(0.5));
+    assert_eq!(curve.iron_fill_factor(), 0.95);
+    ```
+     */
+    pub fn scale_flux_density(mut self, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "flux density scaling factor must be positive and finite, is {factor}"
+        );
+        for flux_density in self.flux_density.iter_mut() {
+            *flux_density *= factor;
+        }
+        return self;
+    }
+
+    /**
+    Returns a copy of `self` whose flux density values have been smoothed.
+
+    Noisy measurement data can lead to irregular curves which in turn cause
+    oscillations in the splines of a [`FerromagneticPermeability`]. This method
+    replaces each flux density value by the value of a least-squares line
+    fitted through the neighbouring datapoints (a first-order
+    Savitzky-Golay filter which accounts for non-uniformly spaced field
+    strength values). The window is centered around the datapoint and shrinks
+    towards the ends of the curve, so the first and the last datapoint are not
+    changed.
+
+    The `smoothing_factor` is clamped to the interval `[0, 1]` (NaN is treated
+    as 0) and controls the window size: 0 means no smoothing at all, 1 means
+    that the window of the middle datapoint spans the entire curve. Since
+    smoothing can break the monotonicity of the curve, the result is validated
+    via [`MagnetizationCurve::validate_monotonicity`]. If this check fails, the
+    window is shrunk until the smoothed curve passes it. Without smoothing, the
+    curve is returned unchanged, hence the result is always valid.
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let h = |v| MagneticFieldStrength::new::<ampere_per_meter>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let curve = MagnetizationCurve::new(
+        vec![h(0.0), h(100.0), h(200.0), h(300.0), h(400.0), h(500.0)],
+        vec![b(0.0), b(0.5), b(1.05), b(1.1), b(1.4), b(1.5)],
+        1.0,
+    )
+    .unwrap();
+
+    // No smoothing
+    assert_eq!(curve.smoothed(0.0), curve);
+    assert_eq!(curve.smoothed(f64::NAN), curve);
+
+    // The kink at 200 A/m is flattened, but the endpoints are retained
+    let smoothed = curve.smoothed(0.5);
+    assert!(smoothed.flux_density()[2].get::<tesla>() < 1.05);
+    assert_eq!(smoothed.flux_density()[0], curve.flux_density()[0]);
+    assert_eq!(smoothed.flux_density()[5], curve.flux_density()[5]);
+    assert!(smoothed.validate_monotonicity().is_ok());
+
+    // Factors larger than 1 are clamped
+    assert_eq!(curve.smoothed(5.0), curve.smoothed(1.0));
+    ```
+     */
+    pub fn smoothed(&self, smoothing_factor: f64) -> Self {
+        // A NaN smoothing factor is treated like no smoothing at all
+        let smoothing_factor = if smoothing_factor.is_nan() {
+            0.0
+        } else {
+            smoothing_factor.clamp(0.0, 1.0)
+        };
+        let num_points = self.flux_density.len();
+        let mut half_window =
+            (smoothing_factor * num_points.saturating_sub(1) as f64 / 2.0).round() as usize;
+
+        while half_window > 0 {
+            let curve = Self {
+                field_strength: self.field_strength.clone(),
+                flux_density: self.smoothed_flux_density(half_window),
+                iron_fill_factor: self.iron_fill_factor,
+            };
+            if curve.validate_monotonicity().is_ok() {
+                return curve;
+            }
+            half_window -= 1;
+        }
+        return self.clone();
+    }
+
+    /**
+    Returns the flux density values of `self` smoothed with a window of
+    `2 * half_window + 1` datapoints, see [`MagnetizationCurve::smoothed`].
+     */
+    fn smoothed_flux_density(&self, half_window: usize) -> Vec<MagneticFluxDensity> {
+        let num_points = self.flux_density.len();
+        return (0..num_points)
+            .map(|index| {
+                let half = half_window.min(index).min(num_points - 1 - index);
+                if half == 0 {
+                    return self.flux_density[index];
+                }
+
+                // Least-squares line through the window, evaluated at the
+                // field strength of the datapoint itself
+                let window = (index - half)..=(index + half);
+                let center = self.field_strength[index].get::<ampere_per_meter>();
+                let len = (2 * half + 1) as f64;
+                let x_mean = self.field_strength[window.clone()]
+                    .iter()
+                    .map(|h| h.get::<ampere_per_meter>() - center)
+                    .sum::<f64>()
+                    / len;
+                let y_mean = self.flux_density[window.clone()]
+                    .iter()
+                    .map(|b| b.get::<tesla>())
+                    .sum::<f64>()
+                    / len;
+                let mut covariance = 0.0;
+                let mut variance = 0.0;
+                for (h, b) in self.field_strength[window.clone()]
+                    .iter()
+                    .zip(self.flux_density[window].iter())
+                {
+                    let dx = h.get::<ampere_per_meter>() - center - x_mean;
+                    covariance += dx * (b.get::<tesla>() - y_mean);
+                    variance += dx * dx;
+                }
+                let slope = if variance > 0.0 {
+                    covariance / variance
+                } else {
+                    0.0
+                };
+                return MagneticFluxDensity::new::<tesla>(y_mean - slope * x_mean);
+            })
+            .collect();
+    }
+
+    /**
+    Extends `self` with additional datapoints and returns the extended curve.
 
+    This is useful if a manufacturer provides the magnetization curve in two
+    separate tables, e.g. a finely resolved low-field table and a sparser
+    high-field extension. The extended curve is checked in the same way as in
+    [`MagnetizationCurve::new`]. In particular, the additional vectors need to
+    have the same length and all additional field strength and flux density
+    values need to be larger than the existing ones (see
+    [`MagnetizationCurve::validate_monotonicity`]).
 
-    // Invalid iron fill factor
-    assert!(MagnetizationCurve::new(
-        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0), MagneticFieldStrength::new::<ampere_per_meter>(150.0)],
-        vec![MagneticFluxDensity::new::<tesla>(0.5), MagneticFluxDensity::new::<tesla>(0.6)],
-        1.1,
-    ).is_err());
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let low_field = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+        ],
+        1.0,
+    )
+    .unwrap();
+
+    let curve = low_field
+        .clone()
+        .append(
+            vec![MagneticFieldStrength::new::<ampere_per_meter>(10000.0)],
+            vec![MagneticFluxDensity::new::<tesla>(1.8)],
+        )
+        .unwrap();
+    assert_eq!(curve.field_strength().len(), 3);
+
+    // The high-field data needs to continue the curve
+    assert!(low_field
+        .append(
+            vec![MagneticFieldStrength::new::<ampere_per_meter>(50.0)],
+            vec![MagneticFluxDensity::new::<tesla>(1.8)],
+        )
+        .is_err());
     ```
      */
-    pub fn new(
-        field_strength: Vec<MagneticFieldStrength>,
-        flux_density: Vec<MagneticFluxDensity>,
-        iron_fill_factor: f64,
+    pub fn append(
+        mut self,
+        additional_field_strength: Vec<MagneticFieldStrength>,
+        additional_flux_density: Vec<MagneticFluxDensity>,
     ) -> Result<Self, InvalidInputData> {
-        let data = MagnetizationCurve {
-            field_strength,
-            flux_density,
-            iron_fill_factor,
-        };
-        data.check()?;
-        return Ok(data);
+        if additional_field_strength.len() != additional_flux_density.len() {
+            return Err(InvalidInputData::IneqNumElementsFluxDensity {
+                field_strength: additional_field_strength.len(),
+                flux_density: additional_flux_density.len(),
+            });
+        }
+        self.field_strength.extend(additional_field_strength);
+        self.flux_density.extend(additional_flux_density);
+        self.check()?;
+        return Ok(self);
     }
 
     // Check the integrity of the data
@@ -596,7 +2942,134 @@ impl MagnetizationCurve {
                 flux_density: self.flux_density.len(),
             });
         }
-        return Ok(());
+        return self.validate_monotonicity();
+    }
+
+    /**
+    Checks whether both the field strength and the flux density of `self` are
+    strictly increasing, i.e. `H[i+1] > H[i]` and `B[i+1] > B[i]` for all
+    consecutive datapoints. This is a physical requirement for a magnetization
+    curve, and non-monotonic data can lead to splines which prevent iterative
+    solvers from converging.
+
+    The check is done by
+    [`check_strictly_increasing`](MagnetizationCurve::check_strictly_increasing),
+    which checks the field strength first. An offending field strength value is
+    therefore reported as
+    [`InvalidInputData::NonStrictlyIncreasingFieldStrength`], an offending flux
+    density value as [`InvalidInputData::NonMonotonicFluxDensity`]. Both carry
+    the index of the first offending datapoint.
+
+    This check is performed by [`MagnetizationCurve::new`].
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let error = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(200.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(0.9),
+        ],
+        1.0,
+    )
+    .unwrap_err();
+    assert!(matches!(error, InvalidInputData::NonMonotonicFluxDensity { index: 2 }));
+
+    let error = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+            MagneticFluxDensity::new::<tesla>(1.1),
+        ],
+        1.0,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        InvalidInputData::NonStrictlyIncreasingFieldStrength { index: 2 }
+    ));
+    ```
+     */
+    pub fn validate_monotonicity(&self) -> Result<(), InvalidInputData> {
+        match self.check_strictly_increasing() {
+            Err(InvalidInputData::NonStrictlyIncreasingFluxDensity { index }) => {
+                return Err(InvalidInputData::NonMonotonicFluxDensity { index });
+            }
+            result => return result,
+        }
+    }
+
+    /**
+    Checks whether the field strength and the flux density of `self` are
+    strictly increasing. The returned error tells which of the two columns is
+    the offending one: The field strength is checked first and an
+    [`InvalidInputData::NonStrictlyIncreasingFieldStrength`] is returned for
+    the first datapoint which is not larger than its predecessor. Afterwards,
+    the flux density is checked in the same way, returning an
+    [`InvalidInputData::NonStrictlyIncreasingFluxDensity`].
+
+    This is an opt-in validation step for curves which have not been created
+    via [`MagnetizationCurve::new`], e.g. because they have been deserialized
+    (deserialization does not validate the datapoints).
+
+    # Examples
+
+    ```
+    use stem_material::prelude::*;
+
+    let curve = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+        ],
+        1.0,
+    )
+    .unwrap();
+    assert!(curve.check_strictly_increasing().is_ok());
+    ```
+     */
+    pub fn check_strictly_increasing(&self) -> Result<(), InvalidInputData> {
+        check_strictly_increasing(&self.field_strength, |index| {
+            InvalidInputData::NonStrictlyIncreasingFieldStrength { index }
+        })?;
+        return check_strictly_increasing(&self.flux_density, |index| {
+            InvalidInputData::NonStrictlyIncreasingFluxDensity { index }
+        });
+    }
+}
+
+/**
+Checks whether `values` are strictly increasing. For the first value which is
+not larger than its predecessor, the error created by `error` from the index of
+that value is returned.
+ */
+fn check_strictly_increasing<T: PartialOrd>(
+    values: &[T],
+    error: impl Fn(usize) -> InvalidInputData,
+) -> Result<(), InvalidInputData> {
+    match values
+        .windows(2)
+        .position(|pair| pair[0].partial_cmp(&pair[1]) != Some(std::cmp::Ordering::Less))
+    {
+        Some(position) => return Err(error(position + 1)),
+        None => return Ok(()),
     }
 }
 
@@ -634,6 +3107,7 @@ material, no layer) and 0 (only layer). This iron fill factor has to be
 specified as an argument to [`PolarizationCurve::new`]. Usually, its value is
 between 0.98 and 0.95, depending on the thickness of the sheet itself.
  */
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PolarizationCurve {
     #[cfg_attr(
@@ -697,6 +3171,45 @@ impl PolarizationCurve {
         return Ok(data);
     }
 
+    /// Returns the magnetic field strength datapoints of `self`.
+    pub fn field_strength(&self) -> &[MagneticFieldStrength] {
+        return self.field_strength.as_slice();
+    }
+
+    /**
+    Converts `self` into a [`MagnetizationCurve`] without consuming it.
+
+    The flux density is calculated from the polarization via
+    `B = J + µ0 * H`. The [`TryFrom`] implementation for [`MagnetizationCurve`]
+    delegates to this method.
+     */
+    pub fn to_magnetization_curve(&self) -> Result<MagnetizationCurve, InvalidInputData> {
+        let flux_density = self
+            .polarization
+            .iter()
+            .zip(self.field_strength.iter())
+            .map(|(j, h)| *j + *h * *VACUUM_PERMEABILITY)
+            .collect();
+
+        let data = MagnetizationCurve {
+            field_strength: self.field_strength.clone(),
+            flux_density,
+            iron_fill_factor: self.iron_fill_factor,
+        };
+        data.check()?;
+        return Ok(data);
+    }
+
+    /// Returns the magnetic polarization datapoints of `self`.
+    pub fn polarization(&self) -> &[MagneticFluxDensity] {
+        return self.polarization.as_slice();
+    }
+
+    /// Returns the iron fill factor of `self`.
+    pub fn iron_fill_factor(&self) -> f64 {
+        return self.iron_fill_factor;
+    }
+
     // Check the integrity of the data
     fn check(&self) -> Result<(), InvalidInputData> {
         if self.iron_fill_factor > 1.0 || self.iron_fill_factor < 0.0 {
@@ -716,22 +3229,7 @@ impl TryFrom<PolarizationCurve> for MagnetizationCurve {
     type Error = InvalidInputData;
 
     fn try_from(value: PolarizationCurve) -> Result<Self, InvalidInputData> {
-        // Calculate the flux density from the polarization
-        let mut flux_density = value.polarization;
-        flux_density
-            .iter_mut()
-            .zip(value.field_strength.iter())
-            .for_each(|(b, h)| {
-                *b = *b + *h * *VACUUM_PERMEABILITY;
-            });
-
-        let data = MagnetizationCurve {
-            field_strength: value.field_strength,
-            flux_density,
-            iron_fill_factor: value.iron_fill_factor,
-        };
-        data.check()?;
-        return Ok(data);
+        return value.to_magnetization_curve();
     }
 }
 
@@ -746,7 +3244,7 @@ impl TryFrom<PolarizationCurve> for FerromagneticPermeability {
 
 /**
 Errors which can occur when attempting to convert a [`MagnetizationCurve`] or
-[`PolarizationCurve`] into a [`FerromagneticPermeability`].
+[`PolarizationCurve`] (or other raw data) into a [`FerromagneticPermeability`].
  */
 #[derive(Debug)]
 pub enum InvalidInputData {
@@ -774,8 +3272,83 @@ pub enum InvalidInputData {
         /// Length of the polarization vector
         polarization: usize,
     },
+    /**
+    The given vectors for magnetic flux density and relative permeability did
+    not have the same length. This error can only be returned by
+    [`FerromagneticPermeability::from_mu_r_table`].
+     */
+    IneqNumElementsPermeability {
+        /// Length of the flux density vector
+        flux_density: usize,
+        /// Length of the relative permeability vector
+        permeability: usize,
+    },
+    /// A relative permeability value is zero, negative or NaN.
+    NonPositivePermeability(f64),
+    /**
+    The flux density values are not strictly increasing. `index` is the
+    position of the first datapoint which is not larger than its predecessor.
+     */
+    NonMonotonicFluxDensity {
+        /// Index of the offending value
+        index: usize,
+    },
+    /**
+    The field strength values are not strictly increasing. `index` is the
+    position of the first datapoint which is not larger than its predecessor.
+    This error is returned by
+    [`MagnetizationCurve::check_strictly_increasing`] and
+    [`MagnetizationCurve::validate_monotonicity`] (and therefore also by
+    [`MagnetizationCurve::new`]).
+     */
+    NonStrictlyIncreasingFieldStrength {
+        /// Index of the offending value
+        index: usize,
+    },
+    /**
+    The flux density values are not strictly increasing. `index` is the
+    position of the first datapoint which is not larger than its predecessor.
+    This error can only be returned by
+    [`MagnetizationCurve::check_strictly_increasing`].
+     */
+    NonStrictlyIncreasingFluxDensity {
+        /// Index of the offending value
+        index: usize,
+    },
+    /**
+    The raw data needed to recompute a [`FerromagneticPermeability`] is not
+    available, see [`FerromagneticPermeability::original_curve`].
+     */
+    MissingOriginalCurve,
     /// Building one of the [`AkimaSpline`]s failed.
     AkimaBuildError(akima_spline::BuildError),
+    /**
+    The support points of a [`NaturalCubicSpline`] are invalid: Either none are
+    given, the number of x- and y-values differs or the x-values are not
+    strictly increasing.
+     */
+    CubicSplineBuildError,
+    /**
+    The length or area ratio used to combine two permeabilities in
+    [`FerromagneticPermeability::series_combination`] or
+    [`FerromagneticPermeability::parallel_combination`] is not between 0 and 1.
+     */
+    CombinationRatio(f64),
+    /**
+    The [`SampleStrategy::step_width`] (given in A/m) is zero, negative or not
+    finite.
+     */
+    SampleStepWidth(f64),
+    /**
+    The [`SampleStrategy::change_tolerance`] is negative or not finite.
+     */
+    SampleChangeTolerance(f64),
+    /**
+    The [`SampleStrategy::step_width`] is so small compared to the largest
+    field strength of the curve that resampling would need more than
+    1 000 000 steps. The value is the number of steps which would be needed.
+     */
+    SampleStepCount(f64),
 }
 
 impl From<akima_spline::BuildError> for InvalidInputData {
@@ -784,8 +3357,40 @@ impl From<akima_spline::BuildError> for InvalidInputData {
     }
 }
 
+impl InvalidInputData {
+    /// Returns the name of the variant of `self`, e.g. `"IronFillFactor"`.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            InvalidInputData::IronFillFactor(_) => "IronFillFactor",
+            InvalidInputData::IneqNumElementsFluxDensity { .. } => "IneqNumElementsFluxDensity",
+            InvalidInputData::IneqNumElementsPolarization { .. } => "IneqNumElementsPolarization",
+            InvalidInputData::IneqNumElementsPermeability { .. } => "IneqNumElementsPermeability",
+            InvalidInputData::NonPositivePermeability(_) => "NonPositivePermeability",
+            InvalidInputData::NonMonotonicFluxDensity { .. } => "NonMonotonicFluxDensity",
+            InvalidInputData::NonStrictlyIncreasingFieldStrength { .. } => {
+                "NonStrictlyIncreasingFieldStrength"
+            }
+            InvalidInputData::NonStrictlyIncreasingFluxDensity { .. } => {
+                "NonStrictlyIncreasingFluxDensity"
+            }
+            InvalidInputData::MissingOriginalCurve => "MissingOriginalCurve",
+            InvalidInputData::AkimaBuildError(_) => "AkimaBuildError",
+            InvalidInputData::CubicSplineBuildError => "CubicSplineBuildError",
+            InvalidInputData::CombinationRatio(_) => "CombinationRatio",
+            InvalidInputData::SampleStepWidth(_) => "SampleStepWidth",
+            InvalidInputData::SampleChangeTolerance(_) => "SampleChangeTolerance",
+            InvalidInputData::SampleStepCount(_) => "SampleStepCount",
+        }
+    }
+}
+
+/**
+The message starts with the [variant name](InvalidInputData::variant_name),
+followed by a colon and a description of the error.
+ */
 impl std::fmt::Display for InvalidInputData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.variant_name())?;
         match self {
             InvalidInputData::IronFillFactor(value) => write!(
                 f,
@@ -796,7 +3401,7 @@ impl std::fmt::Display for InvalidInputData {
                 flux_density,
             } => write!(
                 f,
-                "got {field_strength} values for field strength, but
+                "got {field_strength} values for field strength, but \
                 {flux_density} values for flux density (should be equal)."
             ),
             InvalidInputData::IneqNumElementsPolarization {
@@ -804,27 +3409,164 @@ impl std::fmt::Display for InvalidInputData {
                 polarization,
             } => write!(
                 f,
-                "got {field_strength} values for field strength, but
+                "got {field_strength} values for field strength, but \
                 {polarization} values for polarization (should be equal)."
             ),
+            InvalidInputData::IneqNumElementsPermeability {
+                flux_density,
+                permeability,
+            } => write!(
+                f,
+                "got {flux_density} values for flux density, but \
+                {permeability} values for relative permeability (should be equal)."
+            ),
+            InvalidInputData::NonPositivePermeability(value) => {
+                write!(f, "relative permeability must be positive, is {value}.")
+            }
+            InvalidInputData::NonMonotonicFluxDensity { index } => write!(
+                f,
+                "flux density must be strictly increasing, but value at index {index} is not larger than its predecessor."
+            ),
+            InvalidInputData::NonStrictlyIncreasingFieldStrength { index } => write!(
+                f,
+                "field strength must be strictly increasing, but value at index {index} is not larger than its predecessor."
+            ),
+            InvalidInputData::NonStrictlyIncreasingFluxDensity { index } => write!(
+                f,
+                "flux density must be strictly increasing, but value at index {index} is not larger than its predecessor."
+            ),
+            InvalidInputData::MissingOriginalCurve => {
+                write!(f, "the original magnetization curve is not available.")
+            }
             InvalidInputData::AkimaBuildError(error) => return error.fmt(f),
+            InvalidInputData::CubicSplineBuildError => write!(
+                f,
+                "spline support points must not be empty, have equal lengths and strictly increasing x-values."
+            ),
+            InvalidInputData::CombinationRatio(value) => {
+                write!(f, "combination ratio must be between 0 and 1, is {value}.")
+            }
+            InvalidInputData::SampleStepWidth(value) => write!(
+                f,
+                "sample step width must be positive and finite, is {value} A/m."
+            ),
+            InvalidInputData::SampleChangeTolerance(value) => write!(
+                f,
+                "sample change tolerance must be non-negative and finite, is {value}."
+            ),
+            InvalidInputData::SampleStepCount(value) => write!(
+                f,
+                "resampling needs at most {MAX_SAMPLE_STEPS} steps, but the step width requires {value}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidInputData {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidInputData::AkimaBuildError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/**
+Errors which can occur in [`FerromagneticPermeability::get_from_str`].
+ */
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum PermeabilityParseError {
+    /// The string could not be parsed into a [`DynQuantity`].
+    InvalidQuantity(<DynQuantity<f64> as std::str::FromStr>::Err),
+    /// The parsed quantity is neither a magnetic flux density nor a magnetic
+    /// field strength.
+    UnexpectedUnit(Unit),
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for PermeabilityParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermeabilityParseError::InvalidQuantity(error) => {
+                return std::fmt::Display::fmt(error, f);
+            }
+            PermeabilityParseError::UnexpectedUnit(unit) => write!(
+                f,
+                "expected a magnetic flux density or field strength, got a quantity with unit {unit:?}."
+            ),
         }
     }
 }
 
-impl std::error::Error for InvalidInputData {}
+#[cfg(feature = "serde")]
+impl std::error::Error for PermeabilityParseError {}
+
+/**
+Controls how the datapoints of a [`MagnetizationCurve`] are resampled before the
+splines of a [`FerromagneticPermeability`] are created, see
+[`FerromagneticPermeability::from_magnetization_with_strategy`].
+
+Starting at 0 A/m, the BH curve is evaluated at multiples of
+[`step_width`](SampleStrategy::step_width). A point is kept as a support point
+if the relative permeability differs from that of the previously kept point by
+more than [`change_tolerance`](SampleStrategy::change_tolerance).
+
+The default strategy uses a step width of 10 A/m and a change tolerance of 0.02
+(2 %).
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SampleStrategy {
+    /// Distance between two evaluated field strength values. Must be positive
+    /// and finite.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_quantity"))]
+    pub step_width: MagneticFieldStrength,
+    /// Relative change of the relative permeability (as a fraction) above
+    /// which a point is kept as a support point. Must be non-negative and
+    /// finite.
+    pub change_tolerance: f64,
+}
+
+impl Default for SampleStrategy {
+    fn default() -> Self {
+        return Self {
+            step_width: MagneticFieldStrength::new::<ampere_per_meter>(10.0),
+            change_tolerance: 0.02,
+        };
+    }
+}
+
+impl SampleStrategy {
+    /// Returns `true` if `self` is equal to [`SampleStrategy::default`].
+    pub fn is_default(&self) -> bool {
+        return *self == Self::default();
+    }
+}
+
+/**
+Maximum number of steps used by [`sample_bh_curve`] to evaluate a BH curve.
+ */
+const MAX_SAMPLE_STEPS: usize = 1_000_000;
 
 /**
 Sample the given BH curve so that the maximum permeability change between two
-support points is equal / less than the given tolerance.
+support points is equal / less than the tolerance of the given strategy.
  */
 fn sample_bh_curve(
     field_strength: &[MagneticFieldStrength],
     flux_density: &[MagneticFluxDensity],
-    change_tol: f64,
+    strategy: &SampleStrategy,
 ) -> Result<(Vec<MagneticFieldStrength>, Vec<MagneticFluxDensity>), InvalidInputData> {
-    // Intial sample step width of 10 A/m
-    let sample_step_width = MagneticFieldStrength::new::<ampere_per_meter>(10.0);
+    let sample_step_width = strategy.step_width;
+    let change_tol = strategy.change_tolerance;
+    let step_width_raw = sample_step_width.get::<ampere_per_meter>();
+    if !(step_width_raw.is_finite() && step_width_raw > 0.0) {
+        return Err(InvalidInputData::SampleStepWidth(step_width_raw));
+    }
+    if !(change_tol.is_finite() && change_tol >= 0.0) {
+        return Err(InvalidInputData::SampleChangeTolerance(change_tol));
+    }
 
     let max_field_strength = field_strength
         .iter()
@@ -832,6 +3574,11 @@ fn sample_bh_curve(
         .reduce(|first, second| if first > second { first } else { second })
         .expect("must have at least one element");
 
+    let num_steps = max_field_strength.get::<ampere_per_meter>() / step_width_raw;
+    if num_steps > MAX_SAMPLE_STEPS as f64 {
+        return Err(InvalidInputData::SampleStepCount(num_steps));
+    }
+
     // Create a B(H) curve
     let extrapl = Some(vec![VACUUM_PERMEABILITY_UNITLESS]);
     let extrapr = Some(vec![VACUUM_PERMEABILITY_UNITLESS]);
@@ -884,6 +3631,141 @@ fn sample_bh_curve(
     return Ok((h_sampled, b_sampled));
 }
 
+/**
+Samples the given [`MagnetizationCurve`] via [`sample_bh_curve`] and returns the
+field strength, the iron fill factor-adjusted flux density and the
+corresponding relative permeability as [`PermeabilitySamples`].
+Datapoints at a field strength of zero are skipped, since the relative
+permeability is not defined there.
+ */
+fn magnetization_samples(
+    raw_curve: &MagnetizationCurve,
+    strategy: &SampleStrategy,
+) -> Result<PermeabilitySamples, InvalidInputData> {
+    let (field_strength, flux_density) = sample_bh_curve(
+        raw_curve.field_strength.as_slice(),
+        raw_curve.flux_density.as_slice(),
+        strategy,
+    )?;
+
+    // Calculate relative permeability
+    let mut induction: Vec<f64> = Vec::with_capacity(field_strength.len());
+    let mut permeability: Vec<f64> = Vec::with_capacity(field_strength.len());
+    let mut field_strength_spline: Vec<f64> = Vec::with_capacity(field_strength.len());
+
+    for (hi, bi) in field_strength
+        .iter()
+        .map(|value| value.get::<ampere_per_meter>())
+        .zip(flux_density.iter().map(|value| value.get::<tesla>()))
+    {
+        if hi != 0.0 {
+            // Adjust for the iron fill factor
+            let b_red = bi * raw_curve.iron_fill_factor
+                + (1.0 - raw_curve.iron_fill_factor) * hi * VACUUM_PERMEABILITY_UNITLESS;
+
+            // Calculate with the reduced flux density
+            let mu_r = b_red / (hi * VACUUM_PERMEABILITY_UNITLESS);
+            field_strength_spline.push(hi);
+            induction.push(b_red);
+            permeability.push(mu_r);
+        }
+    }
+
+    return Ok(PermeabilitySamples::new(
+        field_strength_spline,
+        induction,
+        permeability,
+    ));
+}
+
+/**
+Preprocessed spline support points shared by [`FerromagneticPermeability`] and
+[`CubicSplinePermeability`]. All values left of the permeability maximum are
+discarded and the remaining permeability values are modified to be strictly
+decreasing as described in the [module-level documentation](crate::relative_permeability).
+The slopes are used for the linear extrapolation to the right of the last
+support point, so that the relative permeability reaches 1 at
+[`SATURATION_FLUX_DENSITY_UNITLESS`]. To the left, both splines are
+extrapolated with a horizontal line.
+ */
+struct PermeabilitySamples {
+    field_strength: Vec<f64>,
+    induction: Vec<f64>,
+    permeability: Vec<f64>,
+    slope_field_strength: f64,
+    slope_flux_density: f64,
+}
+
+impl PermeabilitySamples {
+    fn new(field_strength: Vec<f64>, induction: Vec<f64>, permeability: Vec<f64>) -> Self {
+        let mut idx_max = None;
+        let mut min_value = f64::NEG_INFINITY;
+        for (idx, value) in permeability.iter().enumerate() {
+            if *value > min_value {
+                min_value = *value;
+                idx_max = Some(idx);
+            }
+        }
+        let idx_max = idx_max.expect("Guaranteed to have at least one value by the constructor");
+
+        // Remove all values "left" of idx_max
+        let field_strength = field_strength[idx_max..].to_vec();
+        let induction = induction[idx_max..].to_vec();
+        let mut permeability = permeability[idx_max..].to_vec();
+
+        // Modify mu_r(B) to ensure strictly decreasing behaviour.
+        if permeability.len() > 2 {
+            for idx in (0..(permeability.len() - 2)).rev() {
+                if permeability[idx] < permeability[idx + 1] {
+                    let m = (permeability[idx + 1] - permeability[idx + 2])
+                        / (induction[idx + 1] - induction[idx + 2]);
+
+                    // Calculate the new y-value with the gradient
+                    permeability[idx] =
+                        permeability[idx + 1] + m * (induction[idx + 1] - induction[idx + 2]);
+                }
+            }
+        }
+
+        // Extrapolation function for induction values larger than induction[end].
+        let (slope_field_strength, slope_flux_density) = saturation_slopes(
+            *induction
+                .last()
+                .expect("Guaranteed to have at least one value by the constructor"),
+            *permeability
+                .last()
+                .expect("Guaranteed to have at least one value by the constructor"),
+        );
+
+        return Self {
+            field_strength,
+            induction,
+            permeability,
+            slope_field_strength,
+            slope_flux_density,
+        };
+    }
+}
+
+/**
+Returns the slopes of `µr(H)` and `µr(B)` for the linear extrapolation to the
+right of the last support point (`induction`, `permeability`), so that the
+relative permeability reaches 1 at [`SATURATION_FLUX_DENSITY_UNITLESS`].
+ */
+fn saturation_slopes(induction: f64, permeability: f64) -> (f64, f64) {
+    let induction_1 = induction;
+    let induction_2 = SATURATION_FLUX_DENSITY_UNITLESS;
+    let permeability_1 = permeability;
+    let permeability_2 = 1.0;
+    let field_strength_1 = induction_1 / (VACUUM_PERMEABILITY_UNITLESS * permeability_1);
+    let field_strength_2 = induction_2 / (VACUUM_PERMEABILITY_UNITLESS * permeability_2);
+
+    let slope_field_strength =
+        (permeability_2 - permeability_1) / (field_strength_2 - field_strength_1);
+    let slope_flux_density = (permeability_2 - permeability_1) / (induction_2 - induction_1);
+    return (slope_field_strength, slope_flux_density);
+}
+
 #[cfg(feature = "serde")]
 mod serde_impl {
     use deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError;
@@ -894,6 +3776,26 @@ mod serde_impl {
     pub(super) struct FerromagneticPermeabilityDeserializeAlias {
         from_field_strength: AkimaSpline,
         from_flux_density: AkimaSpline,
+        #[serde(default)]
+        original_curve: Option<MagnetizationCurve>,
+        #[serde(default)]
+        sample_strategy: SampleStrategy,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct NaturalCubicSplineDeserializeAlias {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        slope_left: f64,
+        slope_right: f64,
+    }
+
+    impl TryFrom<NaturalCubicSplineDeserializeAlias> for NaturalCubicSpline {
+        type Error = InvalidInputData;
+
+        fn try_from(value: NaturalCubicSplineDeserializeAlias) -> Result<Self, InvalidInputData> {
+            return NaturalCubicSpline::new(value.x, value.y, value.slope_left, value.slope_right);
+        }
     }
 
     #[derive(DeserializeUntaggedVerboseError)]
@@ -912,6 +3814,8 @@ mod serde_impl {
                     Ok(FerromagneticPermeability {
                         from_field_strength: val.from_field_strength,
                         from_flux_density: val.from_flux_density,
+                        original_curve: val.original_curve,
+                        sample_strategy: val.sample_strategy,
                     })
                 }
                 FerromagneticPermeabilityDeEnum::MagnetizationCurve(val) => {
@@ -950,8 +3854,12 @@ mod tests {
         .map(MagneticFluxDensity::new::<tesla>)
         .collect();
 
-        let (h, b) =
-            sample_bh_curve(field_strength.as_slice(), flux_density.as_slice(), 0.02).unwrap();
+        let (h, b) = sample_bh_curve(
+            field_strength.as_slice(),
+            flux_density.as_slice(),
+            &SampleStrategy::default(),
+        )
+        .unwrap();
 
         let len = 300;
         assert_eq!(h.len(), len);
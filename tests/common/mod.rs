@@ -0,0 +1,29 @@
+use stem_material::prelude::*;
+
+/**
+Creates an [`IronLossData`] dataset from the specific losses returned by
+`losses` at the given `frequencies` (in Hz) for flux densities from 0.5 T to
+1.5 T.
+ */
+pub fn generate_data(
+    losses: impl Fn(MagneticFluxDensity, Frequency) -> SpecificPower,
+    frequencies: &[f64],
+) -> IronLossData {
+    let flux_densities: Vec<MagneticFluxDensity> = vec![0.5, 0.7, 0.9, 1.1, 1.3, 1.5]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+
+    let characteristics = frequencies
+        .iter()
+        .map(|f| {
+            let frequency = Frequency::new::<hertz>(*f);
+            let specific_losses: Vec<SpecificPower> = flux_densities
+                .iter()
+                .map(|b| losses(*b, frequency))
+                .collect();
+            IronLossCharacteristic::from_vecs(frequency, &flux_densities, &specific_losses)
+        })
+        .collect();
+    return IronLossData(characteristics);
+}
@@ -339,6 +339,50 @@ fn test_monotonic_decreasing() {
     }
 }
 
+#[test]
+fn test_permeability_derivative() {
+    let field_strength: Vec<MagneticFieldStrength> = vec![
+        0.0, 11.57, 22.11, 31.71, 40.47, 48.50, 55.29, 64.02, 75.66, 89.24, 107.67, 134.83, 179.45,
+        276.45, 582.98, 1583.11, 3578.65, 6665.91, 11303.32, 18871.00, 29765.16, 45905.16,
+        69372.42, 102918.79, 150142.01, 215692.99, 219224.15,
+    ]
+    .into_iter()
+    .map(MagneticFieldStrength::new::<ampere_per_meter>)
+    .collect();
+    let flux_density: Vec<MagneticFluxDensity> = vec![
+        0.0, 0.0970, 0.1940, 0.2910, 0.3880, 0.4851, 0.5821, 0.6791, 0.7761, 0.8731, 0.9701,
+        1.0672, 1.1642, 1.2614, 1.3588, 1.4571, 1.5566, 1.6576, 1.7606, 1.8674, 1.9674, 2.0674,
+        2.1674, 2.2674, 2.3674, 2.4674, 2.4720,
+    ]
+    .into_iter()
+    .map(MagneticFluxDensity::new::<tesla>)
+    .collect();
+
+    let fp = FerromagneticPermeability::from_magnetization(
+        MagnetizationCurve::new(field_strength, flux_density, 1.0).unwrap(),
+    )
+    .unwrap();
+
+    // Compare against a coarse secant of the spline
+    for idx in 1..20 {
+        let b = idx as f64 / 10.0;
+        let secant = (fp.from_flux_density.eval_infallible(b + 1e-3)
+            - fp.from_flux_density.eval_infallible(b - 1e-3))
+            / 2e-3;
+        let derivative = fp.dmu_r_dB(MagneticFluxDensity::new::<tesla>(b));
+        assert!(derivative <= 0.0);
+        approx::assert_abs_diff_eq!(derivative, secant, epsilon = 1e-2 * secant.abs().max(1.0));
+
+        let h = idx as f64 * 1000.0;
+        let secant = (fp.from_field_strength.eval_infallible(h + 1e-1)
+            - fp.from_field_strength.eval_infallible(h - 1e-1))
+            / 2e-1;
+        let derivative = fp.dmu_r_dH(MagneticFieldStrength::new::<ampere_per_meter>(h));
+        assert!(derivative <= 0.0);
+        approx::assert_abs_diff_eq!(derivative, secant, epsilon = 1e-2 * secant.abs().max(1e-3));
+    }
+}
+
 #[test]
 fn test_bh_curve_reconstruction() {
     let field_strength: Vec<MagneticFieldStrength> = vec![
@@ -515,3 +559,249 @@ fn test_permeability_curve_with_iron_fill_factor() {
         epsilon = 0.001
     );
 }
+
+#[test]
+fn test_mu_r_table_derivative() {
+    // µr(B) is linear between the support points with a slope of -2500 1/T.
+    // Since the spline merges smoothly into the extrapolation on both sides, the
+    // slope is only exact in the inner segments.
+    let fp = FerromagneticPermeability::from_mu_r_table(
+        [0.4, 0.8, 1.2, 1.6, 2.0]
+            .into_iter()
+            .map(MagneticFluxDensity::new::<tesla>)
+            .collect(),
+        vec![5000.0, 4000.0, 3000.0, 2000.0, 1000.0],
+        1.0,
+    )
+    .unwrap();
+
+    for b in [0.9, 1.0, 1.1, 1.2] {
+        approx::assert_abs_diff_eq!(
+            fp.dmu_r_dB(MagneticFluxDensity::new::<tesla>(b)),
+            -2500.0,
+            epsilon = 1e-6
+        );
+    }
+
+    // Both splines are extrapolated with a horizontal line to the left
+    assert_eq!(fp.dmu_r_dB(MagneticFluxDensity::new::<tesla>(0.1)), 0.0);
+    assert_eq!(
+        fp.dmu_r_dH(MagneticFieldStrength::new::<ampere_per_meter>(10.0)),
+        0.0
+    );
+
+    // To the right, the splines are extrapolated linearly from the last support
+    // point (2 T, µr = 1000) to µr = 1 at 100 T
+    let slope_b = (1.0 - 1000.0) / (100.0 - 2.0);
+    approx::assert_abs_diff_eq!(
+        fp.dmu_r_dB(MagneticFluxDensity::new::<tesla>(2.5)),
+        slope_b,
+        epsilon = 1e-9
+    );
+    approx::assert_abs_diff_eq!(
+        fp.dmu_r_dB(MagneticFluxDensity::new::<tesla>(50.0)),
+        slope_b,
+        epsilon = 1e-9
+    );
+
+    let h_last = 2.0 / (VACUUM_PERMEABILITY_UNITLESS * 1000.0);
+    let h_saturation = 100.0 / VACUUM_PERMEABILITY_UNITLESS;
+    let slope_h = (1.0 - 1000.0) / (h_saturation - h_last);
+    approx::assert_abs_diff_eq!(
+        fp.dmu_r_dH(MagneticFieldStrength::new::<ampere_per_meter>(2.0 * h_last)),
+        slope_h,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_invalid_input_data_message() {
+    use std::error::Error;
+
+    let error = MagnetizationCurve::new(
+        vec![MagneticFieldStrength::new::<ampere_per_meter>(100.0)],
+        vec![MagneticFluxDensity::new::<tesla>(0.5)],
+        1.5,
+    )
+    .unwrap_err();
+    assert_eq!(error.variant_name(), "IronFillFactor");
+    assert!(error.to_string().starts_with("IronFillFactor: "));
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_cubic_spline_permeability() {
+    let field_strength: Vec<_> = vec![
+        0.0, 11.57, 22.11, 31.71, 40.47, 48.50, 55.29, 64.02, 75.66, 89.24, 107.67, 134.83, 179.45,
+        276.45, 582.98, 1583.11, 3578.65, 6665.91, 11303.32, 18871.00, 29765.16, 45905.16,
+        69372.42, 102918.79, 150142.01, 215692.99, 219224.15,
+    ]
+    .into_iter()
+    .map(MagneticFieldStrength::new::<ampere_per_meter>)
+    .collect();
+    let flux_density: Vec<_> = vec![
+        0.0, 0.0970, 0.1940, 0.2910, 0.3880, 0.4851, 0.5821, 0.6791, 0.7761, 0.8731, 0.9701,
+        1.0672, 1.1642, 1.2614, 1.3588, 1.4571, 1.5566, 1.6576, 1.7606, 1.8674, 1.9674, 2.0674,
+        2.1674, 2.2674, 2.3674, 2.4674, 2.4720,
+    ]
+    .into_iter()
+    .map(MagneticFluxDensity::new::<tesla>)
+    .collect();
+    let curve = MagnetizationCurve::new(field_strength, flux_density, 1.0).unwrap();
+
+    let akima = FerromagneticPermeability::from_magnetization(curve.clone()).unwrap();
+    let cubic = CubicSplinePermeability::from_magnetization(curve).unwrap();
+
+    // Within the support points, both spline types should agree closely
+    for b in [0.5, 0.9, 1.0, 1.5, 2.0] {
+        let b = MagneticFluxDensity::new::<tesla>(b);
+        let mu_r_akima = akima.mu_r_from_b(b);
+        let mu_r_cubic = cubic.mu_r_from_b(b);
+        assert!((mu_r_akima - mu_r_cubic).abs() / mu_r_akima < 0.01);
+    }
+
+    // The extrapolation is identical
+    for b in [10.0, 90.0] {
+        approx::assert_abs_diff_eq!(
+            akima.mu_r_from_b(MagneticFluxDensity::new::<tesla>(b)),
+            cubic.mu_r_from_b(MagneticFluxDensity::new::<tesla>(b)),
+            epsilon = 1e-6
+        );
+    }
+    approx::assert_abs_diff_eq!(
+        cubic.mu_r_from_b(MagneticFluxDensity::new::<tesla>(1000.0)),
+        1.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_permeability_model_matches_call() {
+    let field_strength: Vec<_> = vec![0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+        .into_iter()
+        .map(MagneticFieldStrength::new::<ampere_per_meter>)
+        .collect();
+    let flux_density: Vec<_> = vec![0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let permeability = FerromagneticPermeability::from_magnetization(
+        MagnetizationCurve::new(field_strength, flux_density, 1.0).unwrap(),
+    )
+    .unwrap();
+
+    // Negative values and values far beyond the last spline knot are treated
+    // the same way as in the `IsQuantityFunction` implementation
+    for b in [-1.5, -0.5, 0.0, 1.5, 10.0, 1000.0] {
+        let b = MagneticFluxDensity::new::<tesla>(b);
+        let mu_r = permeability.mu_r_from_b(b);
+        assert_eq!(mu_r, permeability.call(&[b.into()]).value);
+        assert!(mu_r >= 1.0);
+    }
+    for h in [-2000.0, -100.0, 0.0, 100.0, 1e5, 1e9] {
+        let h = MagneticFieldStrength::new::<ampere_per_meter>(h);
+        let mu_r = permeability.mu_r_from_h(h);
+        assert_eq!(mu_r, permeability.call(&[h.into()]).value);
+        assert!(mu_r >= 1.0);
+    }
+}
+
+#[test]
+fn test_relative_permeability_get_at_matches_get() {
+    let field_strength: Vec<_> = vec![0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+        .into_iter()
+        .map(MagneticFieldStrength::new::<ampere_per_meter>)
+        .collect();
+    let flux_density: Vec<_> = vec![0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let curve = MagnetizationCurve::new(field_strength, flux_density, 1.0).unwrap();
+    let models = [
+        RelativePermeability::FerromagneticPermeability(
+            FerromagneticPermeability::from_magnetization(curve.clone()).unwrap(),
+        ),
+        RelativePermeability::CubicSplinePermeability(
+            CubicSplinePermeability::from_magnetization(curve).unwrap(),
+        ),
+    ];
+
+    // Includes negative values and values beyond the last spline knot
+    for model in models.iter() {
+        for b in [-1.5, -0.5, 0.0, 1.5, 1.8, 10.0, 1000.0] {
+            let b = MagneticFluxDensity::new::<tesla>(b);
+            assert_eq!(model.get_at_b(b), model.get(&[b.into()]));
+        }
+        for h in [-2000.0, -100.0, 0.0, 100.0, 10000.0, 1e5, 1e9] {
+            let h = MagneticFieldStrength::new::<ampere_per_meter>(h);
+            assert_eq!(model.get_at_h(h), model.get(&[h.into()]));
+        }
+    }
+}
+
+#[test]
+fn test_smoothed_retains_monotonicity() {
+    let h = |v| MagneticFieldStrength::new::<ampere_per_meter>(v);
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+
+    // Smoothing this curve with a large window breaks the monotonicity, hence
+    // the window is shrunk
+    let curve = MagnetizationCurve::new(
+        vec![
+            h(0.0),
+            h(10.0),
+            h(20.0),
+            h(30.0),
+            h(5000.0),
+            h(5001.0),
+            h(5002.0),
+        ],
+        vec![b(0.0), b(0.1), b(0.2), b(1.5), b(1.6), b(1.61), b(1.62)],
+        1.0,
+    )
+    .unwrap();
+    for factor in 0..=10 {
+        let smoothed = curve.smoothed(factor as f64 / 10.0);
+        assert!(smoothed.validate_monotonicity().is_ok());
+        assert_eq!(smoothed.field_strength(), curve.field_strength());
+    }
+    assert_ne!(curve.smoothed(1.0), curve);
+}
+
+#[test]
+#[should_panic]
+fn test_scale_flux_density_non_positive_factor() {
+    let curve = MagnetizationCurve::new(
+        vec![
+            MagneticFieldStrength::new::<ampere_per_meter>(0.0),
+            MagneticFieldStrength::new::<ampere_per_meter>(100.0),
+        ],
+        vec![
+            MagneticFluxDensity::new::<tesla>(0.0),
+            MagneticFluxDensity::new::<tesla>(1.0),
+        ],
+        1.0,
+    )
+    .unwrap();
+    curve.scale_flux_density(-1.0);
+}
+
+#[test]
+fn test_eq_within_tolerance() {
+    let flux_density: Vec<MagneticFluxDensity> = [0.5, 1.0, 1.5, 1.8, 2.0]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let permeability = |first_mu_r: f64| {
+        FerromagneticPermeability::from_mu_r_table(
+            flux_density.clone(),
+            vec![first_mu_r, 4000.0, 1500.0, 500.0, 100.0],
+            1.0,
+        )
+        .unwrap()
+    };
+
+    let reference = permeability(5000.0);
+    assert_eq!(reference, permeability(5000.0 * (1.0 + 1e-14)));
+    assert_ne!(reference, permeability(5000.0 * (1.0 + 1e-6)));
+}
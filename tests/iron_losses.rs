@@ -0,0 +1,40 @@
+use stem_material::prelude::*;
+
+#[test]
+fn test_add_constant() {
+    let first = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(1.0));
+    let other = IronLosses::Constant(SpecificPower::new::<watt_per_kilogram>(2.5));
+    match first + other {
+        IronLosses::Constant(value) => {
+            approx::assert_abs_diff_eq!(value.get::<watt_per_kilogram>(), 3.5, epsilon = 1e-12)
+        }
+        _ => panic!("sum of two constants must be a constant"),
+    }
+}
+
+#[test]
+fn test_add_jordan_models() {
+    let first = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.0),
+        SpecificPower::new::<watt_per_kilogram>(0.5),
+    );
+    let other = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.1),
+    );
+    let sum = IronLosses::JordanModel(first.clone()) + IronLosses::JordanModel(other.clone());
+    assert!(sum.function().is_some());
+
+    for (b, f) in [(0.5, 50.0), (1.0, 100.0), (1.5, 400.0)] {
+        let flux_density = MagneticFluxDensity::new::<tesla>(b);
+        let frequency = Frequency::new::<hertz>(f);
+        let expected =
+            first.losses(flux_density, frequency) + other.losses(flux_density, frequency);
+        approx::assert_abs_diff_eq!(
+            sum.get(&[flux_density.into(), frequency.into()])
+                .get::<watt_per_kilogram>(),
+            expected.get::<watt_per_kilogram>(),
+            epsilon = 1e-9
+        );
+    }
+}
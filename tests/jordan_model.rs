@@ -0,0 +1,129 @@
+mod common;
+
+use common::generate_data;
+use stem_material::prelude::*;
+
+#[test]
+fn test_r_squared() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+    );
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0]);
+
+    // Exact model
+    approx::assert_abs_diff_eq!(model.r_squared(&data), 1.0, epsilon = 1e-12);
+
+    // Fitted model
+    let fitted = JordanModel::try_from(&data).unwrap();
+    approx::assert_abs_diff_eq!(fitted.r_squared(&data), 1.0, epsilon = 1e-4);
+
+    // A bad model results in a smaller value
+    let bad_model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+    );
+    assert!(bad_model.r_squared(&data) < 0.9);
+}
+
+#[test]
+fn test_iron_loss_data_eq() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+    );
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0]);
+    assert_eq!(data, data.clone());
+
+    let mut other = data.clone();
+    other.0[0].characteristic[0].specific_loss = SpecificPower::new::<watt_per_kilogram>(100.0);
+    assert_ne!(data, other);
+    assert_ne!(data.0[0], other.0[0]);
+    assert_eq!(data.0[1], other.0[1]);
+}
+
+#[test]
+fn test_cross_validate() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+    );
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0]);
+
+    // Data generated from an exact model is predicted well from any subset
+    let errors = data.cross_validate().unwrap();
+    assert_eq!(errors.len(), 3);
+    for ((frequency, rms_error), characteristic) in errors.iter().zip(data.0.iter()) {
+        assert_eq!(*frequency, characteristic.frequency);
+        assert!(*rms_error < 0.1, "rms error {rms_error} at {frequency:?}");
+    }
+
+    // Leaving one out of two characteristics is not possible
+    let data = IronLossData(data.0[..2].to_vec());
+    assert!(matches!(
+        data.cross_validate(),
+        Err(FailedCoefficientCalculation::InsufficientData {
+            characteristics_provided: 2,
+            minimum_required: 3
+        })
+    ));
+}
+
+#[test]
+fn test_loss_fractions() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+    );
+    for b in [0.0, 0.1, 0.5, 1.0, 1.5, 2.0] {
+        for f in [0.0, 1.0, 50.0, 60.0, 400.0, 1000.0, 20000.0] {
+            let flux_density = MagneticFluxDensity::new::<tesla>(b);
+            let frequency = Frequency::new::<hertz>(f);
+            let hysteresis = model.hysteresis_loss_fraction(flux_density, frequency);
+            let eddy_current = model.eddy_current_loss_fraction(flux_density, frequency);
+            assert!((0.0..=1.0).contains(&hysteresis));
+            assert_eq!(hysteresis + eddy_current, 1.0);
+            if b == 0.0 || f == 0.0 {
+                assert_eq!(hysteresis, 0.5);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_single_characteristic() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(2.0),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+    );
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0]);
+
+    // Both estimates reproduce the losses at the frequency of the characteristic
+    for characteristic in data.0.iter() {
+        for assume_hysteresis_dominant in [true, false] {
+            let estimate =
+                JordanModel::from_single_characteristic(characteristic, assume_hysteresis_dominant);
+            for pair in characteristic.characteristic.iter() {
+                approx::assert_abs_diff_eq!(
+                    estimate
+                        .losses(pair.flux_density, characteristic.frequency)
+                        .get::<watt_per_kilogram>(),
+                    pair.specific_loss.get::<watt_per_kilogram>(),
+                    epsilon = 1e-9
+                );
+            }
+        }
+    }
+
+    // At 50 Hz, the hysteresis-dominant estimate attributes all losses to kh
+    let estimate = JordanModel::from_single_characteristic(&data.0[0], true);
+    approx::assert_abs_diff_eq!(
+        estimate.hysteresis_coefficient.get::<watt_per_kilogram>(),
+        2.6,
+        epsilon = 1e-9
+    );
+    assert_eq!(
+        estimate.eddy_current_coefficient.get::<watt_per_kilogram>(),
+        0.0
+    );
+}
@@ -24,3 +24,168 @@ fn test_eq() {
     assert_ne!(material, second_material);
     assert_ne!(&material, &second_material);
 }
+
+#[test]
+fn test_eq_function_variants() {
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+        ],
+        1.0,
+    )
+    .unwrap();
+
+    let ferromagnetic = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(
+            permeability.clone(),
+        ))
+        .build();
+    assert_eq!(ferromagnetic, ferromagnetic.clone());
+
+    // Materials with a function as relative permeability are never equal
+    let function = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::Function(
+            QuantityFunction::new(Box::new(permeability)).unwrap(),
+        ))
+        .build();
+    assert_ne!(function, function.clone());
+    assert_ne!(function, ferromagnetic);
+}
+
+#[test]
+fn test_compute_field_strength_and_flux_density() {
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        0.95,
+    )
+    .unwrap();
+    let ferromagnetic = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(
+            permeability.clone(),
+        ))
+        .build();
+    let function = MaterialBuilder::new()
+        .relative_permeability(RelativePermeability::Function(
+            QuantityFunction::new(Box::new(permeability)).unwrap(),
+        ))
+        .build();
+    let constant = MaterialBuilder::new().relative_permeability(1000.0).build();
+
+    for b in [-1.5, 0.1, 0.8, 1.5, 2.5] {
+        let b = MagneticFluxDensity::new::<tesla>(b);
+
+        let h = ferromagnetic.compute_field_strength(b, &[]).unwrap();
+        approx::assert_abs_diff_eq!(
+            h.get::<ampere_per_meter>(),
+            function
+                .compute_field_strength(b, &[])
+                .unwrap()
+                .get::<ampere_per_meter>(),
+            epsilon = 1e-6
+        );
+        approx::assert_abs_diff_eq!(
+            ferromagnetic
+                .compute_flux_density(h, &[], 1e-12, 50)
+                .unwrap()
+                .get::<tesla>(),
+            b.get::<tesla>(),
+            epsilon = 1e-6
+        );
+
+        let h = constant.compute_field_strength(b, &[]).unwrap();
+        approx::assert_abs_diff_eq!(
+            constant
+                .compute_flux_density(h, &[], 1e-12, 0)
+                .unwrap()
+                .get::<tesla>(),
+            b.get::<tesla>(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_material_hash() {
+    let mut material = Material::default();
+    let linear = Linear::new(
+        DynQuantity::new(
+            2.0,
+            Unit::from(PredefUnit::MagneticFluxDensity) / Unit::from(PredefUnit::Temperature),
+        ),
+        DynQuantity::new(1.0, PredefUnit::MagneticFluxDensity),
+    );
+    material.set_remanence(VarQuantity::try_from_quantity_function(linear).unwrap());
+    assert_eq!(material.material_hash(), material.clone().material_hash());
+
+    // Constant properties are part of the fingerprint
+    let mut other = material.clone();
+    other.set_mass_density(VarQuantity::Constant(MassDensity::new::<
+        kilogram_per_cubic_meter,
+    >(7650.0)));
+    assert_ne!(material.material_hash(), other.material_hash());
+
+    // Replacing a function by a constant changes the fingerprint
+    let mut other = material.clone();
+    other.set_remanence(VarQuantity::Constant(MagneticFluxDensity::new::<tesla>(
+        1.0,
+    )));
+    assert_ne!(material.material_hash(), other.material_hash());
+
+    // Optional properties
+    let mut other = material.clone();
+    other.curie_temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(770.0));
+    assert_ne!(material.material_hash(), other.material_hash());
+
+    // The parameters of the models are part of the fingerprint
+    let jordan = |hysteresis_coefficient: f64| {
+        IronLosses::JordanModel(JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(hysteresis_coefficient),
+            SpecificPower::new::<watt_per_kilogram>(0.5),
+        ))
+    };
+    let mut first_model = material.clone();
+    first_model.set_iron_losses(jordan(1.0));
+    let mut second_model = material.clone();
+    second_model.set_iron_losses(jordan(1.1));
+    assert_ne!(first_model.material_hash(), second_model.material_hash());
+    second_model.set_iron_losses(jordan(1.0));
+    assert_eq!(first_model.material_hash(), second_model.material_hash());
+
+    let permeability = |max_flux_density: f64| {
+        RelativePermeability::FerromagneticPermeability(
+            FerromagneticPermeability::from_bh_pairs_si(
+                &[
+                    (0.0, 0.0),
+                    (50.0, 0.6),
+                    (100.0, 1.0),
+                    (200.0, 1.2),
+                    (500.0, 1.4),
+                    (1000.0, 1.5),
+                    (5000.0, 1.7),
+                    (10000.0, max_flux_density),
+                ],
+                1.0,
+            )
+            .unwrap(),
+        )
+    };
+    first_model.set_relative_permeability(permeability(1.8));
+    second_model.set_relative_permeability(permeability(1.9));
+    assert_ne!(first_model.material_hash(), second_model.material_hash());
+    second_model.set_relative_permeability(permeability(1.8));
+    assert_eq!(first_model.material_hash(), second_model.material_hash());
+}
@@ -59,6 +59,63 @@ fn test_serialize_and_deserialize_relative_permeability() {
     );
 }
 
+#[test]
+fn test_serialize_and_deserialize_original_curve() {
+    let field_strength: Vec<_> = vec![0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+        .into_iter()
+        .map(MagneticFieldStrength::new::<ampere_per_meter>)
+        .collect();
+    let flux_density: Vec<_> = vec![0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+        .into_iter()
+        .map(MagneticFluxDensity::new::<tesla>)
+        .collect();
+    let curve = MagnetizationCurve::new(field_strength.clone(), flux_density.clone(), 1.0).unwrap();
+    let permeability = FerromagneticPermeability::from_magnetization(curve.clone()).unwrap();
+    assert_eq!(permeability.original_curve(), Some(&curve));
+
+    let serialized = serde_yaml::to_string(&permeability).unwrap();
+    assert!(!serialized.contains("sample_strategy"));
+    let de_permeability: FerromagneticPermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(de_permeability.original_curve(), Some(&curve));
+
+    // Splines without original curve
+    let from_splines = FerromagneticPermeability::from_splines(
+        permeability.from_field_strength.clone(),
+        permeability.from_flux_density.clone(),
+    );
+    assert_eq!(from_splines, permeability);
+    let serialized = serde_yaml::to_string(&from_splines).unwrap();
+    assert!(!serialized.contains("original_curve"));
+    let de_permeability: FerromagneticPermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert!(de_permeability.original_curve().is_none());
+    assert!(de_permeability.change_iron_fill_factor(0.95).is_err());
+
+    // A custom sample strategy is kept alongside the original curve
+    let strategy = SampleStrategy {
+        step_width: MagneticFieldStrength::new::<ampere_per_meter>(5.0),
+        change_tolerance: 0.01,
+    };
+    let permeability =
+        FerromagneticPermeability::from_magnetization_with_strategy(curve, strategy).unwrap();
+    let expected = FerromagneticPermeability::from_magnetization_with_strategy(
+        MagnetizationCurve::new(field_strength, flux_density, 0.95).unwrap(),
+        strategy,
+    )
+    .unwrap();
+    assert_eq!(
+        permeability.change_iron_fill_factor(0.95).unwrap(),
+        expected
+    );
+
+    let serialized = serde_yaml::to_string(&permeability).unwrap();
+    assert!(serialized.contains("sample_strategy"));
+    let de_permeability: FerromagneticPermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(
+        de_permeability.change_iron_fill_factor(0.95).unwrap(),
+        expected
+    );
+}
+
 #[test]
 fn test_deserialize_relative_permeability_from_raw_data() {
     let serialized = indoc! {"
@@ -92,3 +149,199 @@ fn test_deserialize_relative_permeability_from_raw_data() {
         epsilon = 0.001
     );
 }
+
+#[test]
+fn test_check_deserialized_magnetization_curve() {
+    // Deserialization does not validate the datapoints
+    let serialized = indoc! {"
+    field_strength: '[0.0, 100.0, 200.0] A/m'
+    flux_density: '[0.0, 1.0, 0.9] T'
+    iron_fill_factor: 1.0
+    "};
+    let curve: MagnetizationCurve = serde_yaml::from_str(serialized).unwrap();
+    assert!(matches!(
+        curve.check_strictly_increasing(),
+        Err(InvalidInputData::NonStrictlyIncreasingFluxDensity { index: 2 })
+    ));
+}
+
+#[test]
+fn test_deserialize_relative_permeability_from_polarization_curve() {
+    let serialized = indoc! {"
+    field_strength: '[0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0] A/m'
+    polarization: '[0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8] T'
+    iron_fill_factor: 0.95
+    "};
+    let de_permeability: FerromagneticPermeability = serde_yaml::from_str(serialized).unwrap();
+
+    let curve = PolarizationCurve::new(
+        vec![0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+            .into_iter()
+            .map(MagneticFieldStrength::new::<ampere_per_meter>)
+            .collect(),
+        vec![0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+            .into_iter()
+            .map(MagneticFluxDensity::new::<tesla>)
+            .collect(),
+        0.95,
+    )
+    .unwrap();
+    let permeability = FerromagneticPermeability::from_polarization(curve).unwrap();
+
+    assert_eq!(permeability, de_permeability);
+    for b in [0.1, 0.5, 1.0, 1.5, 2.0, 10.0] {
+        let conditions = &[MagneticFluxDensity::new::<tesla>(b).into()];
+        approx::assert_abs_diff_eq!(
+            permeability.call(conditions).value,
+            de_permeability.call(conditions).value,
+            epsilon = 1e-9
+        );
+    }
+    for h in [10.0, 500.0, 5000.0, 50000.0] {
+        let conditions = &[MagneticFieldStrength::new::<ampere_per_meter>(h).into()];
+        approx::assert_abs_diff_eq!(
+            permeability.call(conditions).value,
+            de_permeability.call(conditions).value,
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn test_serialize_and_deserialize_anisotropic_permeability() {
+    let d_axis = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let q_axis = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (100.0, 0.6),
+            (200.0, 1.0),
+            (400.0, 1.2),
+            (1000.0, 1.4),
+            (2000.0, 1.5),
+            (10000.0, 1.7),
+            (20000.0, 1.8),
+        ],
+        1.0,
+    )
+    .unwrap();
+    let permeability = AnisotropicFerromagneticPermeability::new(d_axis, q_axis);
+
+    let serialized = serde_yaml::to_string(&permeability).unwrap();
+    let de_permeability: AnisotropicFerromagneticPermeability =
+        serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+
+    // Usage as trait object within a RelativePermeability
+    let relative_permeability =
+        RelativePermeability::Function(QuantityFunction::new(Box::new(permeability)).unwrap());
+    let serialized = serde_yaml::to_string(&relative_permeability).unwrap();
+    let de_relative_permeability: RelativePermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(relative_permeability, de_relative_permeability);
+}
+
+#[test]
+fn test_deserialize_natural_cubic_spline() {
+    let spline = NaturalCubicSpline::new(
+        vec![0.0, 1.0, 2.0, 3.0],
+        vec![1000.0, 800.0, 300.0, 100.0],
+        0.0,
+        -200.0,
+    )
+    .unwrap();
+
+    // The second derivatives are recalculated on deserialization
+    let serialized = serde_yaml::to_string(&spline).unwrap();
+    assert!(!serialized.contains("second_derivatives"));
+    let de_spline: NaturalCubicSpline = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(spline, de_spline);
+
+    // Invalid support points are rejected
+    let empty = "x: []\ny: []\nslope_left: 0.0\nslope_right: 0.0";
+    assert!(serde_yaml::from_str::<NaturalCubicSpline>(empty).is_err());
+    let not_increasing =
+        "x: [0.0, 2.0, 1.0]\ny: [1.0, 2.0, 3.0]\nslope_left: 0.0\nslope_right: 0.0";
+    assert!(serde_yaml::from_str::<NaturalCubicSpline>(not_increasing).is_err());
+}
+
+#[test]
+fn test_serialize_and_deserialize_cubic_spline_permeability() {
+    let curve = MagnetizationCurve::new(
+        vec![0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0]
+            .into_iter()
+            .map(MagneticFieldStrength::new::<ampere_per_meter>)
+            .collect(),
+        vec![0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8]
+            .into_iter()
+            .map(MagneticFluxDensity::new::<tesla>)
+            .collect(),
+        1.0,
+    )
+    .unwrap();
+    let relative_permeability = RelativePermeability::CubicSplinePermeability(
+        CubicSplinePermeability::from_magnetization(curve).unwrap(),
+    );
+
+    let serialized = serde_yaml::to_string(&relative_permeability).unwrap();
+    let de_relative_permeability: RelativePermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(relative_permeability, de_relative_permeability);
+}
+
+#[test]
+fn test_serialize_and_deserialize_spline_fidelity() {
+    let field_strength: Vec<_> = vec![
+        0.0, 11.57, 22.11, 31.71, 40.47, 48.50, 55.29, 64.02, 75.66, 89.24, 107.67, 134.83, 179.45,
+        276.45, 582.98, 1583.11, 3578.65, 6665.91, 11303.32, 18871.00, 29765.16, 45905.16,
+        69372.42, 102918.79, 150142.01, 215692.99, 219224.15,
+    ]
+    .into_iter()
+    .map(MagneticFieldStrength::new::<ampere_per_meter>)
+    .collect();
+    let flux_density: Vec<_> = vec![
+        0.0, 0.0970, 0.1940, 0.2910, 0.3880, 0.4851, 0.5821, 0.6791, 0.7761, 0.8731, 0.9701,
+        1.0672, 1.1642, 1.2614, 1.3588, 1.4571, 1.5566, 1.6576, 1.7606, 1.8674, 1.9674, 2.0674,
+        2.1674, 2.2674, 2.3674, 2.4674, 2.4720,
+    ]
+    .into_iter()
+    .map(MagneticFluxDensity::new::<tesla>)
+    .collect();
+    let permeability = FerromagneticPermeability::from_magnetization(
+        MagnetizationCurve::new(field_strength, flux_density, 0.95).unwrap(),
+    )
+    .unwrap();
+
+    let serialized = serde_yaml::to_string(&permeability).unwrap();
+    let de_permeability: FerromagneticPermeability = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+
+    // Pseudo-random sample points in [0, 1) from a fixed-seed xorshift generator,
+    // so the test is reproducible without an additional dependency
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        return (state >> 11) as f64 / (1u64 << 53) as f64;
+    };
+
+    for _ in 0..1000 {
+        // Covers the negative range, the support points and the extrapolation
+        let b = MagneticFluxDensity::new::<tesla>(6.0 * next() - 3.0);
+        assert_eq!(permeability.get(b), de_permeability.get(b), "B = {b:?}");
+
+        let h = MagneticFieldStrength::new::<ampere_per_meter>(6e5 * next() - 3e5);
+        assert_eq!(permeability.get(h), de_permeability.get(h), "H = {h:?}");
+    }
+}
@@ -172,3 +172,19 @@ fn test_titan() {
         epsilon = 1e-6
     )
 }
+
+#[test]
+fn test_material_database() {
+    let mut database = MaterialDatabase::open("stem_test_database/src").unwrap();
+    assert!(database.list_materials().contains(&"Copper".to_string()));
+
+    let copper = database.read("Copper").unwrap();
+    let mut dbm = create_dbm();
+    let expected: Material = dbm.read("Copper").unwrap();
+    assert_eq!(copper, expected);
+
+    assert!(matches!(
+        database.read("Unobtainium"),
+        Err(DatabaseError::Read { .. })
+    ));
+}
@@ -0,0 +1,53 @@
+use stem_material::prelude::*;
+
+use crate::common::generate_data;
+
+#[test]
+fn test_serialize_and_deserialize_generalized_jordan_model() {
+    let model = GeneralizedJordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.2),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+        1.1,
+        1.8,
+    );
+
+    let serialized = serde_yaml::to_string(&model).unwrap();
+    let de_model: GeneralizedJordanModel = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(model, de_model);
+
+    let iron_losses = IronLosses::GeneralizedJordanModel(model);
+    let serialized = serde_yaml::to_string(&iron_losses).unwrap();
+    let de_iron_losses: IronLosses = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(iron_losses, de_iron_losses);
+}
+
+#[test]
+fn test_fit_generalized_jordan_model() {
+    let model = GeneralizedJordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(1.2),
+        SpecificPower::new::<watt_per_kilogram>(0.6),
+        1.1,
+        1.8,
+    );
+
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0, 400.0]);
+
+    let fitted = GeneralizedJordanModel::try_from(&data).unwrap();
+    approx::assert_abs_diff_eq!(
+        fitted.hysteresis_coefficient.get::<watt_per_kilogram>(),
+        1.2,
+        epsilon = 0.01
+    );
+    approx::assert_abs_diff_eq!(
+        fitted.eddy_current_coefficient.get::<watt_per_kilogram>(),
+        0.6,
+        epsilon = 0.01
+    );
+    approx::assert_abs_diff_eq!(fitted.hysteresis_frequency_exponent, 1.1, epsilon = 0.01);
+    approx::assert_abs_diff_eq!(fitted.eddy_current_frequency_exponent, 1.8, epsilon = 0.01);
+    approx::assert_abs_diff_eq!(fitted.r_squared(&data), 1.0, epsilon = 1e-3);
+
+    // Two characteristics are not sufficient to determine both exponents
+    let data = IronLossData(data.0[..2].to_vec());
+    assert!(GeneralizedJordanModel::try_from(&data).is_err());
+}
@@ -266,3 +266,79 @@ fn test_deserialize_material_const_permeability() {
     let material: Material = serde_yaml::from_str(&serialized).unwrap();
     assert_eq!(material.relative_permeability().get(&[]), 42.0);
 }
+
+#[test]
+fn test_serialize_and_deserialize_temperature_scaled_jordan_model() {
+    let model = TemperatureScaledJordanModel {
+        base_model: JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(4.2),
+            SpecificPower::new::<watt_per_kilogram>(1.2),
+        ),
+        reference_temperature: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        hysteresis_temp_coefficient: -0.001,
+        eddy_current_temp_coefficient: -0.003,
+    };
+
+    let mut material = Material::default();
+    material.set_iron_losses(IronLosses::TemperatureScaledJordanModel(model));
+
+    let serialized = serde_yaml::to_string(&material).unwrap();
+    let de_material: Material = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(material.iron_losses(), de_material.iron_losses());
+
+    let conditions = &[
+        MagneticFluxDensity::new::<tesla>(1.0).into(),
+        Frequency::new::<hertz>(100.0).into(),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0).into(),
+    ];
+    approx::assert_abs_diff_eq!(
+        material
+            .iron_losses()
+            .get(conditions)
+            .get::<watt_per_kilogram>(),
+        de_material
+            .iron_losses()
+            .get(conditions)
+            .get::<watt_per_kilogram>(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_serialize_and_deserialize_iron_loss_data() {
+    let serialized = indoc! {"
+  - frequency: 50.0 Hz
+    characteristic:
+      - flux_density: 0.5 T
+        specific_loss: 0.86 W/kg
+      - flux_density: 1.0 T
+        specific_loss: 2.6 W/kg
+      - flux_density: 1.5 T
+        specific_loss: 5.52 W/kg
+  - frequency: 100.0 Hz
+    characteristic:
+      - flux_density: 0.5 T
+        specific_loss: 1.93 W/kg
+      - flux_density: 1.0 T
+        specific_loss: 6.19 W/kg
+      - flux_density: 1.5 T
+        specific_loss: 13.56 W/kg
+    "};
+    let data: IronLossData = serde_yaml::from_str(serialized).unwrap();
+    assert_eq!(data.0.len(), 2);
+    assert_eq!(data.0[1].frequency, Frequency::new::<hertz>(100.0));
+    assert_eq!(
+        data.0[1].characteristic[2].specific_loss,
+        SpecificPower::new::<watt_per_kilogram>(13.56)
+    );
+
+    // The serialized format is the raw data format, hence the round trip is lossless
+    let reserialized = serde_yaml::to_string(&data).unwrap();
+    let de_data: IronLossData = serde_yaml::from_str(&reserialized).unwrap();
+    assert_eq!(data, de_data);
+
+    // Consequently, the fitted models are identical as well
+    let model: JordanModel = data.try_into().unwrap();
+    let de_model: JordanModel = de_data.try_into().unwrap();
+    assert_eq!(model, de_model);
+}
@@ -0,0 +1,144 @@
+use stem_material::prelude::*;
+
+fn permeability() -> FerromagneticPermeability {
+    return FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        0.95,
+    )
+    .unwrap();
+}
+
+fn iron_loss_data() -> IronLossData {
+    let b = |v| MagneticFluxDensity::new::<tesla>(v);
+    let p = |v| SpecificPower::new::<watt_per_kilogram>(v);
+    return IronLossData(vec![
+        IronLossCharacteristic::from_vecs(
+            Frequency::new::<hertz>(50.0),
+            &[b(0.5), b(1.0), b(1.5)],
+            &[p(0.86), p(2.6), p(5.52)],
+        ),
+        IronLossCharacteristic::from_vecs(
+            Frequency::new::<hertz>(100.0),
+            &[b(0.5), b(1.0), b(1.5)],
+            &[p(1.93), p(6.19), p(13.56)],
+        ),
+    ]);
+}
+
+#[test]
+fn test_json_ferromagnetic_permeability() {
+    let permeability = permeability();
+    let serialized = serde_json::to_string(&permeability).unwrap();
+    let de_permeability: FerromagneticPermeability = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+
+    // Raw data representation
+    let serialized = r#"{
+        "field_strength": "[0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0] A/m",
+        "flux_density": "[0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8] T",
+        "iron_fill_factor": 0.95
+    }"#;
+    let de_permeability: FerromagneticPermeability = serde_json::from_str(serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+}
+
+#[test]
+fn test_json_jordan_model() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(4.2),
+        SpecificPower::new::<watt_per_kilogram>(1.2),
+    );
+    let serialized = serde_json::to_string(&model).unwrap();
+    let de_model: JordanModel = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(model, de_model);
+
+    // Deserialization from the raw data must match the direct fit
+    let data = iron_loss_data();
+    let serialized = serde_json::to_string(&data).unwrap();
+    let de_model: JordanModel = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(JordanModel::try_from(&data).unwrap(), de_model);
+}
+
+#[test]
+fn test_json_iron_loss_data() {
+    let data = iron_loss_data();
+    let serialized = serde_json::to_string(&data).unwrap();
+    let de_data: IronLossData = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(data, de_data);
+
+    let serialized = r#"[
+        {
+            "frequency": "50.0 Hz",
+            "characteristic": [
+                {"flux_density": "0.5 T", "specific_loss": "0.86 W/kg"},
+                {"flux_density": "1.0 T", "specific_loss": "2.6 W/kg"},
+                {"flux_density": "1.5 T", "specific_loss": "5.52 W/kg"}
+            ]
+        },
+        {
+            "frequency": "100.0 Hz",
+            "characteristic": [
+                {"flux_density": "0.5 T", "specific_loss": "1.93 W/kg"},
+                {"flux_density": "1.0 T", "specific_loss": "6.19 W/kg"},
+                {"flux_density": "1.5 T", "specific_loss": "13.56 W/kg"}
+            ]
+        }
+    ]"#;
+    let de_data: IronLossData = serde_json::from_str(serialized).unwrap();
+    assert_eq!(data.frequency_list(), de_data.frequency_list());
+    assert_eq!(
+        data.total_datapoint_count(),
+        de_data.total_datapoint_count()
+    );
+}
+
+#[test]
+fn test_json_material() {
+    let material = MaterialBuilder::new()
+        .name("M270-50A")
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(
+            permeability(),
+        ))
+        .iron_losses(IronLosses::JordanModel(
+            JordanModel::try_from(iron_loss_data()).unwrap(),
+        ))
+        .electrical_resistivity(VarQuantity::Constant(
+            ElectricalResistivity::new::<ohm_meter>(5.2e-7),
+        ))
+        .build();
+
+    let serialized = serde_json::to_string(&material).unwrap();
+    let de_material: Material = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+
+    // Untagged variants: A constant relative permeability and constant losses
+    let material = MaterialBuilder::new()
+        .name("Copper")
+        .relative_permeability(1.0)
+        .electrical_resistivity(VarQuantity::Constant(
+            ElectricalResistivity::new::<ohm_meter>(1.68e-8),
+        ))
+        .build();
+    let serialized = serde_json::to_string(&material).unwrap();
+    let de_material: Material = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+}
+
+#[test]
+fn test_json_material_with_infinite_values() {
+    // JSON does not support infinite numbers (the default electrical
+    // resistivity), hence the quantities need to be serialized with units.
+    let material = Material::default();
+    let serialized = serialize_with_units(|| serde_json::to_string(&material)).unwrap();
+    let de_material: Material = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+}
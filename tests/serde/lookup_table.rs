@@ -0,0 +1,44 @@
+use indoc::indoc;
+use stem_material::prelude::*;
+
+#[test]
+fn test_deserialize_lookup_table() {
+    let serialized = indoc! {"
+    name: Copper
+    electrical_resistivity:
+      LookupTable1D:
+        x: [20 °C, 120 °C]
+        y: [1 / 58 m/MS, 1 / 42 m/MS]
+    "};
+    let material: Material = serde_yaml::from_str(serialized).unwrap();
+
+    let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(20.0).into()];
+    approx::assert_abs_diff_eq!(
+        material
+            .electrical_resistivity()
+            .get(conditions)
+            .get::<ohm_meter>(),
+        1e-6 / 58.0,
+        epsilon = 1e-12
+    );
+
+    let conditions = &[ThermodynamicTemperature::new::<degree_celsius>(70.0).into()];
+    approx::assert_abs_diff_eq!(
+        material
+            .electrical_resistivity()
+            .get(conditions)
+            .get::<ohm_meter>(),
+        0.5e-6 * (1.0 / 58.0 + 1.0 / 42.0),
+        epsilon = 1e-12
+    );
+
+    // Invalid table: Input values are not strictly increasing
+    let serialized = indoc! {"
+    name: Copper
+    electrical_resistivity:
+      LookupTable1D:
+        x: [120 °C, 20 °C]
+        y: [1 / 58 m/MS, 1 / 42 m/MS]
+    "};
+    assert!(serde_yaml::from_str::<Material>(serialized).is_err());
+}
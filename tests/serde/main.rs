@@ -1,4 +1,13 @@
+#[path = "../common/mod.rs"]
+mod common;
+
 mod ferromagnetic_permeability;
 mod from_test_database;
+mod generalized_jordan_model;
 mod jordan_model;
+mod json;
+mod lookup_table;
 mod material;
+mod material_full_roundtrip;
+mod steinmetz_model;
+mod toml_format;
@@ -225,3 +225,122 @@ fn test_serialize_and_deserialize_with_units() {
     let de_material: Material = serde_yaml::from_str(&actual).expect("deserialization succeeds");
     assert_eq!(material, de_material);
 }
+
+#[test]
+fn test_deserialize_material_curie_and_annealing_temperature() {
+    let serialized = indoc! {"
+    ---
+    name: Iron
+    curie_temperature: 770 °C
+    annealing_temperature: 1023.15
+    "};
+    let material: Material = serde_yaml::from_str(serialized).unwrap();
+    approx::assert_abs_diff_eq!(
+        material
+            .curie_temperature()
+            .unwrap()
+            .get::<degree_celsius>(),
+        770.0,
+        epsilon = 1e-9
+    );
+    approx::assert_abs_diff_eq!(
+        material
+            .annealing_temperature()
+            .unwrap()
+            .get::<degree_celsius>(),
+        750.0,
+        epsilon = 1e-9
+    );
+
+    let serialized = serde_yaml::to_string(&material).unwrap();
+    let de_material: Material = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+
+    // Missing temperatures are not serialized
+    let serialized = serde_yaml::to_string(&Material::default()).unwrap();
+    assert!(!serialized.contains("curie_temperature"));
+}
+
+#[test]
+fn test_serialize_and_deserialize_temperature_dependent_remanence() {
+    let serialized = indoc! {"
+    ---
+    name: NdFeB
+    remanence:
+      TemperatureDependentRemanence:
+        remanence_at_reference: 1.2 T
+        reference_temperature: 20 °C
+        temperature_coefficient: -0.0012
+        relative_coefficient: true
+    "};
+    let material: Material = serde_yaml::from_str(serialized).unwrap();
+
+    let conditions = [ThermodynamicTemperature::new::<degree_celsius>(120.0).into()];
+    approx::assert_abs_diff_eq!(
+        material.remanence().get(&conditions).get::<tesla>(),
+        1.2 * 0.88,
+        epsilon = 1e-12
+    );
+
+    // Clamped at zero
+    let conditions = [ThermodynamicTemperature::new::<degree_celsius>(1000.0).into()];
+    assert_eq!(material.remanence().get(&conditions).get::<tesla>(), 0.0);
+
+    let serialized = serde_yaml::to_string(&material).unwrap();
+    let de_material: Material = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+}
+
+#[test]
+fn test_set_property_by_name() {
+    let mut material = Material::default();
+    material.set_property_by_name("name", "NdFeB").unwrap();
+    material
+        .set_property_by_name("relative_permeability", "1.05")
+        .unwrap();
+    material.set_property_by_name("remanence", "1.2 T").unwrap();
+    material
+        .set_property_by_name("mass_density", "7650 kg/m^3")
+        .unwrap();
+    material
+        .set_property_by_name("curie_temperature", "310 °C")
+        .unwrap();
+
+    assert_eq!(material.name(), "NdFeB");
+    assert_eq!(
+        material.relative_permeability,
+        RelativePermeability::Constant(1.05)
+    );
+    assert_eq!(
+        material.remanence,
+        VarQuantity::Constant(MagneticFluxDensity::new::<tesla>(1.2))
+    );
+    assert_eq!(
+        material.mass_density,
+        VarQuantity::Constant(MassDensity::new::<kilogram_per_cubic_meter>(7650.0))
+    );
+    approx::assert_abs_diff_eq!(
+        material.curie_temperature.unwrap().get::<degree_celsius>(),
+        310.0,
+        epsilon = 1e-9
+    );
+
+    // Errors leave the material unchanged
+    let unchanged = material.clone();
+    assert!(matches!(
+        material.set_property_by_name("colour", "red"),
+        Err(PropertySetError::UnknownProperty(_))
+    ));
+    assert!(matches!(
+        material.set_property_by_name("remanence", "1.2 W/kg"),
+        Err(PropertySetError::UnexpectedUnit {
+            property: "remanence",
+            ..
+        })
+    ));
+    assert!(matches!(
+        material.set_property_by_name("remanence", "not a quantity"),
+        Err(PropertySetError::InvalidQuantity(_))
+    ));
+    assert_eq!(material, unchanged);
+}
@@ -0,0 +1,167 @@
+use stem_material::prelude::*;
+
+fn fully_specified_material() -> Material {
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (130.0, 0.5),
+            (181.0, 0.9),
+            (304.0, 1.3),
+            (680.0, 1.5),
+            (3789.0, 1.7),
+            (13730.0, 1.9),
+        ],
+        0.95,
+    )
+    .unwrap();
+
+    return MaterialBuilder::new()
+        .name("Fully specified")
+        .relative_permeability(RelativePermeability::FerromagneticPermeability(
+            permeability,
+        ))
+        .iron_losses(IronLosses::JordanModel(JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(1.2),
+            SpecificPower::new::<watt_per_kilogram>(0.4),
+        )))
+        .remanence(VarQuantity::Constant(MagneticFluxDensity::new::<tesla>(
+            0.3,
+        )))
+        .intrinsic_coercivity(VarQuantity::Constant(MagneticFieldStrength::new::<
+            ampere_per_meter,
+        >(250.0)))
+        .temperature_dependent_resistivity(
+            ElectricalResistivity::new::<ohm_meter>(4.3e-7),
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            0.001,
+        )
+        .unwrap()
+        .mass_density(VarQuantity::Constant(MassDensity::new::<
+            kilogram_per_cubic_meter,
+        >(7650.0)))
+        .heat_capacity(VarQuantity::Constant(SpecificHeatCapacity::new::<
+            joule_per_kilogram_kelvin,
+        >(460.0)))
+        .thermal_conductivity(VarQuantity::Constant(ThermalConductivity::new::<
+            watt_per_meter_kelvin,
+        >(30.0)))
+        .curie_temperature(ThermodynamicTemperature::new::<degree_celsius>(740.0))
+        .annealing_temperature(ThermodynamicTemperature::new::<degree_celsius>(800.0))
+        .build();
+}
+
+fn condition_points() -> Vec<Vec<DynQuantity<f64>>> {
+    let mut points = Vec::new();
+    for temperature in [-40.0, 20.0, 150.0] {
+        for flux_density in [0.0, 0.7, 1.6, 2.2] {
+            for frequency in [50.0, 400.0] {
+                points.push(vec![
+                    ThermodynamicTemperature::new::<degree_celsius>(temperature).into(),
+                    MagneticFluxDensity::new::<tesla>(flux_density).into(),
+                    Frequency::new::<hertz>(frequency).into(),
+                ]);
+            }
+        }
+    }
+    return points;
+}
+
+fn assert_same_properties(expected: &Material, actual: &Material) {
+    assert_eq!(expected.name(), actual.name());
+    assert_eq!(expected.curie_temperature(), actual.curie_temperature());
+    assert_eq!(
+        expected.annealing_temperature(),
+        actual.annealing_temperature()
+    );
+
+    for conditions in condition_points() {
+        assert_eq!(
+            expected.relative_permeability().get(&conditions),
+            actual.relative_permeability().get(&conditions)
+        );
+        assert_eq!(
+            expected.iron_losses().get(&conditions),
+            actual.iron_losses().get(&conditions)
+        );
+        assert_eq!(
+            expected.remanence().get(&conditions),
+            actual.remanence().get(&conditions)
+        );
+        assert_eq!(
+            expected.intrinsic_coercivity().get(&conditions),
+            actual.intrinsic_coercivity().get(&conditions)
+        );
+        approx::assert_relative_eq!(
+            expected
+                .electrical_resistivity()
+                .get(&conditions)
+                .get::<ohm_meter>(),
+            actual
+                .electrical_resistivity()
+                .get(&conditions)
+                .get::<ohm_meter>(),
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            expected.mass_density().get(&conditions),
+            actual.mass_density().get(&conditions)
+        );
+        assert_eq!(
+            expected.heat_capacity().get(&conditions),
+            actual.heat_capacity().get(&conditions)
+        );
+        assert_eq!(
+            expected.thermal_conductivity().get(&conditions),
+            actual.thermal_conductivity().get(&conditions)
+        );
+    }
+}
+
+#[test]
+fn test_material_full_roundtrip() {
+    let material = fully_specified_material();
+
+    // Make sure that no field has its default value
+    let default = Material::default();
+    assert_ne!(
+        material.relative_permeability(),
+        default.relative_permeability()
+    );
+    assert_ne!(material.iron_losses(), default.iron_losses());
+    assert_ne!(material.remanence(), default.remanence());
+    assert_ne!(
+        material.intrinsic_coercivity(),
+        default.intrinsic_coercivity()
+    );
+    assert_ne!(
+        material.electrical_resistivity(),
+        default.electrical_resistivity()
+    );
+    assert_ne!(material.mass_density(), default.mass_density());
+    assert_ne!(material.heat_capacity(), default.heat_capacity());
+    assert_ne!(
+        material.thermal_conductivity(),
+        default.thermal_conductivity()
+    );
+    assert_ne!(material.curie_temperature(), default.curie_temperature());
+    assert_ne!(
+        material.annealing_temperature(),
+        default.annealing_temperature()
+    );
+
+    let serialized = serde_yaml::to_string(&material).unwrap();
+    let deserialized: Material = serde_yaml::from_str(&serialized).unwrap();
+    assert_same_properties(&material, &deserialized);
+
+    // A second round trip must not change the serialized representation
+    assert_eq!(serialized, serde_yaml::to_string(&deserialized).unwrap());
+}
+
+#[test]
+fn test_material_full_roundtrip_with_units() {
+    let material = fully_specified_material();
+
+    let serialized = serialize_with_units(|| serde_yaml::to_string(&material)).unwrap();
+    let deserialized: Material = serde_yaml::from_str(&serialized).unwrap();
+    assert_same_properties(&material, &deserialized);
+}
@@ -0,0 +1,34 @@
+use stem_material::prelude::*;
+
+use crate::common::generate_data;
+
+#[test]
+fn test_serialize_and_deserialize_steinmetz_model() {
+    let model = SteinmetzModel::new(SpecificPower::new::<watt_per_kilogram>(0.01), 1.4, 2.2);
+
+    let serialized = serde_yaml::to_string(&model).unwrap();
+    let de_model: SteinmetzModel = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(model, de_model);
+
+    let iron_losses = IronLosses::SteinmetzModel(model);
+    let serialized = serde_yaml::to_string(&iron_losses).unwrap();
+    let de_iron_losses: IronLosses = serde_yaml::from_str(&serialized).unwrap();
+    assert_eq!(iron_losses, de_iron_losses);
+}
+
+#[test]
+fn test_fit_steinmetz_model() {
+    let model = SteinmetzModel::new(SpecificPower::new::<watt_per_kilogram>(0.01), 1.4, 2.2);
+
+    let data = generate_data(|b, f| model.losses(b, f), &[50.0, 100.0, 200.0]);
+
+    let fitted = SteinmetzModel::try_from(&data).unwrap();
+    approx::assert_abs_diff_eq!(
+        fitted.coefficient.get::<watt_per_kilogram>(),
+        0.01,
+        epsilon = 1e-4
+    );
+    approx::assert_abs_diff_eq!(fitted.frequency_exponent, 1.4, epsilon = 0.01);
+    approx::assert_abs_diff_eq!(fitted.flux_density_exponent, 2.2, epsilon = 0.01);
+    approx::assert_abs_diff_eq!(fitted.r_squared(&data), 1.0, epsilon = 1e-4);
+}
@@ -0,0 +1,111 @@
+/*!
+TOML compatibility tests using the `toml` dev-dependency.
+
+Contrary to YAML and JSON, a TOML document needs to be a table at its root.
+Therefore only structs such as [`Material`], [`JordanModel`] and
+[`FerromagneticPermeability`] can be (de)serialized as complete documents,
+while e.g. an [`IronLossData`] needs to be wrapped into a struct field.
+Furthermore, TOML does not have a null value, hence optional fields which are
+`None` are omitted during serialization.
+ */
+#![cfg(test)]
+
+use indoc::indoc;
+use stem_material::prelude::*;
+
+#[test]
+fn test_toml_ferromagnetic_permeability() {
+    let permeability = FerromagneticPermeability::from_bh_pairs_si(
+        &[
+            (0.0, 0.0),
+            (50.0, 0.6),
+            (100.0, 1.0),
+            (200.0, 1.2),
+            (500.0, 1.4),
+            (1000.0, 1.5),
+            (5000.0, 1.7),
+            (10000.0, 1.8),
+        ],
+        0.95,
+    )
+    .unwrap();
+    let serialized = toml::to_string(&permeability).unwrap();
+    let de_permeability: FerromagneticPermeability = toml::from_str(&serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+
+    // Raw data representation
+    let serialized = indoc! {"
+    field_strength = '[0.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 5000.0, 10000.0] A/m'
+    flux_density = '[0.0, 0.6, 1.0, 1.2, 1.4, 1.5, 1.7, 1.8] T'
+    iron_fill_factor = 0.95
+    "};
+    let de_permeability: FerromagneticPermeability = toml::from_str(serialized).unwrap();
+    assert_eq!(permeability, de_permeability);
+}
+
+#[test]
+fn test_toml_jordan_model() {
+    let model = JordanModel::new(
+        SpecificPower::new::<watt_per_kilogram>(4.2),
+        SpecificPower::new::<watt_per_kilogram>(1.2),
+    );
+    let serialized = toml::to_string(&model).unwrap();
+    let de_model: JordanModel = toml::from_str(&serialized).unwrap();
+    assert_eq!(model, de_model);
+
+    let serialized = indoc! {"
+    hysteresis_coefficient = '4.2 W/kg'
+    eddy_current_coefficient = '1200 mW/kg'
+    "};
+    let de_model: JordanModel = toml::from_str(serialized).unwrap();
+    approx::assert_abs_diff_eq!(
+        de_model.eddy_current_coefficient.get::<watt_per_kilogram>(),
+        1.2,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_toml_material() {
+    // Untagged variants: Constant values and the tagged predefined models
+    let serialized = indoc! {"
+    name = 'M800-50A'
+    relative_permeability = 42.0
+    remanence = '0.0 T'
+
+    [iron_losses.JordanModel]
+    hysteresis_coefficient = 0.2
+    eddy_current_coefficient = 1.0
+    "};
+    let material: Material = toml::from_str(serialized).unwrap();
+    assert_eq!(material.relative_permeability().get(&[]), 42.0);
+    assert_eq!(
+        material.iron_losses(),
+        &IronLosses::JordanModel(JordanModel::new(
+            SpecificPower::new::<watt_per_kilogram>(0.2),
+            SpecificPower::new::<watt_per_kilogram>(1.0),
+        ))
+    );
+
+    // Round trip including the default (infinite) electrical resistivity
+    let mut material = material;
+    material.set_relative_permeability(RelativePermeability::FerromagneticPermeability(
+        FerromagneticPermeability::from_bh_pairs_si(
+            &[
+                (0.0, 0.0),
+                (50.0, 0.6),
+                (100.0, 1.0),
+                (200.0, 1.2),
+                (500.0, 1.4),
+                (1000.0, 1.5),
+                (5000.0, 1.7),
+                (10000.0, 1.8),
+            ],
+            0.95,
+        )
+        .unwrap(),
+    ));
+    let serialized = toml::to_string(&material).unwrap();
+    let de_material: Material = toml::from_str(&serialized).unwrap();
+    assert_eq!(material, de_material);
+}